@@ -78,6 +78,109 @@ local Signal = {} do
     end
 end
 
+local Auth = {} do
+    local band, bor, bxor, bnot = bit32.band, bit32.bor, bit32.bxor, bit32.bnot
+    local rrotate, rshift, lshift = bit32.rrotate, bit32.rshift, bit32.lshift
+
+    local K = {
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+        0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+        0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+        0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+        0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+        0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+        0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+    }
+
+    function Auth.SHA256(Message)
+        local H = { 0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19 }
+        local Bits = #Message * 8
+        local Tail = ''
+
+        for Index = 7, 0, -1 do
+            Tail = Tail .. string.char(math.floor(Bits / 2 ^ (Index * 8)) % 256)
+        end
+
+        Message = Message .. '\128' .. string.rep('\0', (55 - #Message) % 64) .. Tail
+
+        for Chunk = 1, #Message, 64 do
+            local W = {}
+
+            for Index = 0, 15 do
+                local A, B, C, D = Message:byte(Chunk + Index * 4, Chunk + Index * 4 + 3)
+                W[Index] = bor(lshift(A, 24), lshift(B, 16), lshift(C, 8), D)
+            end
+
+            for Index = 16, 63 do
+                local S0 = bxor(rrotate(W[Index - 15], 7), rrotate(W[Index - 15], 18), rshift(W[Index - 15], 3))
+                local S1 = bxor(rrotate(W[Index - 2], 17), rrotate(W[Index - 2], 19), rshift(W[Index - 2], 10))
+                W[Index] = (W[Index - 16] + S0 + W[Index - 7] + S1) % 2 ^ 32
+            end
+
+            local A, B, C, D, E, F, G, Hh = H[1], H[2], H[3], H[4], H[5], H[6], H[7], H[8]
+
+            for Index = 0, 63 do
+                local S1 = bxor(rrotate(E, 6), rrotate(E, 11), rrotate(E, 25))
+                local Ch = bxor(band(E, F), band(bnot(E), G))
+                local T1 = (Hh + S1 + Ch + K[Index + 1] + W[Index]) % 2 ^ 32
+                local S0 = bxor(rrotate(A, 2), rrotate(A, 13), rrotate(A, 22))
+                local Maj = bxor(band(A, B), band(A, C), band(B, C))
+                local T2 = (S0 + Maj) % 2 ^ 32
+
+                Hh, G, F, E, D, C, B, A = G, F, E, (D + T1) % 2 ^ 32, C, B, A, (T1 + T2) % 2 ^ 32
+            end
+
+            H[1] = (H[1] + A) % 2 ^ 32
+            H[2] = (H[2] + B) % 2 ^ 32
+            H[3] = (H[3] + C) % 2 ^ 32
+            H[4] = (H[4] + D) % 2 ^ 32
+            H[5] = (H[5] + E) % 2 ^ 32
+            H[6] = (H[6] + F) % 2 ^ 32
+            H[7] = (H[7] + G) % 2 ^ 32
+            H[8] = (H[8] + Hh) % 2 ^ 32
+        end
+
+        local Out = {}
+
+        for Index, Word in ipairs(H) do
+            Out[Index] = string.char(rshift(Word, 24), band(rshift(Word, 16), 255), band(rshift(Word, 8), 255), band(Word, 255))
+        end
+
+        return table.concat(Out)
+    end
+
+    function Auth.HMAC(Key, Message)
+        if #Key > 64 then Key = Auth.SHA256(Key) end
+        Key = Key .. string.rep('\0', 64 - #Key)
+
+        local Inner, Outer = {}, {}
+
+        for Index = 1, 64 do
+            local Byte = Key:byte(Index)
+            Inner[Index] = string.char(bxor(Byte, 0x36))
+            Outer[Index] = string.char(bxor(Byte, 0x5c))
+        end
+
+        return Auth.SHA256(table.concat(Outer) .. Auth.SHA256(table.concat(Inner) .. Message))
+    end
+
+    function Auth.Hex(Data)
+        return (Data:gsub('.', function(Char) return ('%02x'):format(Char:byte()) end))
+    end
+
+    function Auth.Query(Secret, Name, UserId, JobId)
+        if not Secret or Secret == '' then return '' end
+
+        local Nonce = HttpService:GenerateGUID(false):gsub('-', ''):lower()
+        local Timestamp = tostring(os.time())
+        local Message = ('%s:%s:%s:%s:%s'):format(Name, UserId, JobId, Nonce, Timestamp)
+        local Signature = Auth.Hex(Auth.HMAC(Auth.SHA256(Secret), Message))
+
+        return ('&nonce=%s&ts=%s&sig=%s'):format(Nonce, Timestamp, Signature)
+    end
+end
+
 do -- Nexus
     local BTN_CLICK = 'ButtonClicked:'
 
@@ -87,6 +190,17 @@ do -- Nexus
 
     Nexus.Commands = {}
     Nexus.Connections = {}
+    Nexus.Pending = {}
+
+    Nexus.Protocol = 1
+    Nexus.ProtocolVersion = 2
+    Nexus.Capabilities = { 'log', 'elements', 'get_text', 'relaunch', 'echo', 'errors', 'latency', 'element_events', 'data' }
+    Nexus.ElementChanged = Signal.new()
+    Nexus.ValueHandlers = {}
+    Nexus.DataChanged = Signal.new()
+    Nexus.DataHandlers = {}
+    Nexus.Script = nil
+    Nexus.RequestTimeout = 10
 
     Nexus.ShutdownTime = 45
     Nexus.ShutdownOnTeleportError = true
@@ -108,6 +222,95 @@ do -- Nexus
         self.Socket:Send(Message)
     end
 
+    function Nexus:SendFrame(Type, Data, Id)
+        assert(self.Socket ~= nil, 'websocket is nil')
+        assert(self.IsConnected, 'websocket not connected')
+
+        self.Socket:Send(HttpService:JSONEncode {
+            v = self.ProtocolVersion,
+            id = Id,
+            type = Type,
+            data = Data or {}
+        })
+    end
+
+    function Nexus:Request(Type, Data, Timeout)
+        local Id = HttpService:GenerateGUID(false)
+        local Event = Instance.new('BindableEvent')
+        local Result
+
+        self.Pending[Id] = function(Frame)
+            Result = Frame
+            Event:Fire()
+        end
+
+        task.delay(Timeout or self.RequestTimeout, function()
+            if self.Pending[Id] then
+                self.Pending[Id] = nil
+                Result = { type = 'error', data = { code = 'timeout', message = 'request timed out' } }
+                Event:Fire()
+            end
+        end)
+
+        self:SendFrame(Type, Data, Id)
+
+        if not Result then
+            Event.Event:Wait()
+        end
+        Event:Destroy()
+
+        if Result.type == 'error' then
+            return nil, Result.data and Result.data.message or 'request failed'
+        end
+
+        return Result.data
+    end
+
+    function Nexus:HandleFrame(Frame)
+        if Frame.type == 'welcome' then
+            self.Protocol = Frame.data and Frame.data.version or 1
+        elseif Frame.type == 'ping' then
+            pcall(self.SendFrame, self, 'pong', Frame.data)
+            return
+        elseif Frame.type == 'event' then
+            local Data = Frame.data or {}
+
+            if Data.kind == 'click' then
+                self.MessageReceived:Fire(BTN_CLICK .. tostring(Data.name))
+            elseif Data.kind == 'change' then
+                self.ElementChanged:Fire(Data.name, Data.value)
+
+                local Handler = self.ValueHandlers[Data.name]
+                if Handler then
+                    task.spawn(Handler, Data.value)
+                end
+            end
+            return
+        elseif Frame.type == 'data' then
+            local Data = Frame.data or {}
+            self.DataChanged:Fire(Data.key, Data.value, Data.by, Data.script)
+
+            local Handlers = self.DataHandlers[tostring(Data.script or '') .. '\0' .. tostring(Data.key)]
+            if Handlers then
+                for _, Handler in ipairs(Handlers) do
+                    task.spawn(Handler, Data.value, Data.by)
+                end
+            end
+            return
+        elseif Frame.type == 'command' then
+            self.MessageReceived:Fire(Frame.data and Frame.data.message or '')
+            return
+        end
+
+        if Frame.re and self.Pending[Frame.re] then
+            local Resolve = self.Pending[Frame.re]
+            self.Pending[Frame.re] = nil
+            Resolve(Frame)
+        elseif Frame.type == 'error' and Frame.data then
+            warn(('[Nexus] %s: %s'):format(tostring(Frame.data.code), tostring(Frame.data.message)))
+        end
+    end
+
     function Nexus:SetAutoRelaunch(Enabled)
         self:Send('SetAutoRelaunch', { Content = Enabled and 'true' or 'false' })
     end
@@ -136,6 +339,23 @@ do -- Nexus
         })
     end
 
+    function Nexus:LogLevel(Level, Message, Data)
+        assert(typeof(Level) == 'string', 'string expected on argument #1, got ' .. typeof(Level))
+
+        if self.Protocol >= 2 then
+            self:SendFrame('Log', {
+                content = tostring(Message),
+                level = Level,
+                data = Data
+            })
+        else
+            self:Send('Log', {
+                Content = tostring(Message),
+                Level = Level
+            })
+        end
+    end
+
     function Nexus:CreateElement(ElementType, Name, Content, Size, Margins, Table)
         assert(typeof(Name) == 'string', 'string expected on argument #1, got ' .. typeof(Name))
         assert(typeof(Content) == 'string', 'string expected on argument #2, got ' .. typeof(Content))
@@ -147,6 +367,7 @@ do -- Nexus
         
         local Payload = {
             Name = Name,
+            Script = self.Script,
             Content = Content,
             Size = Size and table.concat(Size, ','),
             Margin = Margins and table.concat(Margins, ',')
@@ -178,11 +399,95 @@ do -- Nexus
     end
 
     function Nexus:NewLine(...)
-        return self:Send('NewLine')
+        return self:Send('NewLine', self.Script and { Script = self.Script } or nil)
+    end
+
+    function Nexus:RemoveElement(Name)
+        return self:Send('RemoveElement', { Name = Name, Script = self.Script })
+    end
+
+    function Nexus:ClearElements()
+        return self:Send('ClearElements', self.Script and { Script = self.Script } or nil)
     end
 
     function Nexus:GetText(Name)
-        return self:WaitForMessage('ElementText:', 'GetText', { Name = Name })
+        if self.Protocol >= 2 then
+            local Data = self:Request('GetText', { name = Name, script = self.Script })
+            return Data and Data.text or ''
+        end
+
+        return self:WaitForMessage('ElementText:', 'GetText', { Name = Name, Script = self.Script })
+    end
+
+    function Nexus:GetData(Key)
+        local Data, Error = self:Request('DataGet', { script = self.Script, key = Key })
+        return Data and Data.value, Error
+    end
+
+    -- Options.IfAbsent only writes when the key is unset, Options.Expected only
+    -- when the current value matches; returns whether it was written and the stored value.
+    function Nexus:SetData(Key, Value, Options)
+        Options = Options or {}
+        local Data, Error = self:Request('DataSet', {
+            script = self.Script,
+            key = Key,
+            value = Value,
+            ifAbsent = Options.IfAbsent,
+            expected = Options.Expected
+        })
+        if not Data then
+            return false, nil, Error
+        end
+        return Data.set, Data.value
+    end
+
+    function Nexus:ClaimData(Key, Value)
+        return self:SetData(Key, Value == nil and LocalPlayer.Name or Value, { IfAbsent = true })
+    end
+
+    function Nexus:DeleteData(Key)
+        local Data, Error = self:Request('DataDelete', { script = self.Script, key = Key })
+        return Data and Data.removed or false, Error
+    end
+
+    function Nexus:GetDataKeys()
+        local Data, Error = self:Request('DataKeys', { script = self.Script })
+        return Data and Data.keys or {}, Error
+    end
+
+    function Nexus:WatchData(Key, Callback)
+        local Script = self.Script or ''
+        local Id = Script .. '\0' .. tostring(Key)
+
+        if not self.DataHandlers[Id] then
+            self.DataHandlers[Id] = {}
+            self:SendFrame('DataWatch', { script = Script, key = Key })
+        end
+        table.insert(self.DataHandlers[Id], Callback)
+
+        return function()
+            local Handlers = self.DataHandlers[Id]
+            if not Handlers then return end
+
+            local Index = table.find(Handlers, Callback)
+            if Index then
+                table.remove(Handlers, Index)
+            end
+
+            if #Handlers == 0 then
+                self.DataHandlers[Id] = nil
+                pcall(self.SendFrame, self, 'DataUnwatch', { script = Script, key = Key })
+            end
+        end
+    end
+
+    function Nexus:SendTo(Target, Message)
+        local Data, Error = self:Request('Send', { target = Target, message = Message })
+        return Data and Data.sent or 0, Error
+    end
+
+    function Nexus:SendToServer(Message)
+        return self:SendTo({ type = 'server', jobId = game.JobId }, Message)
     end
 
     function Nexus:SetRelaunch(Seconds)
@@ -229,14 +534,25 @@ do -- Nexus
                 Host = 'localhost:5242'
             end
 
-            local Success, Socket = pcall(WSConnect, ('ws://%s/Nexus?name=%s&id=%s&jobId=%s'):format(Host, LocalPlayer.Name, LocalPlayer.UserId, game.JobId))
+            local Query = Auth.Query(self.Secret or getgenv().NexusSecret, LocalPlayer.Name, LocalPlayer.UserId, game.JobId)
+            local Success, Socket = pcall(WSConnect, ('ws://%s/Nexus?name=%s&id=%s&jobId=%s&placeId=%s%s'):format(Host, LocalPlayer.Name, LocalPlayer.UserId, game.JobId, game.PlaceId, Query))
 
             if not Success then task.wait(12) continue end
 
             self.Socket = Socket
             self.IsConnected = true
+            self.Protocol = 1
+            table.clear(self.Pending)
 
             table.insert(self.Connections, Socket.OnMessage:Connect(function(Message)
+                if Message:sub(1, 1) == '{' then
+                    local Decoded, Frame = pcall(HttpService.JSONDecode, HttpService, Message)
+
+                    if Decoded and typeof(Frame) == 'table' and Frame.v and Frame.type then
+                        return self:HandleFrame(Frame)
+                    end
+                end
+
                 self.MessageReceived:Fire(Message)
             end))
 
@@ -245,6 +561,13 @@ do -- Nexus
                 self.Disconnected:Fire()
             end))
 
+            pcall(self.SendFrame, self, 'hello', { version = self.ProtocolVersion, capabilities = self.Capabilities })
+
+            for Id in pairs(self.DataHandlers) do
+                local Script, Key = Id:match('^(.-)\0(.*)$')
+                pcall(self.SendFrame, self, 'DataWatch', { script = Script, key = Key })
+            end
+
             self.Connected:Fire()
 
             while self.IsConnected do
@@ -281,6 +604,10 @@ do -- Nexus
         self:AddCommand('ButtonClicked:' .. Name, Function)
     end
 
+    function Nexus:OnValueChanged(Name, Function)
+        self.ValueHandlers[Name] = Function
+    end
+
     Nexus.MessageReceived:Connect(function(Message)
         local S = Message:find(' ')
 
@@ -291,14 +618,14 @@ do -- Nexus
                 local Success, Error = pcall(Nexus.Commands[Command], Message)
 
                 if not Success and Error then
-                    Nexus:Log(('Error with command `%s`: %s'):format(Command, Error))
+                    Nexus:LogLevel('error', ('Error with command `%s`: %s'):format(Command, Error))
                 end
             end
         elseif Nexus.Commands[Message] then
             local Success, Error = pcall(Nexus.Commands[Message], Message)
 
             if not Success and Error then
-                Nexus:Log(('Error with command `%s`: %s'):format(Message, Error))
+                Nexus:LogLevel('error', ('Error with command `%s`: %s'):format(Message, Error))
             end
         end
     end)
@@ -327,10 +654,10 @@ do -- Default Commands
             local S, E = pcall(Function)
 
             if not S then
-                Nexus:Log(E)
+                Nexus:LogLevel('error', E)
             end
         else
-            Nexus:Log(Error)
+            Nexus:LogLevel('error', Error)
         end
     end)
 
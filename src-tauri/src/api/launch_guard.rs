@@ -0,0 +1,163 @@
+use serde::Serialize;
+
+use crate::data::accounts::AccountStore;
+use crate::data::settings::SettingsStore;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OnlineJoinPolicy {
+    Off,
+    Warn,
+    Skip,
+    Kick,
+}
+
+impl OnlineJoinPolicy {
+    pub fn from_settings(settings: &SettingsStore) -> Self {
+        if !settings.get_bool("General", "WarnOnOnlineJoin") {
+            return Self::Off;
+        }
+        match settings
+            .get_string("General", "OnlineJoinPolicy")
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "off" => Self::Off,
+            "skip" => Self::Skip,
+            "kick" => Self::Kick,
+            _ => Self::Warn,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PresenceDecision {
+    Clear,
+    Unchecked,
+    Confirmed,
+    Warned,
+    Skipped,
+    Kicked,
+}
+
+impl PresenceDecision {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Clear => "clear",
+            Self::Unchecked => "unchecked",
+            Self::Confirmed => "confirmed",
+            Self::Warned => "warned",
+            Self::Skipped => "skipped",
+            Self::Kicked => "kicked",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresenceCheck {
+    pub user_id: i64,
+    pub policy: OnlineJoinPolicy,
+    pub decision: PresenceDecision,
+    pub presence_type: Option<i32>,
+    pub last_location: Option<String>,
+    pub detail: Option<String>,
+}
+
+impl PresenceCheck {
+    fn new(user_id: i64, policy: OnlineJoinPolicy, decision: PresenceDecision) -> Self {
+        Self {
+            user_id,
+            policy,
+            decision,
+            presence_type: None,
+            last_location: None,
+            detail: None,
+        }
+    }
+
+    pub fn allows_launch(&self) -> bool {
+        !matches!(
+            self.decision,
+            PresenceDecision::Warned | PresenceDecision::Skipped
+        )
+    }
+
+    pub fn blocked_message(&self) -> String {
+        let location = self
+            .last_location
+            .as_deref()
+            .filter(|l| !l.is_empty())
+            .unwrap_or("another session");
+        match self.decision {
+            PresenceDecision::Warned => format!(
+                "Account is already online ({}); confirm to launch anyway",
+                location
+            ),
+            _ => format!("Account is already online ({}); launch skipped", location),
+        }
+    }
+}
+
+/// Looks up the account's presence before an auth ticket is requested and
+/// applies the configured policy. `own_session` marks presence that belongs to
+/// an instance this app launched, and `confirmed` is the user's answer to an
+/// earlier warning. Under `Kick` the other sessions are signed out and
+/// `cookie` is replaced with the refreshed one, which is saved to `accounts`.
+pub async fn check_before_launch(
+    accounts: &AccountStore,
+    policy: OnlineJoinPolicy,
+    user_id: i64,
+    cookie: &mut String,
+    own_session: bool,
+    confirmed: bool,
+) -> PresenceCheck {
+    if policy == OnlineJoinPolicy::Off || own_session {
+        return PresenceCheck::new(user_id, policy, PresenceDecision::Clear);
+    }
+
+    let presence = match crate::api::roblox::get_presence(&[user_id]).await {
+        Ok(list) => list.into_iter().find(|p| p.user_id == user_id),
+        Err(e) => {
+            let mut check = PresenceCheck::new(user_id, policy, PresenceDecision::Unchecked);
+            check.detail = Some(e);
+            return check;
+        }
+    };
+    let Some(presence) = presence.filter(|p| p.user_presence_type >= 1) else {
+        return PresenceCheck::new(user_id, policy, PresenceDecision::Clear);
+    };
+
+    let decision = match policy {
+        OnlineJoinPolicy::Warn if confirmed => PresenceDecision::Confirmed,
+        OnlineJoinPolicy::Warn => PresenceDecision::Warned,
+        OnlineJoinPolicy::Skip => PresenceDecision::Skipped,
+        _ => PresenceDecision::Kicked,
+    };
+    let mut check = PresenceCheck::new(user_id, policy, decision);
+    check.presence_type = Some(presence.user_presence_type);
+    check.last_location = Some(presence.last_location);
+
+    if decision == PresenceDecision::Kicked {
+        match crate::api::auth::log_out_other_sessions(cookie).await {
+            Ok(result) => {
+                if let Some(new_cookie) = result.new_cookie {
+                    if let Ok(list) = accounts.get_all() {
+                        if let Some(mut account) = list.into_iter().find(|a| a.user_id == user_id) {
+                            account.security_token = new_cookie.clone();
+                            let _ = accounts.update(account);
+                        }
+                    }
+                    *cookie = new_cookie;
+                }
+            }
+            Err(e) => {
+                check.decision = PresenceDecision::Skipped;
+                check.detail = Some(format!("Failed to sign out other sessions: {}", e));
+            }
+        }
+    }
+
+    check
+}
@@ -1,5 +1,6 @@
 pub mod auth;
 pub mod batch;
+pub mod launch_guard;
 pub mod roblox;
 #[cfg(feature = "webserver")]
 pub mod server;
@@ -78,3 +78,58 @@ pub async fn purchase_product(
 
     response.json().await.map_err(|e| format!("Failed to parse purchase result: {}", e))
 }
+
+pub async fn get_premium_status(security_token: &str, user_id: i64) -> Result<bool, String> {
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!(
+            "https://premiumfeatures.roblox.com/v1/users/{}/validate-membership",
+            user_id
+        ))
+        .header(COOKIE, cookie_header(security_token))
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to get premium status (status {})", response.status().as_u16()));
+    }
+
+    response.json().await.map_err(|e| format!("Failed to parse premium status: {}", e))
+}
+
+/// Counts the limited items in an account's inventory, following every page.
+pub async fn get_limited_count(security_token: &str, user_id: i64) -> Result<i64, String> {
+    let client = reqwest::Client::new();
+    let mut count = 0;
+    let mut cursor = String::new();
+
+    loop {
+        let response = client
+            .get(format!(
+                "https://inventory.roblox.com/v1/users/{}/assets/collectibles?limit=100&sortOrder=Asc&cursor={}",
+                user_id,
+                urlencoding::encode(&cursor)
+            ))
+            .header(COOKIE, cookie_header(security_token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to get limiteds (status {})", response.status().as_u16()));
+        }
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse limiteds: {}", e))?;
+        count += body["data"].as_array().map_or(0, |items| items.len() as i64);
+
+        match body["nextPageCursor"].as_str() {
+            Some(next) if !next.is_empty() => cursor = next.to_string(),
+            _ => return Ok(count),
+        }
+    }
+}
@@ -19,8 +19,11 @@ include!("server/launch_patch.rs");
 include!("server/query.rs");
 include!("server/helpers.rs");
 include!("server/middleware.rs");
+include!("server/rate_limit.rs");
 include!("server/handlers_basic.rs");
 include!("server/handlers_launch.rs");
 include!("server/handlers_edit.rs");
+include!("server/handlers_batch.rs");
+include!("server/handlers_manage.rs");
 include!("server/route_wrappers.rs");
 include!("server/runtime.rs");
@@ -19,9 +19,9 @@ async fn handle_get_accounts(
         return reply(401, "Invalid password", v2);
     }
 
-    let accounts = match state.accounts.get_all() {
+    let accounts = match state.accounts.list_readable() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let filtered: Vec<_> = if let Some(ref group) = params.group {
@@ -29,6 +29,10 @@ async fn handle_get_accounts(
     } else {
         accounts
     };
+    let filtered = match filter_by_query(&state, filtered, params.query.as_deref(), v2) {
+        Ok(a) => a,
+        Err(response) => return *response,
+    };
 
     let names: Vec<String> = filtered.iter().map(|a| a.username.clone()).collect();
     reply(200, &names.join(","), v2)
@@ -55,9 +59,13 @@ async fn handle_get_accounts_json(
         && state.settings.get_bool("WebServer", "AllowGetCookie")
         && check_password(&state, &params.password);
 
-    let accounts = match state.accounts.get_all() {
+    if include_cookies && state.accounts.needs_password().unwrap_or(true) {
+        return reply(423, "Account file is locked", v2);
+    }
+
+    let accounts = match state.accounts.list_readable() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let filtered: Vec<_> = if let Some(ref group) = params.group {
@@ -65,6 +73,10 @@ async fn handle_get_accounts_json(
     } else {
         accounts
     };
+    let filtered = match filter_by_query(&state, filtered, params.query.as_deref(), v2) {
+        Ok(a) => a,
+        Err(response) => return *response,
+    };
 
     let json_accounts: Vec<serde_json::Value> = filtered
         .iter()
@@ -117,6 +129,10 @@ async fn handle_import_cookie(
         _ => return reply(400, "Missing Cookie parameter", v2),
     };
 
+    if let Some(response) = outbound_exhausted(&state, 1, v2) {
+        return response;
+    }
+
     match auth::validate_cookie(cookie).await {
         Ok(info) => {
             let account = crate::data::accounts::Account::new(
@@ -153,7 +169,7 @@ async fn handle_get_cookie(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     match find_account(&accounts, identifier) {
@@ -174,7 +190,7 @@ async fn handle_get_csrf_token(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let account = match find_account(&accounts, identifier) {
@@ -182,6 +198,10 @@ async fn handle_get_csrf_token(
         None => return reply(404, "Account not found", v2),
     };
 
+    if let Some(response) = outbound_exhausted(&state, 1, v2) {
+        return response;
+    }
+
     match auth::get_csrf_token(&account.security_token).await {
         Ok(token) => reply(200, &token, v2),
         Err(e) => reply(400, &e, v2),
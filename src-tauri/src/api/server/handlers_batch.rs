@@ -0,0 +1,459 @@
+const MAX_BATCH_CONCURRENCY: usize = 16;
+
+#[derive(Debug, Default, Deserialize)]
+struct BatchRequest {
+    #[serde(default, alias = "accounts", alias = "Accounts")]
+    accounts: Vec<serde_json::Value>,
+    #[serde(alias = "group", alias = "Group")]
+    group: Option<String>,
+    #[serde(alias = "query", alias = "Query")]
+    query: Option<String>,
+    #[serde(alias = "field", alias = "Field")]
+    field: Option<String>,
+    #[serde(alias = "value", alias = "Value")]
+    value: Option<String>,
+    #[serde(alias = "description", alias = "Description")]
+    description: Option<String>,
+    #[serde(alias = "userId", alias = "UserId")]
+    user_id: Option<i64>,
+    #[serde(alias = "placeId", alias = "PlaceId")]
+    place_id: Option<i64>,
+    #[serde(alias = "jobId", alias = "JobId")]
+    job_id: Option<String>,
+    #[serde(alias = "followUser", alias = "FollowUser")]
+    follow_user: Option<bool>,
+    #[serde(alias = "joinVip", alias = "JoinVIP")]
+    join_vip: Option<bool>,
+    #[serde(alias = "confirmOnline", alias = "ConfirmOnline")]
+    confirm_online: Option<bool>,
+    #[serde(alias = "concurrency", alias = "Concurrency")]
+    concurrency: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BatchResult {
+    #[serde(rename = "Account")]
+    account: String,
+    #[serde(rename = "Success")]
+    success: bool,
+    #[serde(rename = "Message")]
+    message: String,
+}
+
+fn parse_batch_request(body: &str) -> Result<BatchRequest, &'static str> {
+    let request: BatchRequest = serde_json::from_str(body).map_err(|_| "Invalid JSON body")?;
+
+    if request.accounts.is_empty()
+        && request.group.as_deref().unwrap_or("").is_empty()
+        && request.query.as_deref().unwrap_or("").is_empty()
+    {
+        return Err("Missing Accounts, Group or Query");
+    }
+
+    Ok(request)
+}
+
+fn resolve_batch_accounts(
+    state: &AppState,
+    request: &BatchRequest,
+) -> Result<(Vec<crate::data::accounts::Account>, Vec<BatchResult>), String> {
+    let accounts = state.accounts.get_all()?;
+    let mut resolved: Vec<crate::data::accounts::Account> = Vec::new();
+    let mut missing = Vec::new();
+
+    for value in &request.accounts {
+        let identifier = match value {
+            serde_json::Value::String(s) => s.clone(),
+            serde_json::Value::Number(n) => n.to_string(),
+            other => other.to_string(),
+        };
+
+        match find_account(&accounts, &identifier) {
+            Some(account) => {
+                if !resolved.iter().any(|a| a.user_id == account.user_id) {
+                    resolved.push(account);
+                }
+            }
+            None => missing.push(BatchResult {
+                account: identifier,
+                success: false,
+                message: "Account not found".into(),
+            }),
+        }
+    }
+
+    if let Some(group) = request.group.as_deref().filter(|g| !g.is_empty()) {
+        for account in accounts.iter().filter(|a| a.group == group) {
+            if !resolved.iter().any(|a| a.user_id == account.user_id) {
+                resolved.push(account.clone());
+            }
+        }
+    }
+
+    if let Some(query) = request.query.as_deref().filter(|q| !q.is_empty()) {
+        let matched = match state.accounts.parse_query(query) {
+            Ok(parsed) => state.accounts.query_parsed(&parsed)?,
+            Err(e) => {
+                missing.push(BatchResult {
+                    account: query.to_string(),
+                    success: false,
+                    message: format!("Invalid query: {}", e),
+                });
+                Vec::new()
+            }
+        };
+        for account in matched {
+            if !resolved.iter().any(|a| a.user_id == account.user_id) {
+                resolved.push(account);
+            }
+        }
+    }
+
+    Ok((resolved, missing))
+}
+
+fn batch_concurrency(state: &AppState, request: &BatchRequest) -> usize {
+    let configured = state
+        .settings
+        .get_int("WebServer", "BatchConcurrency")
+        .unwrap_or(4)
+        .max(1) as usize;
+
+    request
+        .concurrency
+        .unwrap_or(configured)
+        .clamp(1, configured.min(MAX_BATCH_CONCURRENCY))
+}
+
+async fn run_batch<F, Fut>(
+    state: &AppState,
+    accounts: Vec<crate::data::accounts::Account>,
+    concurrency: usize,
+    outbound_cost: u32,
+    op: F,
+) -> Vec<BatchResult>
+where
+    F: Fn(crate::data::accounts::Account) -> Fut,
+    Fut: std::future::Future<Output = Result<String, String>> + Send + 'static,
+{
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(concurrency));
+    let mut tasks = tokio::task::JoinSet::new();
+    let mut results: Vec<Option<BatchResult>> = vec![None; accounts.len()];
+    let usernames: Vec<String> = accounts.iter().map(|a| a.username.clone()).collect();
+
+    for (index, account) in accounts.into_iter().enumerate() {
+        let username = account.username.clone();
+
+        if outbound_cost > 0 {
+            if let Err(retry_after) = take_outbound(state.settings, outbound_cost) {
+                results[index] = Some(BatchResult {
+                    account: username,
+                    success: false,
+                    message: format!(
+                        "Outbound Roblox request budget exhausted, retry in {}s",
+                        retry_after
+                    ),
+                });
+                continue;
+            }
+        }
+
+        let permit = match semaphore.clone().acquire_owned().await {
+            Ok(p) => p,
+            Err(_) => break,
+        };
+        let fut = op(account);
+        tasks.spawn(async move {
+            let result = fut.await;
+            drop(permit);
+            (index, username, result)
+        });
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok((index, username, result)) = joined {
+            results[index] = Some(match result {
+                Ok(message) => BatchResult {
+                    account: username,
+                    success: true,
+                    message,
+                },
+                Err(message) => BatchResult {
+                    account: username,
+                    success: false,
+                    message,
+                },
+            });
+        }
+    }
+
+    // A task that panicked or never ran still gets a row, so Success stays honest.
+    results
+        .into_iter()
+        .zip(usernames)
+        .map(|(result, account)| {
+            result.unwrap_or(BatchResult {
+                account,
+                success: false,
+                message: "Batch task failed".to_string(),
+            })
+        })
+        .collect()
+}
+
+fn batch_reply(mut results: Vec<BatchResult>, missing: Vec<BatchResult>, v2: bool) -> Response {
+    results.extend(missing);
+
+    let body = if v2 {
+        serde_json::json!({
+            "Success": results.iter().all(|r| r.success),
+            "Message": results,
+        })
+        .to_string()
+    } else {
+        serde_json::to_string(&results).unwrap_or_else(|_| "[]".to_string())
+    };
+
+    Response::builder()
+        .status(200)
+        .header("content-type", "application/json; charset=utf-8")
+        .body(Body::from(body))
+        .unwrap()
+}
+
+async fn handle_batch_set_field(
+    Extension(state): Extension<AppState>,
+    Query(params): Query<AccountQuery>,
+    body: String,
+    v2: bool,
+) -> Response {
+    if !state.settings.get_bool("WebServer", "AllowAccountEditing") {
+        return reply(401, "AllowAccountEditing is disabled", v2);
+    }
+
+    if !check_password(&state, &params.password) {
+        return reply(401, "Invalid password", v2);
+    }
+
+    let request = match parse_batch_request(&body) {
+        Ok(r) => r,
+        Err(e) => return reply(400, e, v2),
+    };
+
+    let field_name = match request.field {
+        Some(ref f) if !f.is_empty() => f.clone(),
+        _ => return reply(400, "Missing Field", v2),
+    };
+
+    let field_value = match request.value {
+        Some(ref v) => v.clone(),
+        None => return reply(400, "Missing Value", v2),
+    };
+
+    let (targets, missing) = match resolve_batch_accounts(&state, &request) {
+        Ok(r) => r,
+        Err(e) => return store_error(&e, v2),
+    };
+
+    let store = state.accounts;
+    let results = run_batch(&state, targets, 1, 0, |mut account| {
+        account.set_field(field_name.clone(), field_value.clone());
+        let result = store.update(account).map(|_| "Field set successfully".to_string());
+        async move { result }
+    })
+    .await;
+
+    batch_reply(results, missing, v2)
+}
+
+async fn handle_batch_set_description(
+    Extension(state): Extension<AppState>,
+    Query(params): Query<AccountQuery>,
+    body: String,
+    v2: bool,
+) -> Response {
+    if !state.settings.get_bool("WebServer", "AllowAccountEditing") {
+        return reply(401, "AllowAccountEditing is disabled", v2);
+    }
+
+    if !check_password(&state, &params.password) {
+        return reply(401, "Invalid password", v2);
+    }
+
+    let request = match parse_batch_request(&body) {
+        Ok(r) => r,
+        Err(e) => return reply(400, e, v2),
+    };
+
+    let description = match request.description {
+        Some(ref d) => d.clone(),
+        None => return reply(400, "Missing Description", v2),
+    };
+
+    let (targets, missing) = match resolve_batch_accounts(&state, &request) {
+        Ok(r) => r,
+        Err(e) => return store_error(&e, v2),
+    };
+
+    let store = state.accounts;
+    let results = run_batch(&state, targets, 1, 0, |mut account| {
+        account.description = description.clone();
+        let result = store
+            .update(account)
+            .map(|_| "Description set successfully".to_string());
+        async move { result }
+    })
+    .await;
+
+    batch_reply(results, missing, v2)
+}
+
+async fn handle_batch_block(
+    Extension(state): Extension<AppState>,
+    Query(params): Query<AccountQuery>,
+    body: String,
+    block: bool,
+    v2: bool,
+) -> Response {
+    if !check_password(&state, &params.password) {
+        return reply(401, "Invalid password", v2);
+    }
+
+    let request = match parse_batch_request(&body) {
+        Ok(r) => r,
+        Err(e) => return reply(400, e, v2),
+    };
+
+    let target_user_id = match request.user_id {
+        Some(id) => id,
+        None => return reply(400, "Missing or invalid UserId", v2),
+    };
+
+    let (targets, missing) = match resolve_batch_accounts(&state, &request) {
+        Ok(r) => r,
+        Err(e) => return store_error(&e, v2),
+    };
+
+    let concurrency = batch_concurrency(&state, &request);
+    let results = run_batch(&state, targets, concurrency, 2, |account| async move {
+        if block {
+            roblox::block_user(&account.security_token, target_user_id)
+                .await
+                .map(|_| "User blocked successfully".to_string())
+        } else {
+            roblox::unblock_user(&account.security_token, target_user_id)
+                .await
+                .map(|_| "User unblocked successfully".to_string())
+        }
+    })
+    .await;
+
+    batch_reply(results, missing, v2)
+}
+
+async fn handle_batch_get_csrf_token(
+    Extension(state): Extension<AppState>,
+    Query(params): Query<AccountQuery>,
+    body: String,
+    v2: bool,
+) -> Response {
+    if !check_password(&state, &params.password) {
+        return reply(401, "Invalid password", v2);
+    }
+
+    let request = match parse_batch_request(&body) {
+        Ok(r) => r,
+        Err(e) => return reply(400, e, v2),
+    };
+
+    let (targets, missing) = match resolve_batch_accounts(&state, &request) {
+        Ok(r) => r,
+        Err(e) => return store_error(&e, v2),
+    };
+
+    let concurrency = batch_concurrency(&state, &request);
+    let results = run_batch(&state, targets, concurrency, 1, |account| async move {
+        auth::get_csrf_token(&account.security_token).await
+    })
+    .await;
+
+    batch_reply(results, missing, v2)
+}
+
+async fn handle_batch_launch_account(
+    Extension(state): Extension<AppState>,
+    Query(params): Query<AccountQuery>,
+    body: String,
+    v2: bool,
+) -> Response {
+    if !state.settings.get_bool("WebServer", "AllowLaunchAccount") {
+        return reply(401, "AllowLaunchAccount is disabled", v2);
+    }
+
+    if !check_password(&state, &params.password) {
+        return reply(401, "Invalid password", v2);
+    }
+
+    let request = match parse_batch_request(&body) {
+        Ok(r) => r,
+        Err(e) => return reply(400, e, v2),
+    };
+
+    let place_id = match request.place_id {
+        Some(id) => id,
+        None => return reply(400, "Missing or invalid PlaceId", v2),
+    };
+
+    let (targets, missing) = match resolve_batch_accounts(&state, &request) {
+        Ok(r) => r,
+        Err(e) => return store_error(&e, v2),
+    };
+
+    let job_id = request.job_id.clone().unwrap_or_default();
+    let follow_user = request.follow_user.unwrap_or(false);
+    let join_vip = request.join_vip.unwrap_or(false);
+    let confirm_online = request.confirm_online.unwrap_or(false);
+    let delay = state.settings.get_int("General", "AccountJoinDelay").unwrap_or(8).max(0) as u64;
+
+    let mut results = Vec::with_capacity(targets.len());
+    for (i, account) in targets.into_iter().enumerate() {
+        if i > 0 && delay > 0 {
+            tokio::time::sleep(std::time::Duration::from_secs(delay)).await;
+        }
+
+        let result = match take_outbound(state.settings, 2) {
+            Ok(()) => {
+                launch_account_for_web(
+                    &state,
+                    &account,
+                    place_id,
+                    &job_id,
+                    follow_user,
+                    join_vip,
+                    confirm_online,
+                )
+                .await
+                    .map_err(|(_, message)| message)
+            }
+            Err(retry_after) => Err(format!(
+                "Outbound Roblox request budget exhausted, retry in {}s",
+                retry_after
+            )),
+        };
+
+        results.push(match result {
+            Ok(message) => BatchResult {
+                account: account.username,
+                success: true,
+                message,
+            },
+            Err(message) => BatchResult {
+                account: account.username,
+                success: false,
+                message,
+            },
+        });
+    }
+
+    batch_reply(results, missing, v2)
+}
@@ -10,7 +10,7 @@ async fn handle_get_alias(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     match find_account(&accounts, identifier) {
@@ -31,7 +31,7 @@ async fn handle_get_description(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     match find_account(&accounts, identifier) {
@@ -57,7 +57,7 @@ async fn handle_get_field(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     match find_account(&accounts, identifier) {
@@ -95,7 +95,7 @@ async fn handle_set_field(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let mut account = match find_account(&accounts, identifier) {
@@ -106,7 +106,7 @@ async fn handle_set_field(
     account.set_field(field_name, field_value);
     match state.accounts.update(account) {
         Ok(_) => reply(200, "Field set successfully", v2),
-        Err(e) => reply(500, &e, v2),
+        Err(e) => store_error(&e, v2),
     }
 }
 
@@ -131,7 +131,7 @@ async fn handle_remove_field(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let mut account = match find_account(&accounts, identifier) {
@@ -142,7 +142,7 @@ async fn handle_remove_field(
     account.remove_field(field_name);
     match state.accounts.update(account) {
         Ok(_) => reply(200, "Field removed successfully", v2),
-        Err(e) => reply(500, &e, v2),
+        Err(e) => store_error(&e, v2),
     }
 }
 
@@ -167,7 +167,7 @@ async fn handle_set_alias(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let mut account = match find_account(&accounts, identifier) {
@@ -178,7 +178,7 @@ async fn handle_set_alias(
     account.alias = body;
     match state.accounts.update(account) {
         Ok(_) => reply(200, "Alias set successfully", v2),
-        Err(e) => reply(500, &e, v2),
+        Err(e) => store_error(&e, v2),
     }
 }
 
@@ -203,7 +203,7 @@ async fn handle_set_description(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let mut account = match find_account(&accounts, identifier) {
@@ -214,7 +214,7 @@ async fn handle_set_description(
     account.description = body;
     match state.accounts.update(account) {
         Ok(_) => reply(200, "Description set successfully", v2),
-        Err(e) => reply(500, &e, v2),
+        Err(e) => store_error(&e, v2),
     }
 }
 
@@ -239,7 +239,7 @@ async fn handle_append_description(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let mut account = match find_account(&accounts, identifier) {
@@ -250,7 +250,7 @@ async fn handle_append_description(
     account.description.push_str(&body);
     match state.accounts.update(account) {
         Ok(_) => reply(200, "Description appended successfully", v2),
-        Err(e) => reply(500, &e, v2),
+        Err(e) => store_error(&e, v2),
     }
 }
 
@@ -272,7 +272,7 @@ async fn handle_set_avatar(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let account = match find_account(&accounts, identifier) {
@@ -280,6 +280,10 @@ async fn handle_set_avatar(
         None => return reply(404, "Account not found", v2),
     };
 
+    if let Some(response) = outbound_exhausted(&state, 2, v2) {
+        return response;
+    }
+
     match roblox::set_avatar(&account.security_token, avatar_json).await {
         Ok(_) => reply(200, "Avatar set successfully", v2),
         Err(e) => reply(400, &e, v2),
@@ -303,7 +307,7 @@ async fn handle_block_user(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let account = match find_account(&accounts, identifier) {
@@ -311,9 +315,13 @@ async fn handle_block_user(
         None => return reply(404, "Account not found", v2),
     };
 
+    if let Some(response) = outbound_exhausted(&state, 2, v2) {
+        return response;
+    }
+
     match roblox::block_user(&account.security_token, target_user_id).await {
         Ok(_) => reply(200, "User blocked successfully", v2),
-        Err(e) => reply(500, &e, v2),
+        Err(e) => store_error(&e, v2),
     }
 }
 
@@ -334,7 +342,7 @@ async fn handle_unblock_user(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let account = match find_account(&accounts, identifier) {
@@ -342,9 +350,13 @@ async fn handle_unblock_user(
         None => return reply(404, "Account not found", v2),
     };
 
+    if let Some(response) = outbound_exhausted(&state, 2, v2) {
+        return response;
+    }
+
     match roblox::unblock_user(&account.security_token, target_user_id).await {
         Ok(_) => reply(200, "User unblocked successfully", v2),
-        Err(e) => reply(500, &e, v2),
+        Err(e) => store_error(&e, v2),
     }
 }
 
@@ -360,7 +372,7 @@ async fn handle_get_blocked_list(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let account = match find_account(&accounts, identifier) {
@@ -368,6 +380,10 @@ async fn handle_get_blocked_list(
         None => return reply(404, "Account not found", v2),
     };
 
+    if let Some(response) = outbound_exhausted(&state, 1, v2) {
+        return response;
+    }
+
     match roblox::get_blocked_users(&account.security_token).await {
         Ok(blocked) => {
             let body = serde_json::to_string(&blocked).unwrap_or_else(|_| "[]".to_string());
@@ -389,7 +405,7 @@ async fn handle_get_blocked_list(
                     .unwrap()
             }
         }
-        Err(e) => reply(500, &e, v2),
+        Err(e) => store_error(&e, v2),
     }
 }
 
@@ -405,7 +421,7 @@ async fn handle_unblock_everyone(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let account = match find_account(&accounts, identifier) {
@@ -413,9 +429,13 @@ async fn handle_unblock_everyone(
         None => return reply(404, "Account not found", v2),
     };
 
+    if let Some(response) = outbound_exhausted(&state, 5, v2) {
+        return response;
+    }
+
     match roblox::unblock_all_users(&account.security_token).await {
         Ok(count) => reply(200, &format!("Unblocked {} users", count), v2),
-        Err(e) => reply(500, &e, v2),
+        Err(e) => store_error(&e, v2),
     }
 }
 
@@ -24,10 +24,11 @@ async fn handle_launch_account(
     let job_id = params.job_id.as_deref().unwrap_or("");
     let follow_user = params.follow_user.as_deref().map(|v| v.eq_ignore_ascii_case("true")).unwrap_or(false);
     let join_vip = params.join_vip.as_deref().map(|v| v.eq_ignore_ascii_case("true")).unwrap_or(false);
+    let confirm_online = params.confirm_online.as_deref().map(|v| v.eq_ignore_ascii_case("true")).unwrap_or(false);
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let account = match find_account(&accounts, identifier) {
@@ -35,23 +36,60 @@ async fn handle_launch_account(
         None => return reply(404, "Account not found", v2),
     };
 
+    if let Some(response) = outbound_exhausted(&state, 2, v2) {
+        return response;
+    }
+
+    match launch_account_for_web(&state, &account, place_id, job_id, follow_user, join_vip, confirm_online).await {
+        Ok(message) => reply(200, &message, v2),
+        Err((status, message)) => reply(status, &message, v2),
+    }
+}
+
+// There is nobody to answer an online-join warning over HTTP, so a warn policy
+// refuses the launch unless the caller passes ConfirmOnline.
+async fn launch_account_for_web(
+    state: &AppState,
+    account: &crate::data::accounts::Account,
+    place_id: i64,
+    job_id: &str,
+    follow_user: bool,
+    join_vip: bool,
+    confirm_online: bool,
+) -> Result<String, (u16, String)> {
     #[cfg(target_os = "windows")]
     {
+        use crate::api::launch_guard::{self, OnlineJoinPolicy, PresenceDecision};
         use crate::platform::windows;
 
-        patch_client_settings_for_launch(state.settings);
-        let is_teleport = state.settings.get_bool("Developer", "IsTeleport");
-        let use_old_join = state.settings.get_bool("Developer", "UseOldJoin");
-        let auto_close_last_process = state.settings.get_bool("General", "AutoCloseLastProcess");
-        let multi_rbx = state.settings.get_bool("General", "EnableMultiRbx");
+        let settings = state.settings;
+        let mut cookie = account.security_token.clone();
+        let presence = launch_guard::check_before_launch(
+            state.accounts,
+            OnlineJoinPolicy::from_settings(settings),
+            account.user_id,
+            &mut cookie,
+            windows::tracker().get_pid(account.user_id).is_some(),
+            confirm_online,
+        )
+        .await;
+        if !presence.allows_launch() {
+            return Err((409, presence.blocked_message()));
+        }
+
+        patch_client_settings_for_launch(settings);
+        let is_teleport = settings.get_bool("Developer", "IsTeleport");
+        let use_old_join = settings.get_bool("Developer", "UseOldJoin");
+        let auto_close_last_process = settings.get_bool("General", "AutoCloseLastProcess");
+        let multi_rbx = settings.get_bool("General", "EnableMultiRbx");
 
         if multi_rbx {
             match windows::enable_multi_roblox() {
                 Ok(true) => {}
                 Ok(false) => {
-                    return reply(500, "Failed to enable Multi Roblox. Close all Roblox processes and try again.", v2);
+                    return Err((500, "Failed to enable Multi Roblox. Close all Roblox processes and try again.".to_string()));
                 }
-                Err(e) => return reply(500, &e, v2),
+                Err(e) => return Err((500, e)),
             }
         } else {
             let _ = windows::disable_multi_roblox();
@@ -64,9 +102,9 @@ async fn handle_launch_account(
         }
 
         let browser_tracker_id = windows::generate_browser_tracker_id();
-        let ticket = match auth::get_auth_ticket(&account.security_token).await {
+        let ticket = match auth::get_auth_ticket(&cookie).await {
             Ok(t) => t,
-            Err(e) => return reply(400, &format!("Failed to get auth ticket: {}", e), v2),
+            Err(e) => return Err((400, format!("Failed to get auth ticket: {}", e))),
         };
 
         let pids_before = windows::get_roblox_pids();
@@ -92,7 +130,7 @@ async fn handle_launch_account(
             if !extracted.is_empty() {
                 link_code = extracted;
                 if let Ok(code) = roblox::parse_private_server_link_code(
-                    &account.security_token,
+                    &cookie,
                     place_id,
                     &link_code,
                 )
@@ -132,7 +170,7 @@ async fn handle_launch_account(
         };
 
         if let Err(e) = launch_result {
-            return reply(500, &format!("Failed to launch: {}", e), v2);
+            return Err((500, format!("Failed to launch: {}", e)));
         }
 
         tokio::time::sleep(std::time::Duration::from_secs(3)).await;
@@ -142,13 +180,16 @@ async fn handle_launch_account(
             tracker.track(account.user_id, pid, browser_tracker_id);
         }
 
-        reply(200, &format!("Launched {} to {}", account.username, place_id), v2)
+        Ok(match presence.decision {
+            PresenceDecision::Clear => format!("Launched {} to {}", account.username, place_id),
+            decision => format!("Launched {} to {} (presence: {})", account.username, place_id, decision.as_str()),
+        })
     }
 
     #[cfg(not(target_os = "windows"))]
     {
-        let _ = (place_id, job_id, follow_user, join_vip, account);
-        reply(500, "Launching is only supported on Windows", v2)
+        let _ = (state, account, place_id, job_id, follow_user, join_vip, confirm_online);
+        Err((500, "Launching is only supported on Windows".to_string()))
     }
 }
 
@@ -177,7 +218,7 @@ async fn handle_follow_user(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let account = match find_account(&accounts, identifier) {
@@ -185,6 +226,10 @@ async fn handle_follow_user(
         None => return reply(404, "Account not found", v2),
     };
 
+    if let Some(response) = outbound_exhausted(&state, 3, v2) {
+        return response;
+    }
+
     let target = match roblox::get_user_id(None, &target_username).await {
         Ok(u) => u,
         Err(e) => return reply(400, &e, v2),
@@ -297,7 +342,7 @@ async fn handle_set_server(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let account = match find_account(&accounts, identifier) {
@@ -305,6 +350,10 @@ async fn handle_set_server(
         None => return reply(404, "Account not found", v2),
     };
 
+    if let Some(response) = outbound_exhausted(&state, 2, v2) {
+        return response;
+    }
+
     match roblox::join_game_instance(&account.security_token, place_id, &job_id, false).await {
         Ok(_) => reply(200, "Server set successfully", v2),
         Err(e) => reply(400, &e, v2),
@@ -328,7 +377,7 @@ async fn handle_set_recommended_server(
 
     let accounts = match state.accounts.get_all() {
         Ok(a) => a,
-        Err(e) => return reply(500, &e, v2),
+        Err(e) => return store_error(&e, v2),
     };
 
     let account = match find_account(&accounts, identifier) {
@@ -336,6 +385,10 @@ async fn handle_set_recommended_server(
         None => return reply(404, "Account not found", v2),
     };
 
+    if let Some(response) = outbound_exhausted(&state, 3, v2) {
+        return response;
+    }
+
     let servers_response = match roblox::get_servers(place_id, "Public", None, Some(&account.security_token)).await {
         Ok(r) => r,
         Err(e) => return reply(400, &format!("Failed to get servers: {}", e), v2),
@@ -0,0 +1,262 @@
+fn check_management_access(state: &AppState, password: &Option<String>, v2: bool) -> Option<Response> {
+    if !state.settings.get_bool("WebServer", "AllowAccountManagement") {
+        return Some(reply(401, "AllowAccountManagement is disabled", v2));
+    }
+
+    if password.as_deref().unwrap_or("").is_empty() || !check_password(state, password) {
+        return Some(reply(401, "Invalid password", v2));
+    }
+
+    None
+}
+
+async fn handle_remove_account(
+    Extension(state): Extension<AppState>,
+    Query(params): Query<AccountQuery>,
+    v2: bool,
+) -> Response {
+    if let Some(denied) = check_management_access(&state, &params.password, v2) {
+        return denied;
+    }
+
+    let identifier = match params.account {
+        Some(ref a) if !a.is_empty() => a,
+        _ => return reply(400, "Missing Account parameter", v2),
+    };
+
+    let accounts = match state.accounts.get_all() {
+        Ok(a) => a,
+        Err(e) => return store_error(&e, v2),
+    };
+
+    let account = match find_account(&accounts, identifier) {
+        Some(a) => a,
+        None => return reply(404, "Account not found", v2),
+    };
+
+    match state.accounts.remove(account.user_id) {
+        Ok(true) => reply(200, &format!("Removed {}", account.username), v2),
+        Ok(false) => reply(404, "Account not found", v2),
+        Err(e) => store_error(&e, v2),
+    }
+}
+
+async fn handle_set_group(
+    Extension(state): Extension<AppState>,
+    Query(params): Query<AccountQuery>,
+    v2: bool,
+) -> Response {
+    if let Some(denied) = check_management_access(&state, &params.password, v2) {
+        return denied;
+    }
+
+    let identifier = match params.account {
+        Some(ref a) if !a.is_empty() => a,
+        _ => return reply(400, "Missing Account parameter", v2),
+    };
+
+    let group = match params.group {
+        Some(ref g) if !g.trim().is_empty() => g.trim().to_string(),
+        _ => return reply(400, "Missing Group parameter", v2),
+    };
+
+    let accounts = match state.accounts.get_all() {
+        Ok(a) => a,
+        Err(e) => return store_error(&e, v2),
+    };
+
+    let mut account = match find_account(&accounts, identifier) {
+        Some(a) => a,
+        None => return reply(404, "Account not found", v2),
+    };
+
+    account.group = group;
+    match state.accounts.update(account) {
+        Ok(_) => reply(200, "Group set successfully", v2),
+        Err(e) => store_error(&e, v2),
+    }
+}
+
+async fn handle_rename_group(
+    Extension(state): Extension<AppState>,
+    Query(params): Query<AccountQuery>,
+    v2: bool,
+) -> Response {
+    if let Some(denied) = check_management_access(&state, &params.password, v2) {
+        return denied;
+    }
+
+    let old_name = match params.group {
+        Some(ref g) if !g.is_empty() => g,
+        _ => return reply(400, "Missing Group parameter", v2),
+    };
+
+    let new_name = match params.new_group {
+        Some(ref g) if !g.trim().is_empty() => g.trim(),
+        _ => return reply(400, "Missing NewGroup parameter", v2),
+    };
+
+    match state.accounts.rename_group(old_name, new_name) {
+        Ok(0) => reply(404, "Group not found", v2),
+        Ok(count) => reply(200, &format!("Renamed group for {} accounts", count), v2),
+        Err(e) => store_error(&e, v2),
+    }
+}
+
+async fn handle_set_password(
+    Extension(state): Extension<AppState>,
+    Query(params): Query<AccountQuery>,
+    body: String,
+    v2: bool,
+) -> Response {
+    if let Some(denied) = check_management_access(&state, &params.password, v2) {
+        return denied;
+    }
+
+    let identifier = match params.account {
+        Some(ref a) if !a.is_empty() => a,
+        _ => return reply(400, "Missing Account parameter", v2),
+    };
+
+    let accounts = match state.accounts.get_all() {
+        Ok(a) => a,
+        Err(e) => return store_error(&e, v2),
+    };
+
+    let mut account = match find_account(&accounts, identifier) {
+        Some(a) => a,
+        None => return reply(404, "Account not found", v2),
+    };
+
+    account.password = body;
+    match state.accounts.update(account) {
+        Ok(_) => reply(200, "Password set successfully", v2),
+        Err(e) => store_error(&e, v2),
+    }
+}
+
+async fn handle_reorder_accounts(
+    Extension(state): Extension<AppState>,
+    Query(params): Query<AccountQuery>,
+    body: String,
+    v2: bool,
+) -> Response {
+    if let Some(denied) = check_management_access(&state, &params.password, v2) {
+        return denied;
+    }
+
+    let identifiers: Vec<serde_json::Value> = match serde_json::from_str(&body) {
+        Ok(v) => v,
+        Err(_) => return reply(400, "Invalid JSON body", v2),
+    };
+
+    let accounts = match state.accounts.get_all() {
+        Ok(a) => a,
+        Err(e) => return store_error(&e, v2),
+    };
+
+    let user_ids: Vec<i64> = identifiers
+        .iter()
+        .filter_map(|value| {
+            let identifier = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            find_account(&accounts, &identifier).map(|a| a.user_id)
+        })
+        .collect();
+
+    match state.accounts.reorder(&user_ids) {
+        Ok(_) => reply(200, &format!("Reordered {} accounts", user_ids.len()), v2),
+        Err(e) => store_error(&e, v2),
+    }
+}
+
+async fn handle_import_cookies(
+    Extension(state): Extension<AppState>,
+    Query(params): Query<AccountQuery>,
+    body: String,
+    v2: bool,
+) -> Response {
+    if let Some(denied) = check_management_access(&state, &params.password, v2) {
+        return denied;
+    }
+
+    let cookies: Vec<String> = body
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_string())
+        .collect();
+
+    if cookies.is_empty() {
+        return reply(400, "Missing body", v2);
+    }
+
+    let group = params.group.clone().filter(|g| !g.trim().is_empty());
+    let concurrency = state
+        .settings
+        .get_int("WebServer", "BatchConcurrency")
+        .unwrap_or(4)
+        .clamp(1, MAX_BATCH_CONCURRENCY as i64) as usize;
+
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(concurrency));
+    let mut tasks = tokio::task::JoinSet::new();
+    let mut results: Vec<Option<BatchResult>> = vec![None; cookies.len()];
+
+    for (index, cookie) in cookies.into_iter().enumerate() {
+        if let Err(retry_after) = take_outbound(state.settings, 1) {
+            results[index] = Some(BatchResult {
+                account: format!("Line {}", index + 1),
+                success: false,
+                message: format!("Outbound Roblox request budget exhausted, retry in {}s", retry_after),
+            });
+            continue;
+        }
+
+        let permit = match semaphore.clone().acquire_owned().await {
+            Ok(p) => p,
+            Err(_) => break,
+        };
+        tasks.spawn(async move {
+            let result = auth::validate_cookie(&cookie).await;
+            drop(permit);
+            (index, cookie, result)
+        });
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        let Ok((index, cookie, result)) = joined else {
+            continue;
+        };
+
+        results[index] = Some(match result {
+            Ok(info) => {
+                let mut account =
+                    crate::data::accounts::Account::new(cookie, info.name.clone(), info.user_id);
+                if let Some(ref group) = group {
+                    account.group = group.clone();
+                }
+                match state.accounts.add(account) {
+                    Ok(_) => BatchResult {
+                        account: info.name,
+                        success: true,
+                        message: "Imported".into(),
+                    },
+                    Err(e) => BatchResult {
+                        account: info.name,
+                        success: false,
+                        message: format!("Failed to save: {}", e),
+                    },
+                }
+            }
+            Err(e) => BatchResult {
+                account: format!("Line {}", index + 1),
+                success: false,
+                message: format!("Invalid cookie: {}", e),
+            },
+        });
+    }
+
+    batch_reply(results.into_iter().flatten().collect(), Vec::new(), v2)
+}
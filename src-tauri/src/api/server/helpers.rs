@@ -30,3 +30,33 @@ fn find_account(accounts: &[crate::data::accounts::Account], identifier: &str) -
         .cloned()
 }
 
+
+// Narrows a listing by the optional Query parameter, a query or smart group name.
+fn filter_by_query(
+    state: &AppState,
+    accounts: Vec<crate::data::accounts::Account>,
+    query: Option<&str>,
+    v2: bool,
+) -> Result<Vec<crate::data::accounts::Account>, Box<Response>> {
+    let Some(query) = query.filter(|q| !q.is_empty()) else {
+        return Ok(accounts);
+    };
+    let parsed = state
+        .accounts
+        .parse_query(query)
+        .map_err(|e| Box::new(reply(400, &format!("Invalid query: {}", e), v2)))?;
+    state
+        .accounts
+        .filter_accounts(accounts, &parsed)
+        .map_err(|e| Box::new(store_error(&e, v2)))
+}
+
+// A locked account store answers 423 so clients can tell it from a failure.
+fn store_error(message: &str, v2: bool) -> Response {
+    let status = if message == crate::data::accounts::LOCKED_ERROR {
+        423
+    } else {
+        500
+    };
+    reply(status, message, v2)
+}
@@ -1,3 +1,17 @@
+fn query_password(req: &Request) -> Option<String> {
+    req.uri().query().and_then(|query| {
+        query.split('&').find_map(|entry| {
+            let mut parts = entry.splitn(2, '=');
+            let key = parts.next().unwrap_or_default();
+            if !key.eq_ignore_ascii_case("password") {
+                return None;
+            }
+            let raw = parts.next().unwrap_or_default().replace('+', " ");
+            Some(urlencoding::decode(&raw).map(|v| v.into_owned()).unwrap_or(raw))
+        })
+    })
+}
+
 async fn external_check(
     Extension(state): Extension<AppState>,
     req: Request,
@@ -20,20 +34,7 @@ async fn external_check(
 
     if !is_running && state.settings.get_bool("WebServer", "EveryRequestRequiresPassword") {
         let ws_password = state.settings.get_string("WebServer", "Password");
-        let provided_password = req
-            .uri()
-            .query()
-            .and_then(|query| {
-                query.split('&').find_map(|entry| {
-                    let mut parts = entry.splitn(2, '=');
-                    let key = parts.next().unwrap_or_default();
-                    if !key.eq_ignore_ascii_case("password") {
-                        return None;
-                    }
-                    let raw = parts.next().unwrap_or_default().replace('+', " ");
-                    Some(urlencoding::decode(&raw).map(|v| v.into_owned()).unwrap_or(raw))
-                })
-            });
+        let provided_password = query_password(&req);
 
         if ws_password.len() < 6 || provided_password.as_deref() != Some(ws_password.as_str()) {
             return reply(
@@ -18,6 +18,10 @@ struct AccountQuery {
     cookie: Option<String>,
     #[serde(alias = "group", alias = "Group")]
     group: Option<String>,
+    #[serde(alias = "query", alias = "Query")]
+    query: Option<String>,
+    #[serde(alias = "newGroup", alias = "NewGroup")]
+    new_group: Option<String>,
     #[serde(alias = "username", alias = "Username")]
     username: Option<String>,
     #[serde(alias = "followUser", alias = "FollowUser")]
@@ -26,4 +30,6 @@ struct AccountQuery {
     join_vip: Option<String>,
     #[serde(alias = "includeCookies", alias = "IncludeCookies")]
     include_cookies: Option<String>,
+    #[serde(alias = "confirmOnline", alias = "ConfirmOnline")]
+    confirm_online: Option<String>,
 }
@@ -0,0 +1,181 @@
+static RATE_LIMITER: std::sync::LazyLock<RateLimiter> = std::sync::LazyLock::new(RateLimiter::new);
+
+const RATE_LIMIT_PRUNE_THRESHOLD: usize = 1024;
+
+struct RateWindow {
+    started: std::time::Instant,
+    count: u32,
+}
+
+struct RateLimiter {
+    windows: std::sync::Mutex<std::collections::HashMap<String, RateWindow>>,
+}
+
+impl RateLimiter {
+    fn new() -> Self {
+        Self {
+            windows: std::sync::Mutex::new(std::collections::HashMap::new()),
+        }
+    }
+
+    fn take(&self, key: &str, cost: u32, limit: u32, window: std::time::Duration) -> Result<(), u64> {
+        if limit == 0 {
+            return Ok(());
+        }
+
+        let mut windows = match self.windows.lock() {
+            Ok(w) => w,
+            Err(_) => return Ok(()),
+        };
+
+        let now = std::time::Instant::now();
+        if windows.len() > RATE_LIMIT_PRUNE_THRESHOLD {
+            windows.retain(|_, w| now.duration_since(w.started) < window);
+        }
+
+        let entry = windows.entry(key.to_string()).or_insert(RateWindow {
+            started: now,
+            count: 0,
+        });
+
+        if now.duration_since(entry.started) >= window {
+            entry.started = now;
+            entry.count = 0;
+        }
+
+        if entry.count.saturating_add(cost) > limit {
+            let remaining = window.saturating_sub(now.duration_since(entry.started));
+            return Err(remaining.as_secs().max(1));
+        }
+
+        entry.count += cost;
+        Ok(())
+    }
+
+    fn clear(&self) {
+        if let Ok(mut windows) = self.windows.lock() {
+            windows.clear();
+        }
+    }
+}
+
+fn rate_limit_window(settings: &SettingsStore) -> std::time::Duration {
+    std::time::Duration::from_secs(
+        settings
+            .get_int("WebServer", "RateLimitWindow")
+            .unwrap_or(60)
+            .max(1) as u64,
+    )
+}
+
+fn take_outbound(settings: &SettingsStore, cost: u32) -> Result<(), u64> {
+    if !settings.get_bool("WebServer", "RateLimitEnabled") {
+        return Ok(());
+    }
+
+    let budget = settings
+        .get_int("WebServer", "OutboundBudget")
+        .unwrap_or(120)
+        .max(0) as u32;
+    RATE_LIMITER.take("outbound", cost, budget, rate_limit_window(settings))
+}
+
+/// Charges `cost` against the shared Roblox budget. Handlers call this only
+/// once the request is authenticated and about to reach Roblox.
+fn outbound_exhausted(state: &AppState, cost: u32, v2: bool) -> Option<Response> {
+    take_outbound(state.settings, cost)
+        .err()
+        .map(|retry_after| too_many_requests("Outbound Roblox request budget exhausted", retry_after, v2))
+}
+
+fn route_key(path: &str) -> &str {
+    path.strip_prefix("/v2").unwrap_or(path)
+}
+
+fn route_limit(settings: &SettingsStore, route: &str) -> u32 {
+    let overrides = settings.get_string("WebServer", "RouteRateLimits");
+
+    let name = route.trim_start_matches('/');
+    for entry in overrides.split(',') {
+        let mut parts = entry.splitn(2, ':');
+        let key = parts.next().unwrap_or_default().trim();
+        if !key.eq_ignore_ascii_case(name) {
+            continue;
+        }
+        if let Some(Ok(limit)) = parts.next().map(|v| v.trim().parse::<u32>()) {
+            return limit;
+        }
+    }
+
+    settings
+        .get_int("WebServer", "RateLimitPerRoute")
+        .unwrap_or(120)
+        .max(0) as u32
+}
+
+fn token_key(req: &Request) -> String {
+    use std::hash::{Hash, Hasher};
+
+    match query_password(req) {
+        Some(password) if !password.is_empty() => {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            password.hash(&mut hasher);
+            format!("token:{:x}", hasher.finish())
+        }
+        _ => format!("client:{}", client_ip(req)),
+    }
+}
+
+fn client_ip(req: &Request) -> String {
+    req.extensions()
+        .get::<axum::extract::ConnectInfo<SocketAddr>>()
+        .map(|addr| addr.ip().to_string())
+        .unwrap_or_else(|| "local".to_string())
+}
+
+fn too_many_requests(message: &str, retry_after: u64, v2: bool) -> Response {
+    let mut response = reply(429, message, v2);
+    if let Ok(value) = axum::http::HeaderValue::from_str(&retry_after.to_string()) {
+        response.headers_mut().insert(axum::http::header::RETRY_AFTER, value);
+    }
+    response
+}
+
+async fn rate_limit(
+    Extension(state): Extension<AppState>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let path = req.uri().path().to_string();
+    let is_v2 = path.starts_with("/v2/");
+    let route = route_key(&path);
+
+    if route.eq_ignore_ascii_case("/Running")
+        || !state.settings.get_bool("WebServer", "RateLimitEnabled")
+    {
+        return next.run(req).await;
+    }
+
+    let window = rate_limit_window(state.settings);
+
+    // Keyed like the token bucket, so a local process without the password
+    // cannot use up the routes of the client that has it.
+    let token = token_key(&req);
+    let route_max = route_limit(state.settings, route);
+    if let Err(retry_after) =
+        RATE_LIMITER.take(&format!("route:{}:{}", token, route), 1, route_max, window)
+    {
+        return too_many_requests(&format!("Rate limit exceeded for {}", route), retry_after, is_v2);
+    }
+
+    let token_max = state
+        .settings
+        .get_int("WebServer", "RateLimitPerToken")
+        .unwrap_or(300)
+        .max(0) as u32;
+    if let Err(retry_after) = RATE_LIMITER.take(&token, 1, token_max, window) {
+        return too_many_requests("Rate limit exceeded", retry_after, is_v2);
+    }
+
+    next.run(req).await
+}
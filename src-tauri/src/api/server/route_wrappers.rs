@@ -159,3 +159,87 @@ async fn v2_unblock_everyone(ext: Extension<AppState>, q: Query<AccountQuery>) -
     handle_unblock_everyone(ext, q, true).await
 }
 
+
+async fn v1_batch_set_field(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_batch_set_field(ext, q, body, false).await
+}
+async fn v2_batch_set_field(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_batch_set_field(ext, q, body, true).await
+}
+
+async fn v1_batch_set_description(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_batch_set_description(ext, q, body, false).await
+}
+async fn v2_batch_set_description(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_batch_set_description(ext, q, body, true).await
+}
+
+async fn v1_batch_block_user(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_batch_block(ext, q, body, true, false).await
+}
+async fn v2_batch_block_user(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_batch_block(ext, q, body, true, true).await
+}
+
+async fn v1_batch_unblock_user(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_batch_block(ext, q, body, false, false).await
+}
+async fn v2_batch_unblock_user(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_batch_block(ext, q, body, false, true).await
+}
+
+async fn v1_batch_launch_account(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_batch_launch_account(ext, q, body, false).await
+}
+async fn v2_batch_launch_account(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_batch_launch_account(ext, q, body, true).await
+}
+
+async fn v1_batch_get_csrf_token(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_batch_get_csrf_token(ext, q, body, false).await
+}
+async fn v2_batch_get_csrf_token(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_batch_get_csrf_token(ext, q, body, true).await
+}
+
+async fn v1_remove_account(ext: Extension<AppState>, q: Query<AccountQuery>) -> Response {
+    handle_remove_account(ext, q, false).await
+}
+async fn v2_remove_account(ext: Extension<AppState>, q: Query<AccountQuery>) -> Response {
+    handle_remove_account(ext, q, true).await
+}
+
+async fn v1_set_group(ext: Extension<AppState>, q: Query<AccountQuery>) -> Response {
+    handle_set_group(ext, q, false).await
+}
+async fn v2_set_group(ext: Extension<AppState>, q: Query<AccountQuery>) -> Response {
+    handle_set_group(ext, q, true).await
+}
+
+async fn v1_rename_group(ext: Extension<AppState>, q: Query<AccountQuery>) -> Response {
+    handle_rename_group(ext, q, false).await
+}
+async fn v2_rename_group(ext: Extension<AppState>, q: Query<AccountQuery>) -> Response {
+    handle_rename_group(ext, q, true).await
+}
+
+async fn v1_set_password(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_set_password(ext, q, body, false).await
+}
+async fn v2_set_password(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_set_password(ext, q, body, true).await
+}
+
+async fn v1_reorder_accounts(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_reorder_accounts(ext, q, body, false).await
+}
+async fn v2_reorder_accounts(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_reorder_accounts(ext, q, body, true).await
+}
+
+async fn v1_import_cookies(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_import_cookies(ext, q, body, false).await
+}
+async fn v2_import_cookies(ext: Extension<AppState>, q: Query<AccountQuery>, body: String) -> Response {
+    handle_import_cookies(ext, q, body, true).await
+}
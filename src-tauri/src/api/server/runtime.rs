@@ -46,6 +46,31 @@ fn build_router(state: AppState) -> Router {
         .route("/v2/GetBlockedList", get(v2_get_blocked_list))
         .route("/UnblockEveryone", post(v1_unblock_everyone))
         .route("/v2/UnblockEveryone", post(v2_unblock_everyone))
+        .route("/BatchSetField", post(v1_batch_set_field))
+        .route("/v2/BatchSetField", post(v2_batch_set_field))
+        .route("/BatchSetDescription", post(v1_batch_set_description))
+        .route("/v2/BatchSetDescription", post(v2_batch_set_description))
+        .route("/BatchBlockUser", post(v1_batch_block_user))
+        .route("/v2/BatchBlockUser", post(v2_batch_block_user))
+        .route("/BatchUnblockUser", post(v1_batch_unblock_user))
+        .route("/v2/BatchUnblockUser", post(v2_batch_unblock_user))
+        .route("/BatchLaunchAccount", post(v1_batch_launch_account))
+        .route("/v2/BatchLaunchAccount", post(v2_batch_launch_account))
+        .route("/BatchGetCSRFToken", post(v1_batch_get_csrf_token))
+        .route("/v2/BatchGetCSRFToken", post(v2_batch_get_csrf_token))
+        .route("/RemoveAccount", post(v1_remove_account))
+        .route("/v2/RemoveAccount", post(v2_remove_account))
+        .route("/SetGroup", post(v1_set_group))
+        .route("/v2/SetGroup", post(v2_set_group))
+        .route("/RenameGroup", post(v1_rename_group))
+        .route("/v2/RenameGroup", post(v2_rename_group))
+        .route("/SetPassword", post(v1_set_password))
+        .route("/v2/SetPassword", post(v2_set_password))
+        .route("/ReorderAccounts", post(v1_reorder_accounts))
+        .route("/v2/ReorderAccounts", post(v2_reorder_accounts))
+        .route("/ImportCookies", post(v1_import_cookies))
+        .route("/v2/ImportCookies", post(v2_import_cookies))
+        .layer(middleware::from_fn_with_state((), rate_limit))
         .layer(middleware::from_fn_with_state((), external_check))
         .layer(Extension(state))
 }
@@ -107,6 +132,7 @@ pub fn stop() -> Result<(), String> {
     match guard.take() {
         Some(handle) => {
             let _ = handle.shutdown_tx.send(true);
+            RATE_LIMITER.clear();
             Ok(())
         }
         None => Err("Web server is not running".into()),
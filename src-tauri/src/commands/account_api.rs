@@ -59,7 +59,9 @@ async fn refresh_cookie(
 #[tauri::command]
 async fn get_robux(state: tauri::State<'_, AccountStore>, user_id: i64) -> Result<i64, String> {
     let cookie = get_cookie(&state, user_id)?;
-    api::roblox::get_robux(&cookie).await
+    let robux = api::roblox::get_robux(&cookie).await?;
+    state.record_robux(user_id, robux)?;
+    Ok(robux)
 }
 
 #[tauri::command]
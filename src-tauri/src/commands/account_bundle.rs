@@ -0,0 +1,60 @@
+#[cfg(feature = "nexus")]
+fn nexus_bundle_settings(usernames: &[String]) -> Vec<serde_json::Value> {
+    nexus::websocket::nexus().export_account_settings(usernames)
+}
+
+#[cfg(not(feature = "nexus"))]
+fn nexus_bundle_settings(_usernames: &[String]) -> Vec<serde_json::Value> {
+    Vec::new()
+}
+
+#[cfg(feature = "nexus")]
+fn apply_nexus_bundle_settings(entries: Vec<serde_json::Value>, usernames: &[String]) -> usize {
+    nexus::websocket::nexus().import_account_settings(entries, usernames)
+}
+
+#[cfg(not(feature = "nexus"))]
+fn apply_nexus_bundle_settings(_entries: Vec<serde_json::Value>, _usernames: &[String]) -> usize {
+    0
+}
+
+#[tauri::command]
+fn export_accounts(
+    state: tauri::State<'_, AccountStore>,
+    user_ids: Vec<i64>,
+    password: String,
+    include_passwords: bool,
+    include_nexus: bool,
+) -> Result<String, String> {
+    let nexus = if include_nexus {
+        let usernames: Vec<String> = state
+            .get_all()?
+            .into_iter()
+            .filter(|a| user_ids.contains(&a.user_id))
+            .map(|a| a.username)
+            .collect();
+        nexus_bundle_settings(&usernames)
+    } else {
+        Vec::new()
+    };
+    let path = state.export_bundle(&user_ids, &password, include_passwords, nexus)?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+#[tauri::command]
+fn import_accounts(
+    state: tauri::State<'_, AccountStore>,
+    file_data: Vec<u8>,
+    password: String,
+    strategy: Option<String>,
+) -> Result<data::accounts::BundleImportSummary, String> {
+    let strategy = strategy.as_deref().unwrap_or("");
+    let strategy = data::accounts::ImportStrategy::from_setting(strategy)
+        .ok_or_else(|| format!("Unknown import strategy: {}", strategy))?;
+    let payload = AccountStore::read_bundle(&file_data, &password)?;
+    let mut summary = state.import_bundle(payload.accounts, strategy)?;
+    if !payload.nexus.is_empty() {
+        summary.nexus = apply_nexus_bundle_settings(payload.nexus, &summary.taken);
+    }
+    Ok(summary)
+}
@@ -0,0 +1,94 @@
+const IMPORT_VALIDATION_CONCURRENCY: usize = 4;
+
+/// Imports accounts from CSV (`format` = "csv") or a cookie list ("list"),
+/// validating every cookie concurrently. A dry run validates and reports
+/// without adding anything.
+#[tauri::command]
+async fn import_accounts_text(
+    state: tauri::State<'_, AccountStore>,
+    text: String,
+    format: String,
+    dry_run: bool,
+) -> Result<data::accounts::AccountImportReport, String> {
+    let (rows, mut results) = match format.as_str() {
+        "csv" => data::accounts::parse_account_csv(&text)?,
+        "list" => data::accounts::parse_account_list(&text),
+        other => return Err(format!("Unknown import format: {}", other)),
+    };
+    let existing: HashSet<i64> = state.get_all()?.iter().map(|a| a.user_id).collect();
+
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(IMPORT_VALIDATION_CONCURRENCY));
+    let mut tasks = tokio::task::JoinSet::new();
+    let mut task_failures = HashMap::new();
+    for (index, row) in rows.into_iter().enumerate() {
+        let permit = semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|e| e.to_string())?;
+        let failure = row.failed("Validation task failed");
+        let task = tasks.spawn(async move {
+            let info = api::auth::validate_cookie(&row.cookie).await;
+            drop(permit);
+            (index, row, info)
+        });
+        task_failures.insert(task.id(), failure);
+    }
+    let mut validated = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok(entry) => validated.push(entry),
+            Err(e) => results.extend(task_failures.remove(&e.id())),
+        }
+    }
+    validated.sort_by_key(|(index, _, _)| *index);
+
+    let mut seen = HashSet::new();
+    let mut accounts = Vec::new();
+    for (_, row, info) in validated {
+        let info = match info {
+            Ok(info) => info,
+            Err(e) => {
+                results.push(row.failed(e));
+                continue;
+            }
+        };
+        if !row.username.is_empty() && !row.username.eq_ignore_ascii_case(&info.name) {
+            results.push(row.failed(format!("Cookie belongs to {}", info.name)));
+            continue;
+        }
+        let exists = existing.contains(&info.user_id) || !seen.insert(info.user_id);
+        results.push(data::accounts::AccountImportRowResult {
+            line: row.line,
+            username: info.name.clone(),
+            user_id: Some(info.user_id),
+            status: match (exists, dry_run) {
+                (true, _) => "exists",
+                (false, true) => "ready",
+                (false, false) => "added",
+            },
+            message: String::new(),
+        });
+        if !exists {
+            accounts.push(row.into_account(info.name, info.user_id));
+        }
+    }
+
+    let mut report = data::accounts::AccountImportReport {
+        dry_run,
+        ..Default::default()
+    };
+    if !dry_run && !accounts.is_empty() {
+        state.add_imported(accounts)?;
+    }
+    results.sort_by_key(|r| r.line);
+    for result in &results {
+        match result.status {
+            "exists" => report.existing += 1,
+            "failed" => report.failed += 1,
+            _ => report.added += 1,
+        }
+    }
+    report.rows = results;
+    Ok(report)
+}
@@ -0,0 +1,157 @@
+const AUTO_LOCK_POLL_SECS: u64 = 15;
+// Consecutive polls that must see the session locked before acting on it.
+const SESSION_LOCK_POLLS: u32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AutoLockBottingPolicy {
+    Defer,
+    Stop,
+    Lock,
+}
+
+impl AutoLockBottingPolicy {
+    fn from_settings(settings: &SettingsStore) -> Self {
+        match settings
+            .get_string("General", "AutoLockBottingPolicy")
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "stop" => Self::Stop,
+            "lock" => Self::Lock,
+            _ => Self::Defer,
+        }
+    }
+}
+
+struct AutoLock {
+    running: std::sync::atomic::AtomicBool,
+    last_activity: std::sync::Mutex<std::time::Instant>,
+}
+
+static AUTO_LOCK: std::sync::LazyLock<AutoLock> = std::sync::LazyLock::new(|| AutoLock {
+    running: std::sync::atomic::AtomicBool::new(false),
+    last_activity: std::sync::Mutex::new(std::time::Instant::now()),
+});
+
+impl AutoLock {
+    fn touch(&self) {
+        if let Ok(mut last) = self.last_activity.lock() {
+            *last = std::time::Instant::now();
+        }
+    }
+
+    fn idle_for(&self) -> std::time::Duration {
+        self.last_activity
+            .lock()
+            .map(|last| last.elapsed())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn os_session_locked() -> bool {
+    platform::windows::is_session_locked()
+}
+
+#[cfg(target_os = "macos")]
+fn os_session_locked() -> bool {
+    platform::macos::is_session_locked()
+}
+
+// logind tracks the lock screen as the session's LockedHint.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn os_session_locked() -> bool {
+    let session = std::env::var("XDG_SESSION_ID").unwrap_or_else(|_| "auto".to_string());
+    std::process::Command::new("loginctl")
+        .args(["show-session", &session, "-p", "LockedHint", "--value"])
+        .output()
+        .is_ok_and(|out| out.status.success() && String::from_utf8_lossy(&out.stdout).trim() == "yes")
+}
+
+#[cfg(not(any(windows, unix)))]
+fn os_session_locked() -> bool {
+    false
+}
+
+/// Locks the account store and tells the UI. `reason` is "idle", "session"
+/// or "manual".
+fn lock_account_store(app: &AppHandle, reason: &str) -> Result<bool, String> {
+    let store = app.state::<AccountStore>();
+    let closed_vaults = store.close_all_vaults()?;
+    let locked = store.lock()?;
+    if locked {
+        let _ = app.emit("accounts-locked", serde_json::json!({ "reason": reason }));
+    } else if closed_vaults > 0 {
+        let _ = app.emit("vaults-closed", serde_json::json!({ "reason": reason }));
+    }
+    Ok(locked || closed_vaults > 0)
+}
+
+fn spawn_auto_lock(app: AppHandle) {
+    let auto_lock = &*AUTO_LOCK;
+    if auto_lock.running.swap(true, std::sync::atomic::Ordering::SeqCst) {
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        let mut session_locked_polls = 0;
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(AUTO_LOCK_POLL_SECS)).await;
+
+            let store = app.state::<AccountStore>();
+            let has_open_vaults = store.list_vaults().is_ok_and(|v| v.iter().any(|v| v.open));
+            if !has_open_vaults && (store.is_locked() || !store.is_encrypted().unwrap_or(false)) {
+                auto_lock.touch();
+                session_locked_polls = 0;
+                continue;
+            }
+
+            let (idle_minutes, on_session_lock, botting_policy) = {
+                let settings = app.state::<SettingsStore>();
+                (
+                    settings.get_int("General", "AutoLockMinutes").unwrap_or(0).max(0) as u64,
+                    settings.get_bool("General", "LockOnSessionLock"),
+                    AutoLockBottingPolicy::from_settings(&settings),
+                )
+            };
+
+            session_locked_polls = if on_session_lock && os_session_locked() {
+                session_locked_polls + 1
+            } else {
+                0
+            };
+            let reason = if session_locked_polls >= SESSION_LOCK_POLLS {
+                "session"
+            } else if idle_minutes > 0 && auto_lock.idle_for().as_secs() >= idle_minutes * 60 {
+                "idle"
+            } else {
+                continue;
+            };
+
+            let botting = get_botting_mode_status().is_ok_and(|status| status.active);
+            if botting {
+                match botting_policy {
+                    AutoLockBottingPolicy::Defer => continue,
+                    AutoLockBottingPolicy::Stop => {
+                        let _ = stop_botting_mode(app.clone(), false);
+                    }
+                    AutoLockBottingPolicy::Lock => {}
+                }
+            }
+
+            if let Err(e) = lock_account_store(&app, reason) {
+                eprintln!("Auto-lock failed: {}", e);
+            }
+        }
+    });
+}
+
+#[tauri::command]
+fn report_activity() {
+    AUTO_LOCK.touch();
+}
+
+#[tauri::command]
+fn lock_accounts(app: AppHandle) -> Result<bool, String> {
+    lock_account_store(&app, "manual")
+}
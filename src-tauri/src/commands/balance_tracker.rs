@@ -0,0 +1,286 @@
+const BALANCE_TRACK_CONCURRENCY: usize = 4;
+const BALANCE_TRACK_MIN_INTERVAL_MINUTES: u64 = 15;
+const BALANCE_TRACK_IDLE_POLL_SECS: u64 = 60;
+
+#[derive(Default)]
+struct BalanceTracker {
+    running: std::sync::atomic::AtomicBool,
+    seeded: std::sync::atomic::AtomicBool,
+    snapshot: tokio::sync::Mutex<()>,
+    wake: tokio::sync::Notify,
+}
+
+static BALANCE_TRACKER: std::sync::LazyLock<BalanceTracker> =
+    std::sync::LazyLock::new(BalanceTracker::default);
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BalanceSnapshotFailure {
+    user_id: i64,
+    username: String,
+    error: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BalanceSnapshotReport {
+    at: chrono::DateTime<chrono::Utc>,
+    recorded: usize,
+    failed: Vec<BalanceSnapshotFailure>,
+    drops: Vec<data::balance_history::BalanceDrop>,
+}
+
+fn balance_drop_threshold(settings: &SettingsStore) -> data::balance_history::DropThreshold {
+    data::balance_history::DropThreshold {
+        min_robux: settings
+            .get_int("General", "BalanceDropMinRobux")
+            .unwrap_or(500)
+            .max(1),
+        min_percent: settings
+            .get_float("General", "BalanceDropMinPercent")
+            .unwrap_or(0.0)
+            .max(0.0),
+        min_limiteds: settings
+            .get_int("General", "BalanceDropMinLimiteds")
+            .unwrap_or(1)
+            .max(0),
+    }
+}
+
+fn balance_history_since(
+    state: &AccountStore,
+    days: Option<i64>,
+) -> Result<Vec<data::balance_history::BalanceSample>, String> {
+    let mut history = state.balance_history()?;
+    if let Some(days) = days.filter(|d| *d > 0) {
+        let cutoff = chrono::Utc::now() - chrono::Duration::days(days);
+        history.retain(|s| s.at >= cutoff);
+    }
+    Ok(history)
+}
+
+fn seed_robux_balances(state: &AccountStore) -> Result<(), String> {
+    if !BALANCE_TRACKER
+        .seeded
+        .load(std::sync::atomic::Ordering::SeqCst)
+    {
+        state.seed_robux_balances()?;
+        BALANCE_TRACKER
+            .seeded
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+    Ok(())
+}
+
+/// Records the balance of every account with a cookie, plus premium status
+/// and limited counts when enabled, and reports drops since the previous
+/// sample of each account.
+async fn run_balance_snapshot(app: &AppHandle) -> Result<BalanceSnapshotReport, String> {
+    let _running = BALANCE_TRACKER
+        .snapshot
+        .try_lock()
+        .map_err(|_| "A balance snapshot is already running".to_string())?;
+
+    let (track_premium, track_limiteds, keep_days, threshold) = {
+        let settings = app.state::<SettingsStore>();
+        (
+            settings.get_bool("General", "BalanceTrackPremium"),
+            settings.get_bool("General", "BalanceTrackLimiteds"),
+            settings
+                .get_int("General", "BalanceHistoryDays")
+                .unwrap_or(90)
+                .max(0),
+            balance_drop_threshold(&settings),
+        )
+    };
+    let accounts: Vec<data::accounts::Account> = app
+        .state::<AccountStore>()
+        .get_all()?
+        .into_iter()
+        .filter(|a| !a.security_token.is_empty())
+        .collect();
+    if accounts.is_empty() {
+        return Err("No accounts to track".to_string());
+    }
+
+    let now = chrono::Utc::now();
+    let at = chrono::DateTime::from_timestamp(now.timestamp(), 0).unwrap_or(now);
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(BALANCE_TRACK_CONCURRENCY));
+    let mut tasks = tokio::task::JoinSet::new();
+    for account in accounts {
+        let permit = semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|e| e.to_string())?;
+        tasks.spawn(async move {
+            let token = &account.security_token;
+            let result = match api::roblox::get_robux(token).await {
+                Ok(robux) => Ok(data::balance_history::BalanceSample {
+                    at,
+                    user_id: account.user_id,
+                    robux,
+                    premium: if track_premium {
+                        api::roblox::get_premium_status(token, account.user_id)
+                            .await
+                            .ok()
+                    } else {
+                        None
+                    },
+                    limiteds: if track_limiteds {
+                        api::roblox::get_limited_count(token, account.user_id)
+                            .await
+                            .ok()
+                    } else {
+                        None
+                    },
+                }),
+                Err(error) => Err(BalanceSnapshotFailure {
+                    user_id: account.user_id,
+                    username: account.username.clone(),
+                    error,
+                }),
+            };
+            drop(permit);
+            result
+        });
+    }
+
+    let mut samples = Vec::new();
+    let mut failed = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok(Ok(sample)) => samples.push(sample),
+            Ok(Err(failure)) => failed.push(failure),
+            Err(e) => eprintln!("Balance snapshot task failed: {}", e),
+        }
+    }
+    samples.sort_by_key(|s| s.user_id);
+    failed.sort_by_key(|f| f.user_id);
+
+    let history = app
+        .state::<AccountStore>()
+        .record_balance_snapshot(&samples, keep_days)?;
+    let drops: Vec<_> = data::balance_history::find_drops(&history, &threshold)
+        .into_iter()
+        .filter(|d| d.to_at == at)
+        .collect();
+    if !drops.is_empty() {
+        let _ = app.emit("balance-drops", &drops);
+    }
+
+    Ok(BalanceSnapshotReport {
+        at,
+        recorded: samples.len(),
+        failed,
+        drops,
+    })
+}
+
+fn spawn_balance_tracker(app: AppHandle) {
+    let tracker = &*BALANCE_TRACKER;
+    if tracker
+        .running
+        .swap(true, std::sync::atomic::Ordering::SeqCst)
+    {
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let (enabled, interval_secs) = {
+                let settings = app.state::<SettingsStore>();
+                let minutes = settings
+                    .get_int("General", "BalanceTrackInterval")
+                    .unwrap_or(360)
+                    .max(BALANCE_TRACK_MIN_INTERVAL_MINUTES as i64)
+                    as u64;
+                (settings.get_bool("General", "TrackBalances"), minutes * 60)
+            };
+
+            let mut wait_secs = BALANCE_TRACK_IDLE_POLL_SECS;
+            let store = app.state::<AccountStore>();
+            if !store.is_locked() {
+                if let Err(e) = seed_robux_balances(&store) {
+                    eprintln!("Failed to load balance history: {}", e);
+                }
+                if enabled {
+                    let since_last = store
+                        .balance_history()
+                        .ok()
+                        .and_then(|h| h.last().map(|s| s.at))
+                        .map(|at| (chrono::Utc::now() - at).num_seconds().max(0) as u64);
+                    match since_last {
+                        Some(elapsed) if elapsed < interval_secs => {
+                            wait_secs = interval_secs - elapsed;
+                        }
+                        _ => {
+                            if let Err(e) = run_balance_snapshot(&app).await {
+                                eprintln!("Balance snapshot failed: {}", e);
+                            }
+                            wait_secs = interval_secs;
+                        }
+                    }
+                }
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(std::time::Duration::from_secs(wait_secs)) => {}
+                _ = tracker.wake.notified() => {}
+            }
+        }
+    });
+}
+
+#[tauri::command]
+async fn snapshot_balances(app: AppHandle) -> Result<BalanceSnapshotReport, String> {
+    run_balance_snapshot(&app).await
+}
+
+/// Wakes the tracker after its settings change.
+#[tauri::command]
+fn refresh_balance_tracker() {
+    BALANCE_TRACKER.wake.notify_one();
+}
+
+#[tauri::command]
+fn get_balance_history(
+    state: tauri::State<'_, AccountStore>,
+    user_id: Option<i64>,
+    days: Option<i64>,
+) -> Result<Vec<data::balance_history::BalanceSample>, String> {
+    let mut history = balance_history_since(&state, days)?;
+    if let Some(user_id) = user_id {
+        history.retain(|s| s.user_id == user_id);
+    }
+    Ok(history)
+}
+
+#[tauri::command]
+fn get_balance_totals(
+    state: tauri::State<'_, AccountStore>,
+    days: Option<i64>,
+) -> Result<Vec<data::balance_history::BalanceTotal>, String> {
+    Ok(data::balance_history::totals(&balance_history_since(
+        &state, days,
+    )?))
+}
+
+/// Drops within the last `days`, using the configured thresholds unless
+/// `min_robux` overrides the Robux amount.
+#[tauri::command]
+fn get_balance_drops(
+    state: tauri::State<'_, AccountStore>,
+    settings: tauri::State<'_, SettingsStore>,
+    days: Option<i64>,
+    min_robux: Option<i64>,
+) -> Result<Vec<data::balance_history::BalanceDrop>, String> {
+    let mut threshold = balance_drop_threshold(&settings);
+    if let Some(min_robux) = min_robux {
+        threshold.min_robux = min_robux.max(1);
+    }
+    Ok(data::balance_history::find_drops(
+        &balance_history_since(&state, days)?,
+        &threshold,
+    ))
+}
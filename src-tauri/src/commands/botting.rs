@@ -20,7 +20,7 @@ async fn launch_account_for_cycle(
     };
 
     let (
-        cookie,
+        mut cookie,
         is_teleport,
         use_old_join,
         auto_close_last_process,
@@ -41,6 +41,25 @@ async fn launch_account_for_cycle(
         )
     };
 
+    // Botting cannot wait for a confirmation, so a warning is surfaced to the
+    // UI and the cycle carries on; skip and kick are applied as configured.
+    let own_session = windows::tracker().get_pid(user_id).is_some();
+    let presence = {
+        let state = app.state::<AccountStore>();
+        let settings = app.state::<SettingsStore>();
+        check_launch_presence(&state, &settings, user_id, &mut cookie, own_session, false).await
+    };
+    if let Err(blocked) = presence {
+        emit_presence_warning(app, &blocked);
+        let warned = blocked
+            .presence
+            .as_ref()
+            .is_some_and(|c| c.decision == api::launch_guard::PresenceDecision::Warned);
+        if !warned {
+            return Err(blocked.error.unwrap_or_default());
+        }
+    }
+
     let resolved_launch = resolve_launch_job(job_id, false, "");
 
     if multi_rbx {
@@ -500,9 +519,10 @@ async fn run_botting_session(
 
 #[cfg(target_os = "windows")]
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 async fn start_botting_mode(
     app: tauri::AppHandle,
-    _state: tauri::State<'_, AccountStore>,
+    state: tauri::State<'_, AccountStore>,
     settings: tauri::State<'_, SettingsStore>,
     user_ids: Vec<i64>,
     place_id: i64,
@@ -512,7 +532,9 @@ async fn start_botting_mode(
     interval_minutes: i64,
     launch_delay_seconds: i64,
     player_grace_minutes: i64,
+    query: Option<String>,
 ) -> Result<BottingStatusPayload, String> {
+    let user_ids = state.resolve_selection(&user_ids, query.as_deref())?;
     if user_ids.len() < 2 {
         return Err("Select at least two accounts for Botting Mode".into());
     }
@@ -642,6 +664,7 @@ async fn start_botting_mode(
 
 #[cfg(not(target_os = "windows"))]
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 async fn start_botting_mode(
     _app: tauri::AppHandle,
     _state: tauri::State<'_, AccountStore>,
@@ -654,6 +677,7 @@ async fn start_botting_mode(
     _interval_minutes: i64,
     _launch_delay_seconds: i64,
     _player_grace_minutes: i64,
+    _query: Option<String>,
 ) -> Result<BottingStatusPayload, String> {
     Err("Botting Mode is only supported on Windows".into())
 }
@@ -707,10 +731,12 @@ fn add_botting_accounts(
     app: tauri::AppHandle,
     state: tauri::State<'_, AccountStore>,
     user_ids: Vec<i64>,
+    query: Option<String>,
 ) -> Result<BottingStatusPayload, String> {
     let Some(session) = BOTTING_MANAGER.get_session() else {
         return Err("Botting Mode is not running".into());
     };
+    let user_ids = state.resolve_selection(&user_ids, query.as_deref())?;
     if user_ids.is_empty() {
         return Err("Select at least one account to add".into());
     }
@@ -797,6 +823,7 @@ fn add_botting_accounts(
     _app: tauri::AppHandle,
     _state: tauri::State<'_, AccountStore>,
     _user_ids: Vec<i64>,
+    _query: Option<String>,
 ) -> Result<BottingStatusPayload, String> {
     Err("Botting Mode is only supported on Windows".into())
 }
@@ -11,10 +11,24 @@ async fn launch_roblox(
     join_vip: bool,
     link_code: String,
     shuffle_job: bool,
-) -> Result<(), String> {
+    presence_confirmed: Option<bool>,
+) -> Result<LaunchResult, String> {
     use platform::windows;
 
-    let cookie = get_cookie(&state, user_id)?;
+    let mut cookie = get_cookie(&state, user_id)?;
+    let presence = match check_launch_presence(
+        &state,
+        &settings,
+        user_id,
+        &mut cookie,
+        windows::tracker().get_pid(user_id).is_some(),
+        presence_confirmed.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(check) => check,
+        Err(blocked) => return Ok(blocked),
+    };
     let is_teleport = settings.get_bool("Developer", "IsTeleport");
     let use_old_join = settings.get_bool("Developer", "UseOldJoin");
     let auto_close_last_process = settings.get_bool("General", "AutoCloseLastProcess");
@@ -140,7 +154,7 @@ async fn launch_roblox(
         }
     }
 
-    Ok(())
+    Ok(LaunchResult::launched(user_id, presence))
 }
 
 #[cfg(not(target_os = "windows"))]
@@ -156,12 +170,26 @@ async fn launch_roblox(
     join_vip: bool,
     link_code: String,
     shuffle_job: bool,
-) -> Result<(), String> {
+    presence_confirmed: Option<bool>,
+) -> Result<LaunchResult, String> {
     #[cfg(target_os = "macos")]
     {
         use platform::macos;
 
-        let cookie = get_cookie(&state, user_id)?;
+        let mut cookie = get_cookie(&state, user_id)?;
+        let presence = match check_launch_presence(
+            &state,
+            &settings,
+            user_id,
+            &mut cookie,
+            macos::tracker().get_pid(user_id).is_some(),
+            presence_confirmed.unwrap_or(false),
+        )
+        .await
+        {
+            Ok(check) => check,
+            Err(blocked) => return Ok(blocked),
+        };
         let is_teleport = settings.get_bool("Developer", "IsTeleport");
         let use_old_join = settings.get_bool("Developer", "UseOldJoin");
         let auto_close_last_process = settings.get_bool("General", "AutoCloseLastProcess");
@@ -249,7 +277,7 @@ async fn launch_roblox(
             tracker.track(user_id, pid, browser_tracker_id);
         }
 
-        return Ok(());
+        return Ok(LaunchResult::launched(user_id, presence));
     }
 
     #[cfg(all(not(target_os = "windows"), not(target_os = "macos")))]
@@ -265,6 +293,7 @@ async fn launch_roblox(
             join_vip,
             link_code,
             shuffle_job,
+            presence_confirmed,
         );
         Err("Launching is only supported on Windows and macOS".into())
     }
@@ -272,6 +301,7 @@ async fn launch_roblox(
 
 #[cfg(target_os = "windows")]
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 async fn launch_multiple(
     app: tauri::AppHandle,
     state: tauri::State<'_, AccountStore>,
@@ -280,9 +310,13 @@ async fn launch_multiple(
     place_id: i64,
     job_id: String,
     launch_data: String,
-) -> Result<(), String> {
+    presence_confirmed: Option<bool>,
+    query: Option<String>,
+) -> Result<Vec<LaunchResult>, String> {
     use platform::windows;
 
+    let user_ids = state.resolve_selection(&user_ids, query.as_deref())?;
+
     let delay = settings.get_int("General", "AccountJoinDelay").unwrap_or(8) as u64;
     let multi_rbx = settings.get_bool("General", "EnableMultiRbx");
     let delay = if multi_rbx { delay.max(12) } else { delay };
@@ -296,6 +330,8 @@ async fn launch_multiple(
     tracker.reset_launch_cancelled();
 
     let accounts = state.get_all()?;
+    let confirmed = presence_confirmed.unwrap_or(false);
+    let mut results = Vec::with_capacity(user_ids.len());
 
     for (i, &uid) in user_ids.iter().enumerate() {
         if tracker.is_launch_cancelled() {
@@ -321,9 +357,29 @@ async fn launch_multiple(
             }),
         );
 
-        let cookie = match get_cookie(&state, uid) {
+        let mut cookie = match get_cookie(&state, uid) {
             Ok(c) => c,
-            Err(_) => continue,
+            Err(e) => {
+                results.push(LaunchResult::failed(uid, None, e));
+                continue;
+            }
+        };
+        let presence = match check_launch_presence(
+            &state,
+            &settings,
+            uid,
+            &mut cookie,
+            tracker.get_pid(uid).is_some(),
+            confirmed,
+        )
+        .await
+        {
+            Ok(check) => check,
+            Err(blocked) => {
+                emit_presence_warning(&app, &blocked);
+                results.push(blocked);
+                continue;
+            }
         };
 
         let resolved_launch = resolve_launch_job(&acct_job, false, "");
@@ -339,6 +395,11 @@ async fn launch_multiple(
         if auto_close_last_process && tracker.get_pid(uid).is_some() {
             let closed = tracker.kill_for_user_graceful(uid, 4500);
             if !closed {
+                results.push(LaunchResult::failed(
+                    uid,
+                    Some(presence),
+                    "Previous Roblox instance did not close before relaunch".into(),
+                ));
                 tokio::time::sleep(std::time::Duration::from_secs(2)).await;
                 continue;
             }
@@ -348,14 +409,16 @@ async fn launch_multiple(
         let browser_tracker_id = get_or_create_browser_tracker_id(&state, uid)?;
         let ticket = match api::auth::get_auth_ticket(&cookie).await {
             Ok(t) => t,
-            Err(_) => {
+            Err(e) => {
+                results.push(LaunchResult::failed(uid, Some(presence), e));
                 tokio::time::sleep(std::time::Duration::from_secs(2)).await;
                 continue;
             }
         };
         let private_join = match resolve_private_join(&cookie, acct_place, &resolved_launch).await {
             Ok(value) => value,
-            Err(_) => {
+            Err(e) => {
+                results.push(LaunchResult::failed(uid, Some(presence), e));
                 tokio::time::sleep(std::time::Duration::from_secs(2)).await;
                 continue;
             }
@@ -391,10 +454,12 @@ async fn launch_multiple(
             windows::launch_url(&url)
         };
 
-        if launch_result.is_err() {
+        if let Err(e) = launch_result {
+            results.push(LaunchResult::failed(uid, Some(presence), e));
             tokio::time::sleep(std::time::Duration::from_secs(2)).await;
             continue;
         }
+        results.push(LaunchResult::launched(uid, presence));
 
         if let Some(pid) = wait_for_new_roblox_pid(&pids_before, std::time::Duration::from_secs(12)).await
         {
@@ -424,11 +489,12 @@ async fn launch_multiple(
     }
 
     let _ = app.emit("launch-complete", serde_json::json!({}));
-    Ok(())
+    Ok(results)
 }
 
 #[cfg(not(target_os = "windows"))]
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 async fn launch_multiple(
     app: tauri::AppHandle,
     state: tauri::State<'_, AccountStore>,
@@ -437,11 +503,15 @@ async fn launch_multiple(
     place_id: i64,
     job_id: String,
     launch_data: String,
-) -> Result<(), String> {
+    presence_confirmed: Option<bool>,
+    query: Option<String>,
+) -> Result<Vec<LaunchResult>, String> {
     #[cfg(target_os = "macos")]
     {
         use platform::macos;
 
+        let user_ids = state.resolve_selection(&user_ids, query.as_deref())?;
+
         let delay = settings.get_int("General", "AccountJoinDelay").unwrap_or(8) as u64;
         let multi_rbx = settings.get_bool("General", "EnableMultiRbx");
         let delay = if multi_rbx { delay.max(12) } else { delay };
@@ -453,6 +523,8 @@ async fn launch_multiple(
         tracker.reset_launch_cancelled();
 
         let accounts = state.get_all()?;
+        let confirmed = presence_confirmed.unwrap_or(false);
+        let mut results = Vec::with_capacity(user_ids.len());
 
         for (i, &uid) in user_ids.iter().enumerate() {
             if tracker.is_launch_cancelled() {
@@ -478,9 +550,29 @@ async fn launch_multiple(
                 }),
             );
 
-            let cookie = match get_cookie(&state, uid) {
+            let mut cookie = match get_cookie(&state, uid) {
                 Ok(c) => c,
-                Err(_) => continue,
+                Err(e) => {
+                    results.push(LaunchResult::failed(uid, None, e));
+                    continue;
+                }
+            };
+            let presence = match check_launch_presence(
+                &state,
+                &settings,
+                uid,
+                &mut cookie,
+                tracker.get_pid(uid).is_some(),
+                confirmed,
+            )
+            .await
+            {
+                Ok(check) => check,
+                Err(blocked) => {
+                    emit_presence_warning(&app, &blocked);
+                    results.push(blocked);
+                    continue;
+                }
             };
 
             let resolved_launch = resolve_launch_job(&acct_job, false, "");
@@ -507,7 +599,8 @@ async fn launch_multiple(
             let browser_tracker_id = get_or_create_browser_tracker_id(&state, uid)?;
             let ticket = match api::auth::get_auth_ticket(&cookie).await {
                 Ok(t) => t,
-                Err(_) => {
+                Err(e) => {
+                    results.push(LaunchResult::failed(uid, Some(presence), e));
                     tokio::time::sleep(std::time::Duration::from_secs(2)).await;
                     continue;
                 }
@@ -515,7 +608,8 @@ async fn launch_multiple(
             let private_join = match resolve_private_join(&cookie, acct_place, &resolved_launch).await
             {
                 Ok(value) => value,
-                Err(_) => {
+                Err(e) => {
+                    results.push(LaunchResult::failed(uid, Some(presence), e));
                     tokio::time::sleep(std::time::Duration::from_secs(2)).await;
                     continue;
                 }
@@ -551,10 +645,12 @@ async fn launch_multiple(
                 macos::launch_url(&url)
             };
 
-            if launch_result.is_err() {
+            if let Err(e) = launch_result {
+                results.push(LaunchResult::failed(uid, Some(presence), e));
                 tokio::time::sleep(std::time::Duration::from_secs(2)).await;
                 continue;
             }
+            results.push(LaunchResult::launched(uid, presence));
 
             if let Some(pid) =
                 wait_for_new_roblox_pid(&pids_before, std::time::Duration::from_secs(12)).await
@@ -579,12 +675,22 @@ async fn launch_multiple(
         }
 
         let _ = app.emit("launch-complete", serde_json::json!({}));
-        return Ok(());
+        return Ok(results);
     }
 
     #[cfg(all(not(target_os = "windows"), not(target_os = "macos")))]
     {
-        let _ = (app, state, settings, user_ids, place_id, job_id, launch_data);
+        let _ = (
+            app,
+            state,
+            settings,
+            user_ids,
+            place_id,
+            job_id,
+            launch_data,
+            presence_confirmed,
+            query,
+        );
         Err("Launching is only supported on Windows and macOS".into())
     }
 }
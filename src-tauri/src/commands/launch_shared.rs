@@ -332,6 +332,67 @@ async fn wait_for_new_roblox_pid(pids_before: &[u32], timeout: std::time::Durati
     }
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LaunchResult {
+    user_id: i64,
+    launched: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    presence: Option<api::launch_guard::PresenceCheck>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl LaunchResult {
+    fn failed(user_id: i64, presence: Option<api::launch_guard::PresenceCheck>, error: String) -> Self {
+        Self {
+            user_id,
+            launched: false,
+            presence,
+            error: Some(error),
+        }
+    }
+
+    fn launched(user_id: i64, presence: api::launch_guard::PresenceCheck) -> Self {
+        Self {
+            user_id,
+            launched: true,
+            presence: Some(presence),
+            error: None,
+        }
+    }
+}
+
+async fn check_launch_presence(
+    state: &AccountStore,
+    settings: &SettingsStore,
+    user_id: i64,
+    cookie: &mut String,
+    own_session: bool,
+    confirmed: bool,
+) -> Result<api::launch_guard::PresenceCheck, LaunchResult> {
+    let policy = api::launch_guard::OnlineJoinPolicy::from_settings(settings);
+    let check =
+        api::launch_guard::check_before_launch(state, policy, user_id, cookie, own_session, confirmed)
+            .await;
+    if check.allows_launch() {
+        Ok(check)
+    } else {
+        let message = check.blocked_message();
+        Err(LaunchResult::failed(user_id, Some(check), message))
+    }
+}
+
+fn emit_presence_warning(app: &tauri::AppHandle, result: &LaunchResult) {
+    if let Some(check) = result
+        .presence
+        .as_ref()
+        .filter(|c| c.decision == api::launch_guard::PresenceDecision::Warned)
+    {
+        let _ = app.emit("launch-presence-warning", check);
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 struct BottingAccountStatusPayload {
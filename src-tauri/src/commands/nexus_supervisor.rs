@@ -0,0 +1,363 @@
+#[cfg(feature = "nexus")]
+fn spawn_nexus_supervisors(app: tauri::AppHandle) {
+    spawn_nexus_relaunch_supervisor(app.clone());
+    spawn_nexus_heartbeat_monitor(app.clone());
+    spawn_nexus_instance_controller(app);
+}
+
+#[cfg(feature = "nexus")]
+fn nexus_account_user_id(app: &tauri::AppHandle, username: &str) -> Result<i64, String> {
+    app.state::<AccountStore>()
+        .get_all()?
+        .iter()
+        .find(|a| a.username.eq_ignore_ascii_case(username))
+        .map(|a| a.user_id)
+        .ok_or_else(|| format!("{} is not in the account list", username))
+}
+
+#[cfg(feature = "nexus")]
+fn spawn_nexus_relaunch_supervisor(app: tauri::AppHandle) {
+    let server = nexus::websocket::nexus();
+    if !server.try_start_relaunch_supervisor() {
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        let mut last_launch: Option<std::time::Instant> = None;
+
+        while server.is_running() {
+            let (default_delay, launcher_delay, auto_close, max_instances) = {
+                let settings = app.state::<SettingsStore>();
+                (
+                    settings
+                        .get_float("AccountControl", "RelaunchDelay")
+                        .unwrap_or(60.0),
+                    settings
+                        .get_float("AccountControl", "LauncherDelay")
+                        .unwrap_or(9.0)
+                        .max(0.0),
+                    settings.get_bool("AccountControl", "AutoCloseEnabled"),
+                    settings
+                        .get_int("AccountControl", "MaxInstances")
+                        .unwrap_or(0)
+                        .max(0) as usize,
+                )
+            };
+
+            let due = if server.is_internet_available() {
+                server.take_due_relaunches(default_delay)
+            } else {
+                Vec::new()
+            };
+
+            for target in due {
+                if let Some(last) = last_launch {
+                    let spacing = std::time::Duration::try_from_secs_f64(launcher_delay)
+                        .unwrap_or(std::time::Duration::from_secs(9));
+                    if let Some(remaining) = spacing.checked_sub(last.elapsed()) {
+                        tokio::time::sleep(remaining).await;
+                    }
+                }
+                if !server.is_running() {
+                    break;
+                }
+
+                // MaxInstances belongs to Auto Close, as in the instance controller.
+                if auto_close
+                    && max_instances > 0
+                    && controlled_roblox_instances(&app).len() >= max_instances
+                {
+                    server.report_relaunch(
+                        &target.username,
+                        &Err(format!("MaxInstances ({}) reached", max_instances)),
+                        &app,
+                    );
+                    continue;
+                }
+
+                let result = relaunch_nexus_account(&app, &target).await;
+                last_launch = Some(std::time::Instant::now());
+                server.report_relaunch(&target.username, &result, &app);
+            }
+
+            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        }
+
+        server.finish_relaunch_supervisor();
+    });
+}
+
+#[cfg(feature = "nexus")]
+async fn relaunch_nexus_account(
+    app: &tauri::AppHandle,
+    target: &nexus::websocket::RelaunchTarget,
+) -> Result<(), String> {
+    let user_id = nexus_account_user_id(app, &target.username)?;
+
+    // Relaunches run unattended, so a warn policy is treated as confirmed;
+    // skip and kick still apply.
+    let result = launch_roblox(
+        app.state::<AccountStore>(),
+        app.state::<SettingsStore>(),
+        user_id,
+        target.place_id,
+        target.job_id.clone(),
+        String::new(),
+        false,
+        false,
+        String::new(),
+        false,
+        Some(true),
+    )
+    .await?;
+
+    match result.error {
+        Some(error) if !result.launched => Err(error),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(feature = "nexus")]
+fn spawn_nexus_heartbeat_monitor(app: tauri::AppHandle) {
+    let server = nexus::websocket::nexus();
+    if !server.try_start_heartbeat_monitor() {
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        while server.is_running() {
+            let (timeout, kill_stale) = {
+                let settings = app.state::<SettingsStore>();
+                (
+                    settings
+                        .get_float("AccountControl", "HeartbeatTimeout")
+                        .unwrap_or(30.0),
+                    settings.get_bool("AccountControl", "KillStaleClients"),
+                )
+            };
+
+            if timeout > 0.0 {
+                let timeout = std::time::Duration::try_from_secs_f64(timeout)
+                    .unwrap_or(std::time::Duration::from_secs(30));
+                let stale = server.reap_stale_connections(timeout, &app);
+                if kill_stale {
+                    for username in stale {
+                        if let Ok(user_id) = nexus_account_user_id(&app, &username) {
+                            kill_tracked_roblox(user_id);
+                        }
+                    }
+                }
+            }
+
+            server.probe_latency();
+            tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+        }
+
+        server.finish_heartbeat_monitor();
+    });
+}
+
+#[cfg(feature = "nexus")]
+fn kill_tracked_roblox(user_id: i64) -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        platform::windows::tracker().kill_for_user(user_id)
+    }
+    #[cfg(target_os = "macos")]
+    {
+        platform::macos::tracker().kill_for_user(user_id)
+    }
+    #[cfg(all(not(target_os = "windows"), not(target_os = "macos")))]
+    {
+        let _ = user_id;
+        false
+    }
+}
+
+/// MaxInstances belongs to the Auto Close settings and only applies while
+/// auto close is on, closing the newest clients over the cap. A "Per
+/// Instance" policy closes each client once it has run for the interval;
+/// "Global" closes every controlled client together once per interval.
+#[cfg(feature = "nexus")]
+struct InstanceControlConfig {
+    max_instances: usize,
+    auto_minimize: bool,
+    auto_minimize_interval: std::time::Duration,
+    auto_close: bool,
+    auto_close_interval: std::time::Duration,
+    auto_close_global: bool,
+    internet_check: bool,
+}
+
+#[cfg(feature = "nexus")]
+fn load_instance_control_config(settings: &SettingsStore) -> InstanceControlConfig {
+    let seconds = |key: &str, default: i64, scale: u64| {
+        std::time::Duration::from_secs(
+            settings.get_int("AccountControl", key).unwrap_or(default).max(1) as u64 * scale,
+        )
+    };
+    InstanceControlConfig {
+        max_instances: settings
+            .get_int("AccountControl", "MaxInstances")
+            .unwrap_or(0)
+            .max(0) as usize,
+        auto_minimize: settings.get_bool("AccountControl", "AutoMinimizeEnabled"),
+        auto_minimize_interval: seconds("AutoMinimizeInterval", 15, 1),
+        auto_close: settings.get_bool("AccountControl", "AutoCloseEnabled"),
+        auto_close_interval: seconds("AutoCloseInterval", 5, 60),
+        auto_close_global: settings.get_int("AccountControl", "AutoCloseType").unwrap_or(0) == 1,
+        internet_check: settings.get_bool("AccountControl", "InternetCheck"),
+    }
+}
+
+#[cfg(feature = "nexus")]
+fn spawn_nexus_instance_controller(app: tauri::AppHandle) {
+    let server = nexus::websocket::nexus();
+    if !server.try_start_instance_controller() {
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        let mut first_seen: HashMap<(i64, u32), std::time::Instant> = HashMap::new();
+        let mut last_minimize: Option<std::time::Instant> = None;
+        let mut last_internet_check: Option<std::time::Instant> = None;
+        let mut last_global_close = std::time::Instant::now();
+
+        while server.is_running() {
+            let cfg = {
+                let settings = app.state::<SettingsStore>();
+                load_instance_control_config(settings.inner())
+            };
+
+            if !cfg.internet_check {
+                server.set_internet_available(true, &app);
+            } else if last_internet_check
+                .is_none_or(|t| t.elapsed() >= std::time::Duration::from_secs(15))
+            {
+                last_internet_check = Some(std::time::Instant::now());
+                let available = check_internet_connection().await;
+                server.set_internet_available(available, &app);
+            }
+
+            let instances = controlled_roblox_instances(&app);
+            first_seen.retain(|key, _| instances.contains(key));
+            for key in &instances {
+                first_seen.entry(*key).or_insert_with(std::time::Instant::now);
+            }
+
+            let mut by_age: Vec<((i64, u32), std::time::Instant)> =
+                first_seen.iter().map(|(k, v)| (*k, *v)).collect();
+            by_age.sort_by_key(|(_, seen)| *seen);
+
+            let mut to_close: Vec<i64> = Vec::new();
+            if cfg.auto_close {
+                if cfg.max_instances > 0 && by_age.len() > cfg.max_instances {
+                    to_close.extend(by_age[cfg.max_instances..].iter().map(|((uid, _), _)| *uid));
+                }
+                if cfg.auto_close_global {
+                    if last_global_close.elapsed() >= cfg.auto_close_interval {
+                        last_global_close = std::time::Instant::now();
+                        to_close.extend(by_age.iter().map(|((uid, _), _)| *uid));
+                    }
+                } else {
+                    to_close.extend(
+                        by_age
+                            .iter()
+                            .filter(|(_, seen)| seen.elapsed() >= cfg.auto_close_interval)
+                            .map(|((uid, _), _)| *uid),
+                    );
+                }
+            } else {
+                last_global_close = std::time::Instant::now();
+            }
+
+            to_close.sort_unstable();
+            to_close.dedup();
+            for user_id in &to_close {
+                if kill_tracked_roblox(*user_id) {
+                    first_seen.retain(|(uid, _), _| uid != user_id);
+                }
+            }
+
+            if cfg.auto_minimize
+                && last_minimize.is_none_or(|t| t.elapsed() >= cfg.auto_minimize_interval)
+            {
+                last_minimize = Some(std::time::Instant::now());
+                for (_, pid) in first_seen.keys() {
+                    minimize_roblox_pid(*pid);
+                }
+            }
+
+            tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+        }
+
+        server.finish_instance_controller();
+    });
+}
+
+#[cfg(feature = "nexus")]
+async fn check_internet_connection() -> bool {
+    let Ok(client) = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(5))
+        .build()
+    else {
+        return false;
+    };
+    client.head("https://www.roblox.com/").send().await.is_ok()
+}
+
+#[cfg(feature = "nexus")]
+fn controlled_roblox_instances(app: &tauri::AppHandle) -> Vec<(i64, u32)> {
+    let usernames = nexus::websocket::nexus().controlled_usernames();
+    let Ok(accounts) = app.state::<AccountStore>().get_all() else {
+        return Vec::new();
+    };
+    let user_ids: HashSet<i64> = accounts
+        .iter()
+        .filter(|a| usernames.iter().any(|u| u.eq_ignore_ascii_case(&a.username)))
+        .map(|a| a.user_id)
+        .collect();
+
+    tracked_roblox_instances()
+        .into_iter()
+        .filter(|(uid, _)| user_ids.contains(uid))
+        .collect()
+}
+
+#[cfg(feature = "nexus")]
+fn tracked_roblox_instances() -> Vec<(i64, u32)> {
+    #[cfg(target_os = "windows")]
+    {
+        platform::windows::tracker()
+            .get_all()
+            .into_iter()
+            .map(|p| (p.user_id, p.pid))
+            .collect()
+    }
+    #[cfg(target_os = "macos")]
+    {
+        platform::macos::tracker()
+            .get_all()
+            .into_iter()
+            .map(|p| (p.user_id, p.pid))
+            .collect()
+    }
+    #[cfg(all(not(target_os = "windows"), not(target_os = "macos")))]
+    {
+        Vec::new()
+    }
+}
+
+#[cfg(feature = "nexus")]
+fn minimize_roblox_pid(pid: u32) {
+    #[cfg(target_os = "windows")]
+    {
+        if let Some(hwnd) = platform::windows::find_main_window(pid) {
+            let _ = platform::windows::minimize_window(hwnd);
+        }
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = pid;
+    }
+}
@@ -0,0 +1,193 @@
+const PRESENCE_BATCH_SIZE: usize = 100;
+const PRESENCE_HISTORY_LIMIT: usize = 50;
+const PRESENCE_MIN_INTERVAL_SECS: u64 = 30;
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PresenceSnapshot {
+    user_id: i64,
+    presence: api::roblox::UserPresence,
+    updated_at_ms: i64,
+    last_in_game_ms: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PresenceChange {
+    user_id: i64,
+    at_ms: i64,
+    previous_type: Option<i32>,
+    presence_type: i32,
+    previous_place_id: Option<i64>,
+    place_id: Option<i64>,
+    game_id: Option<String>,
+    last_location: String,
+}
+
+#[derive(Default)]
+struct PresenceMonitor {
+    running: std::sync::atomic::AtomicBool,
+    latest: std::sync::Mutex<HashMap<i64, PresenceSnapshot>>,
+    history: std::sync::Mutex<HashMap<i64, std::collections::VecDeque<PresenceChange>>>,
+    followed: std::sync::Mutex<HashSet<i64>>,
+    wake: tokio::sync::Notify,
+}
+
+static PRESENCE_MONITOR: std::sync::LazyLock<PresenceMonitor> =
+    std::sync::LazyLock::new(PresenceMonitor::default);
+
+impl PresenceMonitor {
+    fn apply(&self, presences: Vec<api::roblox::UserPresence>) -> Vec<PresenceChange> {
+        let now = now_ms();
+        let mut changes = Vec::new();
+        let mut latest = match self.latest.lock() {
+            Ok(guard) => guard,
+            Err(_) => return changes,
+        };
+
+        for presence in presences {
+            let user_id = presence.user_id;
+            let previous = latest.get(&user_id);
+            let changed = previous.is_none_or(|p| {
+                p.presence.user_presence_type != presence.user_presence_type
+                    || p.presence.place_id != presence.place_id
+                    || p.presence.game_id != presence.game_id
+            });
+
+            let last_in_game_ms = if presence.user_presence_type == 2 {
+                Some(now)
+            } else {
+                previous.and_then(|p| p.last_in_game_ms)
+            };
+
+            if changed {
+                changes.push(PresenceChange {
+                    user_id,
+                    at_ms: now,
+                    previous_type: previous.map(|p| p.presence.user_presence_type),
+                    presence_type: presence.user_presence_type,
+                    previous_place_id: previous.and_then(|p| p.presence.place_id),
+                    place_id: presence.place_id,
+                    game_id: presence.game_id.clone(),
+                    last_location: presence.last_location.clone(),
+                });
+            }
+
+            latest.insert(
+                user_id,
+                PresenceSnapshot {
+                    user_id,
+                    presence,
+                    updated_at_ms: now,
+                    last_in_game_ms,
+                },
+            );
+        }
+        drop(latest);
+
+        if let Ok(mut history) = self.history.lock() {
+            for change in &changes {
+                let entries = history.entry(change.user_id).or_default();
+                if entries.len() >= PRESENCE_HISTORY_LIMIT {
+                    entries.pop_front();
+                }
+                entries.push_back(change.clone());
+            }
+        }
+
+        changes
+    }
+
+    fn tracked_user_ids(&self, app: &tauri::AppHandle) -> Vec<i64> {
+        let mut ids: Vec<i64> = app
+            .state::<AccountStore>()
+            .get_all()
+            .map(|accounts| accounts.iter().map(|a| a.user_id).collect())
+            .unwrap_or_default();
+        if let Ok(followed) = self.followed.lock() {
+            ids.extend(followed.iter().copied());
+        }
+        ids.retain(|id| *id > 0);
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+}
+
+fn spawn_presence_monitor(app: tauri::AppHandle) {
+    let monitor = &*PRESENCE_MONITOR;
+    if monitor.running.swap(true, std::sync::atomic::Ordering::SeqCst) {
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let (enabled, interval_secs) = {
+                let settings = app.state::<SettingsStore>();
+                let minutes = settings
+                    .get_int("General", "PresenceUpdateRate")
+                    .unwrap_or(5)
+                    .max(1) as u64;
+                (
+                    settings.get_bool("General", "ShowPresence"),
+                    (minutes * 60).max(PRESENCE_MIN_INTERVAL_SECS),
+                )
+            };
+
+            let has_followed = monitor.followed.lock().is_ok_and(|f| !f.is_empty());
+            if enabled || has_followed {
+                let user_ids = monitor.tracked_user_ids(&app);
+                let mut changes = Vec::new();
+                for chunk in user_ids.chunks(PRESENCE_BATCH_SIZE) {
+                    match api::roblox::get_presence(chunk).await {
+                        Ok(presences) => changes.extend(monitor.apply(presences)),
+                        Err(e) => {
+                            eprintln!("Presence poll failed: {}", e);
+                            break;
+                        }
+                    }
+                }
+                if !changes.is_empty() {
+                    let _ = app.emit("presence-changed", &changes);
+                }
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(std::time::Duration::from_secs(interval_secs)) => {}
+                _ = monitor.wake.notified() => {}
+            }
+        }
+    });
+}
+
+#[tauri::command]
+fn get_cached_presence() -> Result<Vec<PresenceSnapshot>, String> {
+    let latest = PRESENCE_MONITOR.latest.lock().map_err(|e| e.to_string())?;
+    Ok(latest.values().cloned().collect())
+}
+
+#[tauri::command]
+fn get_presence_history(user_id: Option<i64>) -> Result<Vec<PresenceChange>, String> {
+    let history = PRESENCE_MONITOR.history.lock().map_err(|e| e.to_string())?;
+    let mut changes: Vec<PresenceChange> = history
+        .iter()
+        .filter(|(id, _)| user_id.is_none_or(|u| u == **id))
+        .flat_map(|(_, entries)| entries.iter().cloned())
+        .collect();
+    changes.sort_by_key(|c| c.at_ms);
+    Ok(changes)
+}
+
+#[tauri::command]
+fn set_followed_presence(user_ids: Vec<i64>) -> Result<(), String> {
+    let mut followed = PRESENCE_MONITOR.followed.lock().map_err(|e| e.to_string())?;
+    *followed = user_ids.into_iter().filter(|id| *id > 0).collect();
+    drop(followed);
+    PRESENCE_MONITOR.wake.notify_one();
+    Ok(())
+}
+
+#[tauri::command]
+fn refresh_presence() {
+    PRESENCE_MONITOR.wake.notify_one();
+}
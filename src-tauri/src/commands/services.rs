@@ -16,6 +16,18 @@ type NexusAccountViewResponse = nexus::websocket::AccountView;
 #[cfg(feature = "nexus")]
 type NexusElementResponse = nexus::websocket::CustomElement;
 
+#[cfg(feature = "nexus")]
+type NexusLogEntryResponse = nexus::websocket::NexusLogEntry;
+
+#[cfg(feature = "nexus")]
+type NexusLogFilterRequest = nexus::websocket::NexusLogFilter;
+
+#[cfg(not(feature = "nexus"))]
+type NexusLogEntryResponse = serde_json::Value;
+
+#[cfg(not(feature = "nexus"))]
+type NexusLogFilterRequest = serde_json::Value;
+
 #[cfg(not(feature = "nexus"))]
 #[derive(Debug, Clone, serde::Serialize)]
 struct NexusStatusResponse {
@@ -29,19 +41,30 @@ struct NexusStatusResponse {
 struct NexusAccountViewResponse {
     username: String,
     auto_execute: String,
+    #[serde(default)]
+    auto_execute_scripts: Vec<serde_json::Value>,
     place_id: i64,
     job_id: String,
-    relaunch_delay: f64,
+    #[serde(default)]
+    relaunch_delay: Option<f64>,
     auto_relaunch: bool,
     is_checked: bool,
     status: String,
     in_game_job_id: String,
+    #[serde(default)]
+    has_secret: bool,
+    #[serde(default)]
+    latency_ms: Option<u64>,
+    #[serde(default)]
+    broadcast_groups: Vec<String>,
 }
 
 #[cfg(not(feature = "nexus"))]
 #[derive(Debug, Clone, serde::Serialize)]
 struct NexusElementResponse {
     name: String,
+    script: String,
+    owners: Vec<String>,
     element_type: String,
     content: String,
     size: Option<(i32, i32)>,
@@ -108,9 +131,26 @@ async fn start_nexus_server(
         .get_int("AccountControl", "NexusPort")
         .unwrap_or(5242) as u16;
     let allow_external = settings.get_bool("AccountControl", "AllowExternalConnections");
-    nexus::websocket::nexus()
-        .start(port, allow_external, app)
-        .await
+    let server = nexus::websocket::nexus();
+    server.configure_auth(
+        settings.get_bool("AccountControl", "RequireAuth"),
+        &settings.get_string("AccountControl", "NexusKey"),
+    );
+    server.set_element_persistence(settings.get_bool("AccountControl", "PersistElements"));
+    server.set_data_persistence(settings.get_bool("AccountControl", "PersistData"));
+    server.configure_log(
+        settings
+            .get_int("AccountControl", "LogCapacity")
+            .unwrap_or(1000)
+            .max(0) as usize,
+        settings
+            .get_int("AccountControl", "LogMaxFileSize")
+            .unwrap_or(0)
+            .max(0) as u64,
+    );
+    let port = server.start(port, allow_external, app.clone()).await?;
+    spawn_nexus_supervisors(app);
+    Ok(port)
 }
 
 #[cfg(not(feature = "nexus"))]
@@ -227,18 +267,214 @@ fn nexus_send_to_all(_message: String) -> Result<(), String> {
     Err(NEXUS_DISABLED_ERR.into())
 }
 
+#[cfg(feature = "nexus")]
+fn resolve_nexus_target(
+    state: &AccountStore,
+    target: serde_json::Value,
+) -> Result<nexus::websocket::NexusTarget, String> {
+    if target.get("type").and_then(|t| t.as_str()) == Some("group") {
+        let group = target
+            .get("group")
+            .and_then(|g| g.as_str())
+            .ok_or("Missing group")?;
+        let usernames = state
+            .get_all()?
+            .into_iter()
+            .filter(|a| a.group == group)
+            .map(|a| a.username)
+            .collect();
+        return Ok(nexus::websocket::NexusTarget::Accounts { usernames });
+    }
+    serde_json::from_value(target).map_err(|e| format!("Invalid target: {}", e))
+}
+
+#[cfg(feature = "nexus")]
+#[tauri::command]
+fn nexus_send_targeted(
+    state: tauri::State<'_, AccountStore>,
+    target: serde_json::Value,
+    message: String,
+) -> Result<usize, String> {
+    let target = resolve_nexus_target(&state, target)?;
+    Ok(nexus::websocket::nexus().send_to_target(&target, &message))
+}
+
+#[cfg(not(feature = "nexus"))]
+#[tauri::command]
+fn nexus_send_targeted(
+    _state: tauri::State<'_, AccountStore>,
+    _target: serde_json::Value,
+    _message: String,
+) -> Result<usize, String> {
+    Err(NEXUS_DISABLED_ERR.into())
+}
+
+#[cfg(feature = "nexus")]
+#[tauri::command]
+fn get_nexus_broadcast_groups() -> Result<std::collections::BTreeMap<String, Vec<String>>, String> {
+    Ok(nexus::websocket::nexus().broadcast_groups())
+}
+
+#[cfg(not(feature = "nexus"))]
+#[tauri::command]
+fn get_nexus_broadcast_groups() -> Result<std::collections::BTreeMap<String, Vec<String>>, String> {
+    Ok(std::collections::BTreeMap::new())
+}
+
+#[cfg(feature = "nexus")]
+#[tauri::command]
+fn set_nexus_broadcast_group(name: String, usernames: Vec<String>) -> Result<(), String> {
+    nexus::websocket::nexus().set_broadcast_group(&name, &usernames)
+}
+
+#[cfg(not(feature = "nexus"))]
+#[tauri::command]
+fn set_nexus_broadcast_group(_name: String, _usernames: Vec<String>) -> Result<(), String> {
+    Err(NEXUS_DISABLED_ERR.into())
+}
+
 #[cfg(feature = "nexus")]
 #[tauri::command]
-fn get_nexus_log() -> Result<Vec<String>, String> {
-    Ok(nexus::websocket::nexus().get_log())
+fn delete_nexus_broadcast_group(name: String) -> Result<bool, String> {
+    Ok(nexus::websocket::nexus().delete_broadcast_group(&name))
 }
 
 #[cfg(not(feature = "nexus"))]
 #[tauri::command]
-fn get_nexus_log() -> Result<Vec<String>, String> {
+fn delete_nexus_broadcast_group(_name: String) -> Result<bool, String> {
+    Err(NEXUS_DISABLED_ERR.into())
+}
+
+#[cfg(feature = "nexus")]
+#[tauri::command]
+fn push_nexus_script(
+    library: tauri::State<'_, data::scripts::LuaScriptLibrary>,
+    script_id: String,
+) -> Result<usize, String> {
+    let script = library
+        .get(&script_id)
+        .ok_or_else(|| format!("Script {} not found", script_id))?;
+    Ok(nexus::websocket::nexus().push_script(&script))
+}
+
+#[cfg(not(feature = "nexus"))]
+#[tauri::command]
+fn push_nexus_script(
+    _library: tauri::State<'_, data::scripts::LuaScriptLibrary>,
+    _script_id: String,
+) -> Result<usize, String> {
+    Err(NEXUS_DISABLED_ERR.into())
+}
+
+#[cfg(feature = "nexus")]
+#[tauri::command]
+fn rotate_nexus_secret(
+    username: Option<String>,
+    settings: tauri::State<'_, SettingsStore>,
+) -> Result<String, String> {
+    let server = nexus::websocket::nexus();
+    match username.filter(|u| !u.is_empty()) {
+        Some(username) => server.rotate_secret(&username),
+        None => {
+            let key = nexus::websocket::generate_nexus_key();
+            settings.set("AccountControl", "NexusKey", &key)?;
+            server.configure_auth(settings.get_bool("AccountControl", "RequireAuth"), &key);
+            Ok(key)
+        }
+    }
+}
+
+#[cfg(not(feature = "nexus"))]
+#[tauri::command]
+fn rotate_nexus_secret(
+    _username: Option<String>,
+    _settings: tauri::State<'_, SettingsStore>,
+) -> Result<String, String> {
+    Err(NEXUS_DISABLED_ERR.into())
+}
+
+/// Turns handshake authentication on or off for the running server. Enabling
+/// it without a shared key generates one and returns it, since otherwise any
+/// account without its own secret could never connect.
+#[cfg(feature = "nexus")]
+#[tauri::command]
+fn set_nexus_require_auth(
+    enabled: bool,
+    settings: tauri::State<'_, SettingsStore>,
+) -> Result<Option<String>, String> {
+    let mut key = settings.get_string("AccountControl", "NexusKey");
+    let generated = if enabled && key.trim().is_empty() {
+        key = nexus::websocket::generate_nexus_key();
+        settings.set("AccountControl", "NexusKey", &key)?;
+        Some(key.clone())
+    } else {
+        None
+    };
+    settings.set(
+        "AccountControl",
+        "RequireAuth",
+        if enabled { "true" } else { "false" },
+    )?;
+    nexus::websocket::nexus().configure_auth(enabled, &key);
+    Ok(generated)
+}
+
+#[cfg(not(feature = "nexus"))]
+#[tauri::command]
+fn set_nexus_require_auth(
+    _enabled: bool,
+    _settings: tauri::State<'_, SettingsStore>,
+) -> Result<Option<String>, String> {
+    Err(NEXUS_DISABLED_ERR.into())
+}
+
+#[cfg(feature = "nexus")]
+#[tauri::command]
+fn get_nexus_log(
+    filter: Option<NexusLogFilterRequest>,
+) -> Result<Vec<NexusLogEntryResponse>, String> {
+    Ok(nexus::websocket::nexus().get_log(&filter.unwrap_or_default()))
+}
+
+#[cfg(not(feature = "nexus"))]
+#[tauri::command]
+fn get_nexus_log(
+    _filter: Option<NexusLogFilterRequest>,
+) -> Result<Vec<NexusLogEntryResponse>, String> {
     Ok(Vec::new())
 }
 
+#[cfg(feature = "nexus")]
+#[tauri::command]
+fn export_nexus_log(
+    filter: Option<NexusLogFilterRequest>,
+    format: Option<String>,
+) -> Result<String, String> {
+    let as_text = format.as_deref() == Some("text");
+    let stamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let out_path = std::env::current_dir()
+        .map_err(|e| format!("Failed to read current directory: {}", e))?
+        .join(format!(
+            "NexusLog-{}.{}",
+            stamp,
+            if as_text { "txt" } else { "jsonl" }
+        ));
+    nexus::websocket::nexus().export_log(&out_path, &filter.unwrap_or_default(), as_text)?;
+    Ok(out_path.to_string_lossy().into_owned())
+}
+
+#[cfg(not(feature = "nexus"))]
+#[tauri::command]
+fn export_nexus_log(
+    _filter: Option<NexusLogFilterRequest>,
+    _format: Option<String>,
+) -> Result<String, String> {
+    Err(NEXUS_DISABLED_ERR.into())
+}
+
 #[cfg(feature = "nexus")]
 #[tauri::command]
 fn clear_nexus_log() -> Result<(), String> {
@@ -266,14 +502,146 @@ fn get_nexus_elements() -> Result<Vec<NexusElementResponse>, String> {
 
 #[cfg(feature = "nexus")]
 #[tauri::command]
-fn set_nexus_element_value(name: String, value: String) -> Result<(), String> {
-    nexus::websocket::nexus().set_element_value(&name, &value);
-    Ok(())
+fn set_nexus_element_value(
+    name: String,
+    value: String,
+    script: Option<String>,
+) -> Result<usize, String> {
+    Ok(nexus::websocket::nexus().set_element_value(&script.unwrap_or_default(), &name, &value))
+}
+
+#[cfg(not(feature = "nexus"))]
+#[tauri::command]
+fn set_nexus_element_value(
+    _name: String,
+    _value: String,
+    _script: Option<String>,
+) -> Result<usize, String> {
+    Err(NEXUS_DISABLED_ERR.into())
+}
+
+#[cfg(feature = "nexus")]
+#[tauri::command]
+fn click_nexus_element(name: String, script: Option<String>) -> Result<usize, String> {
+    Ok(nexus::websocket::nexus().click_element(&script.unwrap_or_default(), &name))
+}
+
+#[cfg(not(feature = "nexus"))]
+#[tauri::command]
+fn click_nexus_element(_name: String, _script: Option<String>) -> Result<usize, String> {
+    Err(NEXUS_DISABLED_ERR.into())
+}
+
+#[cfg(feature = "nexus")]
+#[tauri::command]
+fn remove_nexus_element(
+    app: tauri::AppHandle,
+    name: String,
+    script: Option<String>,
+) -> Result<bool, String> {
+    Ok(nexus::websocket::nexus().remove_element(&script.unwrap_or_default(), &name, Some(&app)))
+}
+
+#[cfg(not(feature = "nexus"))]
+#[tauri::command]
+fn remove_nexus_element(
+    _app: tauri::AppHandle,
+    _name: String,
+    _script: Option<String>,
+) -> Result<bool, String> {
+    Err(NEXUS_DISABLED_ERR.into())
+}
+
+#[cfg(feature = "nexus")]
+#[tauri::command]
+fn clear_nexus_elements(app: tauri::AppHandle, script: Option<String>) -> Result<usize, String> {
+    Ok(nexus::websocket::nexus().clear_elements(None, script.as_deref(), Some(&app)))
+}
+
+#[cfg(not(feature = "nexus"))]
+#[tauri::command]
+fn clear_nexus_elements(_app: tauri::AppHandle, _script: Option<String>) -> Result<usize, String> {
+    Err(NEXUS_DISABLED_ERR.into())
+}
+
+#[cfg(feature = "nexus")]
+#[tauri::command]
+fn get_nexus_data(
+    script: Option<String>,
+) -> Result<
+    std::collections::BTreeMap<String, std::collections::BTreeMap<String, serde_json::Value>>,
+    String,
+> {
+    Ok(nexus::websocket::nexus().get_shared_data(script.as_deref()))
 }
 
 #[cfg(not(feature = "nexus"))]
 #[tauri::command]
-fn set_nexus_element_value(_name: String, _value: String) -> Result<(), String> {
+fn get_nexus_data(
+    _script: Option<String>,
+) -> Result<
+    std::collections::BTreeMap<String, std::collections::BTreeMap<String, serde_json::Value>>,
+    String,
+> {
+    Ok(std::collections::BTreeMap::new())
+}
+
+#[cfg(feature = "nexus")]
+#[tauri::command]
+fn set_nexus_data(
+    app: tauri::AppHandle,
+    script: Option<String>,
+    key: String,
+    value: serde_json::Value,
+) -> Result<bool, String> {
+    nexus::websocket::nexus()
+        .set_data_value(
+            nexus::websocket::NexusDataWrite {
+                script: script.unwrap_or_default(),
+                key,
+                value,
+                if_absent: false,
+                expected: None,
+            },
+            None,
+            Some(&app),
+        )
+        .map(|(set, _)| set)
+}
+
+#[cfg(not(feature = "nexus"))]
+#[tauri::command]
+fn set_nexus_data(
+    _app: tauri::AppHandle,
+    _script: Option<String>,
+    _key: String,
+    _value: serde_json::Value,
+) -> Result<bool, String> {
+    Err(NEXUS_DISABLED_ERR.into())
+}
+
+#[cfg(feature = "nexus")]
+#[tauri::command]
+fn delete_nexus_data(
+    app: tauri::AppHandle,
+    script: Option<String>,
+    key: String,
+) -> Result<bool, String> {
+    Ok(nexus::websocket::nexus().delete_data_value(
+        &script.unwrap_or_default(),
+        &key,
+        None,
+        Some(&app),
+    ))
+}
+
+#[cfg(not(feature = "nexus"))]
+#[tauri::command]
+fn delete_nexus_data(
+    _app: tauri::AppHandle,
+    _script: Option<String>,
+    _key: String,
+) -> Result<bool, String> {
     Err(NEXUS_DISABLED_ERR.into())
 }
 
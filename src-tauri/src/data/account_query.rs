@@ -0,0 +1,371 @@
+use std::collections::HashMap;
+
+const MAX_QUERY_DEPTH: usize = 64;
+
+/// What a query can see of one account. Times are ages in seconds so
+/// evaluation needs no clock.
+#[derive(Debug, Clone, Copy)]
+pub struct QuerySubject<'a> {
+    pub user_id: i64,
+    pub username: &'a str,
+    pub alias: &'a str,
+    pub group: &'a str,
+    pub vault: &'a str,
+    pub tags: &'a [String],
+    pub fields: &'a HashMap<String, String>,
+    pub valid: bool,
+    pub has_password: bool,
+    pub last_use_age: i64,
+    pub robux: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Compare {
+    Less,
+    LessEq,
+    Greater,
+    GreaterEq,
+    Equal,
+    NotEqual,
+}
+
+impl Compare {
+    fn holds(self, left: i64, right: i64) -> bool {
+        match self {
+            Self::Less => left < right,
+            Self::LessEq => left <= right,
+            Self::Greater => left > right,
+            Self::GreaterEq => left >= right,
+            Self::Equal => left == right,
+            Self::NotEqual => left != right,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Term {
+    Tag(String),
+    Group(String),
+    Vault(String),
+    Name(String),
+    Field(String, Option<String>),
+    Valid,
+    HasPassword,
+    Robux(Compare, i64),
+    LastUse(Compare, i64),
+    UserId(Compare, i64),
+}
+
+/// A parsed account query such as `tag:farm and robux>100 and last_use<7d`.
+///
+/// Terms are `tag:`, `group:`, `vault:`, `name:` (username or alias
+/// contains), `field:Name` or `field:Name=Value`, the flags `valid` and
+/// `has:password`, and comparisons on `robux`, `id` and `last_use` (an age
+/// such as `30m`, `12h`, `7d` or `2w`). Terms combine with `and`, `or`, `not`
+/// and parentheses; adjacent terms are joined with `and`. Text matches
+/// ignore case and values with spaces can be quoted. Parentheses nest at
+/// most `MAX_QUERY_DEPTH` deep.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountQuery(Node);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Term(Term),
+    Not(Box<Node>),
+    And(Vec<Node>),
+    Or(Vec<Node>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Open,
+    Close,
+}
+
+fn flush(word: &mut String, quoted: &mut bool, tokens: &mut Vec<Token>) {
+    if !word.is_empty() || *quoted {
+        let text = std::mem::take(word);
+        tokens.push(if *quoted {
+            Token::Quoted(text)
+        } else {
+            Token::Word(text)
+        });
+    }
+    *quoted = false;
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+    let mut quoted = false;
+    let mut in_quotes = false;
+
+    for c in input.chars() {
+        if in_quotes {
+            if c == '"' {
+                in_quotes = false;
+            } else {
+                word.push(c);
+            }
+            continue;
+        }
+        match c {
+            '"' => {
+                in_quotes = true;
+                quoted = true;
+            }
+            '(' | ')' => {
+                flush(&mut word, &mut quoted, &mut tokens);
+                tokens.push(if c == '(' { Token::Open } else { Token::Close });
+            }
+            c if c.is_whitespace() => flush(&mut word, &mut quoted, &mut tokens),
+            c => word.push(c),
+        }
+    }
+    if in_quotes {
+        return Err("Unterminated quote".to_string());
+    }
+    flush(&mut word, &mut quoted, &mut tokens);
+    Ok(tokens)
+}
+
+fn parse_age(value: &str) -> Option<i64> {
+    let split = value.len() - value.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let (amount, unit) = value.split_at(split);
+    let amount: i64 = amount.parse().ok()?;
+    let scale = match unit.to_ascii_lowercase().as_str() {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "" | "d" => 86400,
+        "w" => 604800,
+        _ => return None,
+    };
+    amount.checked_mul(scale)
+}
+
+fn parse_comparison(text: &str) -> Option<(String, Compare, &str)> {
+    let at = text.find(['<', '>', '=', '!'])?;
+    let (key, rest) = text.split_at(at);
+    let (op, value) = [
+        ("<=", Compare::LessEq),
+        (">=", Compare::GreaterEq),
+        ("!=", Compare::NotEqual),
+        ("<", Compare::Less),
+        (">", Compare::Greater),
+        ("=", Compare::Equal),
+    ]
+    .into_iter()
+    .find_map(|(symbol, op)| rest.strip_prefix(symbol).map(|value| (op, value)))?;
+    Some((key.to_ascii_lowercase(), op, value))
+}
+
+fn parse_term(text: &str) -> Result<Term, String> {
+    let lower = text.to_ascii_lowercase();
+    match lower.as_str() {
+        "valid" => return Ok(Term::Valid),
+        "has:password" => return Ok(Term::HasPassword),
+        _ => {}
+    }
+
+    if let Some((key, value)) = text.split_once(':') {
+        let value = value.to_string();
+        let missing = || format!("Missing value for {}:", key);
+        return match key.to_ascii_lowercase().as_str() {
+            _ if value.is_empty() => Err(missing()),
+            "tag" => Ok(Term::Tag(value)),
+            "group" => Ok(Term::Group(value)),
+            "vault" => Ok(Term::Vault(value)),
+            "name" => Ok(Term::Name(value)),
+            "field" => Ok(match value.split_once('=') {
+                Some((name, expected)) => Term::Field(name.to_string(), Some(expected.to_string())),
+                None => Term::Field(value, None),
+            }),
+            _ => Err(format!("Unknown term: {}", text)),
+        };
+    }
+
+    let Some((key, op, value)) = parse_comparison(text) else {
+        return Err(format!("Unknown term: {}", text));
+    };
+    let number = |value: &str| {
+        value
+            .parse::<i64>()
+            .map_err(|_| format!("Expected a number in {}", text))
+    };
+    match key.as_str() {
+        "robux" => Ok(Term::Robux(op, number(value)?)),
+        "id" | "userid" | "user_id" => Ok(Term::UserId(op, number(value)?)),
+        "last_use" | "lastuse" => parse_age(value)
+            .map(|age| Term::LastUse(op, age))
+            .ok_or_else(|| format!("Expected an age like 7d in {}", text)),
+        _ => Err(format!("Unknown term: {}", text)),
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.tokens.get(self.pos), Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    // Chains of `and`/`or` build one flat node so long queries stay shallow.
+    fn or(&mut self) -> Result<Node, String> {
+        let mut nodes = vec![self.and()?];
+        while self.peek_keyword("or") {
+            self.pos += 1;
+            nodes.push(self.and()?);
+        }
+        Ok(match nodes.len() {
+            1 => nodes.remove(0),
+            _ => Node::Or(nodes),
+        })
+    }
+
+    fn and(&mut self) -> Result<Node, String> {
+        let mut nodes = vec![self.unary()?];
+        loop {
+            if self.peek_keyword("and") {
+                self.pos += 1;
+            } else if self.peek_keyword("or")
+                || matches!(self.tokens.get(self.pos), None | Some(Token::Close))
+            {
+                break;
+            }
+            nodes.push(self.unary()?);
+        }
+        Ok(match nodes.len() {
+            1 => nodes.remove(0),
+            _ => Node::And(nodes),
+        })
+    }
+
+    fn unary(&mut self) -> Result<Node, String> {
+        let mut negated = false;
+        while self.peek_keyword("not") {
+            self.pos += 1;
+            negated = !negated;
+        }
+        let node = self.operand()?;
+        Ok(if negated {
+            Node::Not(Box::new(node))
+        } else {
+            node
+        })
+    }
+
+    fn operand(&mut self) -> Result<Node, String> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| "Query ends too early".to_string())?;
+        self.pos += 1;
+        match token {
+            Token::Word(w) if w.eq_ignore_ascii_case("and") || w.eq_ignore_ascii_case("or") => {
+                Err(format!("Unexpected {}", w))
+            }
+            Token::Open => {
+                if self.depth == MAX_QUERY_DEPTH {
+                    return Err("Query is nested too deeply".to_string());
+                }
+                self.depth += 1;
+                let inner = self.or()?;
+                self.depth -= 1;
+                if self.tokens.get(self.pos) != Some(&Token::Close) {
+                    return Err("Expected )".to_string());
+                }
+                self.pos += 1;
+                Ok(inner)
+            }
+            Token::Close => Err("Unexpected )".to_string()),
+            Token::Word(w) | Token::Quoted(w) => parse_term(&w).map(Node::Term),
+        }
+    }
+}
+
+impl AccountQuery {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let tokens = tokenize(input)?;
+        if tokens.is_empty() {
+            return Err("Query is empty".to_string());
+        }
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            depth: 0,
+        };
+        let node = parser.or()?;
+        if parser.pos < parser.tokens.len() {
+            return Err("Unexpected )".to_string());
+        }
+        Ok(Self(node))
+    }
+
+    pub fn matches(&self, subject: &QuerySubject) -> bool {
+        self.0.matches(subject)
+    }
+
+    /// Whether evaluating the query needs Robux balances.
+    pub fn uses_robux(&self) -> bool {
+        self.0.uses_robux()
+    }
+}
+
+impl Node {
+    fn matches(&self, subject: &QuerySubject) -> bool {
+        match self {
+            Self::Not(inner) => !inner.matches(subject),
+            Self::And(nodes) => nodes.iter().all(|n| n.matches(subject)),
+            Self::Or(nodes) => nodes.iter().any(|n| n.matches(subject)),
+            Self::Term(term) => term.matches(subject),
+        }
+    }
+
+    fn uses_robux(&self) -> bool {
+        match self {
+            Self::Not(inner) => inner.uses_robux(),
+            Self::And(nodes) | Self::Or(nodes) => nodes.iter().any(Node::uses_robux),
+            Self::Term(term) => matches!(term, Term::Robux(..)),
+        }
+    }
+}
+
+impl Term {
+    fn matches(&self, subject: &QuerySubject) -> bool {
+        match self {
+            Self::Tag(tag) => subject.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)),
+            Self::Group(group) => subject.group.eq_ignore_ascii_case(group),
+            Self::Vault(vault) => subject.vault.eq_ignore_ascii_case(vault),
+            Self::Name(name) => {
+                let name = name.to_lowercase();
+                subject.username.to_lowercase().contains(&name)
+                    || subject.alias.to_lowercase().contains(&name)
+            }
+            Self::Field(name, expected) => {
+                let value = subject
+                    .fields
+                    .iter()
+                    .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                    .map(|(_, value)| value.as_str());
+                match (value, expected) {
+                    (Some(value), Some(expected)) => value.eq_ignore_ascii_case(expected),
+                    (Some(value), None) => !value.is_empty(),
+                    (None, _) => false,
+                }
+            }
+            Self::Valid => subject.valid,
+            Self::HasPassword => subject.has_password,
+            Self::Robux(op, amount) => subject.robux.is_some_and(|robux| op.holds(robux, *amount)),
+            Self::LastUse(op, age) => op.holds(subject.last_use_age, *age),
+            Self::UserId(op, id) => op.holds(subject.user_id, *id),
+        }
+    }
+}
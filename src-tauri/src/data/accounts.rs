@@ -1,11 +1,24 @@
+use crate::data::account_query::{AccountQuery, QuerySubject};
+use crate::data::atomic::write_atomic;
+use crate::data::balance_history::{self, BalanceSample};
 use crate::data::crypto;
-use chrono::{DateTime, Utc};
+use crate::data::csv;
+use crate::data::keychain::{FileBackend, OsBackend, SecretBackend};
+use crate::data::settings::SettingsStore;
+use chrono::{DateTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
 use std::sync::Mutex;
 
 include!("accounts/model.rs");
+include!("accounts/sealed.rs");
 include!("accounts/store.rs");
+include!("accounts/backups.rs");
+include!("accounts/vaults.rs");
+include!("accounts/bundle.rs");
+include!("accounts/import_rows.rs");
+include!("accounts/smart_groups.rs");
+include!("accounts/balances.rs");
 include!("accounts/commands.rs");
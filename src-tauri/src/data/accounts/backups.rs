@@ -0,0 +1,237 @@
+const BACKUP_DIR_NAME: &str = "AccountBackups";
+const BACKUP_TIME_FORMAT: &str = "%Y%m%d-%H%M%S";
+const BACKUP_INTERVAL_SECS: i64 = 600;
+pub const DEFAULT_BACKUP_LIMIT: usize = 5;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBackupInfo {
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub size: u64,
+    pub valid: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountFileRecovery {
+    pub damaged: Option<String>,
+    pub backups: Vec<AccountBackupInfo>,
+    pub suggested: Option<String>,
+}
+
+impl AccountStore {
+    fn backup_dir(&self) -> PathBuf {
+        self.data_dir().join(BACKUP_DIR_NAME)
+    }
+
+    fn backup_stem(&self) -> String {
+        self.file_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "AccountData".to_string())
+    }
+
+    fn backup_time(&self, name: &str) -> Option<DateTime<Utc>> {
+        let stamp = name
+            .strip_prefix(&self.backup_stem())?
+            .strip_prefix('-')?
+            .strip_suffix(".json")?;
+        chrono::NaiveDateTime::parse_from_str(stamp, BACKUP_TIME_FORMAT)
+            .ok()
+            .map(|dt| Utc.from_utc_datetime(&dt))
+    }
+
+    pub fn set_backup_limit(&self, limit: usize) -> Result<(), String> {
+        *self.backup_limit.lock().map_err(|e| e.to_string())? = limit;
+        Ok(())
+    }
+
+    fn current_key(&self) -> Result<Option<Vec<u8>>, String> {
+        Ok(self
+            .password_hash
+            .lock()
+            .map_err(|e| e.to_string())?
+            .clone())
+    }
+
+    /// Lists the rotating backups, newest first. With `key`, encrypted backups
+    /// are opened too, so one that no longer decrypts is not valid.
+    pub fn list_backups(&self, key: Option<&[u8]>) -> Result<Vec<AccountBackupInfo>, String> {
+        let entries = match fs::read_dir(self.backup_dir()) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(format!("Failed to read account backups: {}", e)),
+        };
+
+        let mut backups: Vec<AccountBackupInfo> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                let created_at = self.backup_time(&name)?;
+                let data = fs::read(entry.path()).ok()?;
+                Some(AccountBackupInfo {
+                    name,
+                    created_at,
+                    size: data.len() as u64,
+                    valid: Self::damage_reason(&data, key).is_none(),
+                })
+            })
+            .collect();
+        backups.sort_by_key(|b| std::cmp::Reverse(b.created_at));
+        Ok(backups)
+    }
+
+    // Called after every successful write with the bytes now on disk. Keeps at
+    // most one backup per interval and prunes past the configured limit.
+    fn backup_written(&self, data: &[u8]) -> Result<(), String> {
+        let limit = *self.backup_limit.lock().map_err(|e| e.to_string())?;
+        if limit == 0 {
+            return Ok(());
+        }
+
+        let backups = self.list_backups(None)?;
+        let now = Utc::now();
+        if backups
+            .first()
+            .is_some_and(|newest| (now - newest.created_at).num_seconds() < BACKUP_INTERVAL_SECS)
+        {
+            return Ok(());
+        }
+
+        let dir = self.backup_dir();
+        fs::create_dir_all(&dir).map_err(|e| format!("Failed to create backup folder: {}", e))?;
+        let name = format!(
+            "{}-{}.json",
+            self.backup_stem(),
+            now.format(BACKUP_TIME_FORMAT)
+        );
+        write_atomic(&dir.join(name), data)
+            .map_err(|e| format!("Failed to write account backup: {}", e))?;
+
+        for stale in backups.iter().skip(limit.saturating_sub(1)) {
+            let _ = fs::remove_file(dir.join(&stale.name));
+        }
+        Ok(())
+    }
+
+    fn write_account_file(&self, data: &[u8]) -> Result<(), String> {
+        self.set_aside_damaged_file()?;
+        write_atomic(&self.file_path, data)
+            .map_err(|e| format!("Failed to write account file: {}", e))?;
+        if let Err(e) = self.backup_written(data) {
+            eprintln!("Warning: {}", e);
+        }
+        Ok(())
+    }
+
+    /// Explains why `data` cannot be an account file. Without `key` only the
+    /// structure is checked; with the key of a correct unlock, a protected
+    /// file whose header parses must also decrypt.
+    fn damage_reason(data: &[u8], key: Option<&[u8]>) -> Option<String> {
+        if data.is_empty() {
+            return None;
+        }
+        if crypto::is_encrypted(data) {
+            if let Err(e) = crypto::inspect(data) {
+                return Some(format!("Encrypted container is damaged: {}", e));
+            }
+        } else if data.trim_ascii_start().starts_with(b"{") {
+            if SealedAccountFile::parse(data).is_none() {
+                return Some("Account file is not valid JSON".to_string());
+            }
+        } else {
+            return Self::decode_plain_or_legacy_accounts(data).err();
+        }
+        let key = key?;
+        Self::decode_protected(data, key)
+            .err()
+            .map(|e| format!("Encrypted container is damaged: {}", e))
+    }
+
+    /// Checks the account file on disk and remembers the result, so saves
+    /// keep the damaged file aside instead of overwriting it.
+    pub fn detect_damage(&self) -> Result<Option<String>, String> {
+        let key = self.current_key()?;
+        let reason = match fs::read(&self.file_path) {
+            Ok(data) => Self::damage_reason(&data, key.as_deref()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => Some(format!("Failed to read account file: {}", e)),
+        };
+        *self.damaged.lock().map_err(|e| e.to_string())? = reason.clone();
+        Ok(reason)
+    }
+
+    fn aside_path(&self, label: &str) -> PathBuf {
+        let file_name = self
+            .file_path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        self.file_path.with_file_name(format!(
+            "{}.{}-{}",
+            file_name,
+            label,
+            Utc::now().format(BACKUP_TIME_FORMAT)
+        ))
+    }
+
+    fn set_aside_damaged_file(&self) -> Result<(), String> {
+        let mut damaged = self.damaged.lock().map_err(|e| e.to_string())?;
+        if damaged.is_none() {
+            return Ok(());
+        }
+        if self.file_path.exists() {
+            fs::rename(&self.file_path, self.aside_path("damaged"))
+                .map_err(|e| format!("Failed to move damaged account file: {}", e))?;
+        }
+        *damaged = None;
+        Ok(())
+    }
+
+    pub fn recovery(&self) -> Result<AccountFileRecovery, String> {
+        let damaged = self.damaged.lock().map_err(|e| e.to_string())?.clone();
+        let backups = self.list_backups(self.current_key()?.as_deref())?;
+        let suggested = backups.iter().find(|b| b.valid).map(|b| b.name.clone());
+        Ok(AccountFileRecovery {
+            damaged,
+            backups,
+            suggested,
+        })
+    }
+
+    /// Replaces the account file with the named backup. The current file is
+    /// kept as `.damaged-<time>` when it is damaged and as `.replaced-<time>`
+    /// otherwise. Returns whether a password is now needed to open it.
+    pub fn restore_backup(&self, name: &str) -> Result<bool, String> {
+        let backup = self
+            .list_backups(self.current_key()?.as_deref())?
+            .into_iter()
+            .find(|b| b.name == name)
+            .ok_or_else(|| "Backup not found".to_string())?;
+        if !backup.valid {
+            return Err("Backup is damaged".to_string());
+        }
+        let data = fs::read(self.backup_dir().join(&backup.name))
+            .map_err(|e| format!("Failed to read account backup: {}", e))?;
+
+        let mut accounts = self.accounts.lock().map_err(|e| e.to_string())?;
+        self.set_aside_damaged_file()?;
+        if self.file_path.exists() {
+            fs::copy(&self.file_path, self.aside_path("replaced"))
+                .map_err(|e| format!("Failed to keep the current account file: {}", e))?;
+        }
+        write_atomic(&self.file_path, &data)
+            .map_err(|e| format!("Failed to write account file: {}", e))?;
+        accounts.clear();
+        *self.password_hash.lock().map_err(|e| e.to_string())? = None;
+        drop(accounts);
+
+        if Self::is_protected(&data) {
+            self.locked.store(true, std::sync::atomic::Ordering::SeqCst);
+            return Ok(true);
+        }
+        self.load()?;
+        Ok(false)
+    }
+}
@@ -0,0 +1,57 @@
+const BALANCE_HISTORY_FILE_NAME: &str = "BalanceHistory.csv";
+
+impl AccountStore {
+    fn balance_history_path(&self) -> PathBuf {
+        self.data_dir().join(BALANCE_HISTORY_FILE_NAME)
+    }
+
+    /// Every recorded balance sample, oldest first. Requires an unlocked
+    /// store since the history lists which accounts are held.
+    pub fn balance_history(&self) -> Result<Vec<BalanceSample>, String> {
+        if self.is_locked() {
+            return Err(LOCKED_ERROR.to_string());
+        }
+        match fs::read_to_string(self.balance_history_path()) {
+            Ok(text) => balance_history::parse(&text),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(format!("Failed to read balance history: {}", e)),
+        }
+    }
+
+    /// Appends one snapshot, drops samples older than `keep_days` (zero keeps
+    /// everything) and returns the history that was written.
+    pub fn record_balance_snapshot(
+        &self,
+        samples: &[BalanceSample],
+        keep_days: i64,
+    ) -> Result<Vec<BalanceSample>, String> {
+        let mut history = self.balance_history()?;
+        if keep_days > 0 {
+            let cutoff = Utc::now() - chrono::Duration::days(keep_days);
+            history.retain(|s| s.at >= cutoff);
+        }
+        history.extend_from_slice(samples);
+        history.sort_by_key(|s| s.at);
+
+        write_atomic(
+            &self.balance_history_path(),
+            balance_history::write(&history).as_bytes(),
+        )
+        .map_err(|e| format!("Failed to write balance history: {}", e))?;
+        for sample in samples {
+            self.record_robux(sample.user_id, sample.robux)?;
+        }
+        Ok(history)
+    }
+
+    /// Fills the Robux cache from the latest recorded samples without
+    /// replacing balances fetched since.
+    pub fn seed_robux_balances(&self) -> Result<(), String> {
+        let history = self.balance_history()?;
+        let mut balances = self.robux_balances.lock().map_err(|e| e.to_string())?;
+        for sample in history.iter().rev() {
+            balances.entry(sample.user_id).or_insert(sample.robux);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+const BUNDLE_FORMAT: &str = "RAMBundle";
+const BUNDLE_VERSION: u8 = 1;
+const BUNDLE_EXTENSION: &str = "rambundle";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportStrategy {
+    Skip,
+    Replace,
+    Newest,
+}
+
+impl ImportStrategy {
+    pub fn from_setting(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "skip" => Some(Self::Skip),
+            "replace" => Some(Self::Replace),
+            "newest" => Some(Self::Newest),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleImportSummary {
+    pub total: usize,
+    pub added: usize,
+    pub replaced: usize,
+    pub skipped: usize,
+    pub nexus: usize,
+    /// Usernames whose account came from the bundle, so their Nexus settings
+    /// follow the same merge decision.
+    #[serde(skip)]
+    pub taken: Vec<String>,
+}
+
+/// Portable export of selected accounts. The header stays readable so a
+/// bundle can be recognised and described before asking for its password;
+/// `Payload` is a hex encoded encrypted container of `BundlePayload`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct AccountBundleFile {
+    format: String,
+    version: u8,
+    created_at: DateTime<Utc>,
+    account_count: usize,
+    includes_passwords: bool,
+    includes_nexus: bool,
+    payload: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BundlePayload {
+    pub accounts: Vec<Account>,
+    /// Nexus control entries, kept as raw JSON so bundles stay readable in
+    /// builds without Nexus.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub nexus: Vec<serde_json::Value>,
+}
+
+impl AccountStore {
+    /// Encrypts the selected accounts into a new bundle next to the account
+    /// file and returns its path.
+    pub fn export_bundle(
+        &self,
+        user_ids: &[i64],
+        password: &str,
+        include_passwords: bool,
+        nexus: Vec<serde_json::Value>,
+    ) -> Result<PathBuf, String> {
+        Self::validate_password(password)?;
+        let accounts: Vec<Account> = self
+            .get_all()?
+            .into_iter()
+            .filter(|a| user_ids.contains(&a.user_id))
+            .map(|mut account| {
+                account.vault.clear();
+                if !include_passwords {
+                    account.password.clear();
+                }
+                account
+            })
+            .collect();
+        if accounts.is_empty() {
+            return Err("No accounts selected to export".to_string());
+        }
+
+        let payload = BundlePayload { accounts, nexus };
+        let json = serde_json::to_string(&payload)
+            .map_err(|e| format!("Failed to serialize bundle: {}", e))?;
+        let sealed = crypto::encrypt(
+            &json,
+            &crypto::hash_password(password),
+            crypto::ContainerFormat::Versioned(crypto::KdfParams::default()),
+        )
+        .map_err(|e| format!("Failed to encrypt: {}", e))?;
+
+        let created_at = Utc::now();
+        let file = AccountBundleFile {
+            format: BUNDLE_FORMAT.to_string(),
+            version: BUNDLE_VERSION,
+            created_at,
+            account_count: payload.accounts.len(),
+            includes_passwords: include_passwords,
+            includes_nexus: !payload.nexus.is_empty(),
+            payload: sodiumoxide::hex::encode(&sealed),
+        };
+        let data = serde_json::to_vec_pretty(&file)
+            .map_err(|e| format!("Failed to serialize bundle: {}", e))?;
+
+        let path = self.data_dir().join(format!(
+            "AccountBundle-{}.{}",
+            created_at.format(BACKUP_TIME_FORMAT),
+            BUNDLE_EXTENSION
+        ));
+        if path.exists() {
+            return Err("A bundle was already exported this second".to_string());
+        }
+        write_atomic(&path, &data).map_err(|e| format!("Failed to write bundle: {}", e))?;
+        Ok(path)
+    }
+
+    pub fn read_bundle(data: &[u8], password: &str) -> Result<BundlePayload, String> {
+        let file = serde_json::from_slice::<AccountBundleFile>(data)
+            .ok()
+            .filter(|file| file.format == BUNDLE_FORMAT)
+            .ok_or_else(|| "Not an account bundle".to_string())?;
+        if file.version != BUNDLE_VERSION {
+            return Err(format!("Unsupported bundle version {}", file.version));
+        }
+        let sealed = sodiumoxide::hex::decode(&file.payload)
+            .map_err(|_| format!("Bundle is damaged: {}", crypto::CryptoError::InvalidData))?;
+        let json = crypto::decrypt(&sealed, &crypto::hash_password(password))
+            .map_err(|_| "Bundle password is incorrect".to_string())?;
+        serde_json::from_slice(&json).map_err(|e| format!("Bundle is damaged: {}", e))
+    }
+
+    /// Merges bundle accounts into the store. Accounts already listed, in the
+    /// main file or an open vault, are updated where they live; new ones go
+    /// to the main file. A bundle without passwords keeps the existing ones.
+    pub fn import_bundle(
+        &self,
+        imported: Vec<Account>,
+        strategy: ImportStrategy,
+    ) -> Result<BundleImportSummary, String> {
+        let vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        if self.is_locked() {
+            return Err(LOCKED_ERROR.to_string());
+        }
+
+        let mut summary = BundleImportSummary {
+            total: imported.len(),
+            ..Default::default()
+        };
+        let mut seen = HashSet::new();
+        let mut touched: Vec<&AccountStore> = Vec::new();
+
+        for mut account in imported {
+            if account.user_id <= 0 || !seen.insert(account.user_id) {
+                summary.skipped += 1;
+                continue;
+            }
+            account.vault.clear();
+
+            let holder = if self.own_contains(account.user_id) {
+                self
+            } else {
+                match Self::vault_holding(&vaults, account.user_id) {
+                    Some(vault) => vault,
+                    None => {
+                        summary.taken.push(account.username.clone());
+                        self.accounts
+                            .lock()
+                            .map_err(|e| e.to_string())?
+                            .push(account);
+                        summary.added += 1;
+                        if !touched.iter().any(|s| std::ptr::eq(*s, self)) {
+                            touched.push(self);
+                        }
+                        continue;
+                    }
+                }
+            };
+
+            let mut accounts = holder.accounts.lock().map_err(|e| e.to_string())?;
+            let Some(existing) = accounts.iter_mut().find(|a| a.user_id == account.user_id) else {
+                continue;
+            };
+            let take = match strategy {
+                ImportStrategy::Skip => false,
+                ImportStrategy::Replace => true,
+                ImportStrategy::Newest => account.last_use > existing.last_use,
+            };
+            if !take {
+                summary.skipped += 1;
+                continue;
+            }
+            if account.password.is_empty() {
+                account.password = std::mem::take(&mut existing.password);
+            }
+            summary.taken.push(account.username.clone());
+            *existing = account;
+            summary.replaced += 1;
+            drop(accounts);
+            if !touched.iter().any(|s| std::ptr::eq(*s, holder)) {
+                touched.push(holder);
+            }
+        }
+
+        for store in touched {
+            store.save()?;
+        }
+        Ok(summary)
+    }
+}
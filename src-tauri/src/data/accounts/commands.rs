@@ -13,7 +13,49 @@ pub fn get_accounts(state: tauri::State<'_, AccountStore>) -> Result<Vec<Account
 
 #[tauri::command]
 pub fn save_accounts(state: tauri::State<'_, AccountStore>) -> Result<(), String> {
-    state.save()
+    state.save_all()
+}
+
+#[tauri::command]
+pub fn list_vaults(state: tauri::State<'_, AccountStore>) -> Result<Vec<VaultInfo>, String> {
+    state.list_vaults()
+}
+
+#[tauri::command]
+pub fn create_vault(
+    state: tauri::State<'_, AccountStore>,
+    name: String,
+    password: String,
+) -> Result<(), String> {
+    state.create_vault(name.trim(), &password)
+}
+
+#[tauri::command]
+pub fn open_vault(
+    state: tauri::State<'_, AccountStore>,
+    name: String,
+    password: String,
+) -> Result<usize, String> {
+    state.open_vault(&name, &password)
+}
+
+#[tauri::command]
+pub fn close_vault(state: tauri::State<'_, AccountStore>, name: String) -> Result<bool, String> {
+    state.close_vault(&name)
+}
+
+#[tauri::command]
+pub fn delete_vault(state: tauri::State<'_, AccountStore>, name: String) -> Result<(), String> {
+    state.delete_vault(&name)
+}
+
+#[tauri::command]
+pub fn move_accounts_to_vault(
+    state: tauri::State<'_, AccountStore>,
+    user_ids: Vec<i64>,
+    vault: Option<String>,
+) -> Result<usize, String> {
+    state.move_accounts_to_vault(&user_ids, vault.as_deref().unwrap_or(""))
 }
 
 #[tauri::command]
@@ -58,12 +100,170 @@ pub fn needs_password(state: tauri::State<'_, AccountStore>) -> Result<bool, Str
     state.needs_password()
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RememberUnlockStatus {
+    pub enabled: bool,
+    pub backend: &'static str,
+    pub stored: bool,
+}
+
+pub fn unlock_key_backend(
+    state: &AccountStore,
+    settings: &SettingsStore,
+) -> Box<dyn SecretBackend> {
+    match settings.get_string("General", "UnlockKeyBackend").as_str() {
+        "file" => Box::new(FileBackend::new(state.data_dir())),
+        _ => Box::new(OsBackend),
+    }
+}
+
+fn remember_unlock_status(state: &AccountStore, settings: &SettingsStore) -> RememberUnlockStatus {
+    let backend = unlock_key_backend(state, settings);
+    RememberUnlockStatus {
+        enabled: settings.get_bool("General", "RememberUnlock"),
+        backend: backend.name(),
+        stored: state.has_remembered_unlock_key(backend.as_ref()),
+    }
+}
+
+// Keeps a remembered key in step after the password changes or is removed.
+fn refresh_remembered_unlock_key(
+    state: &AccountStore,
+    settings: &SettingsStore,
+) -> Result<(), String> {
+    if !settings.get_bool("General", "RememberUnlock") {
+        return Ok(());
+    }
+    let backend = unlock_key_backend(state, settings);
+    if state.is_encrypted()? {
+        state.remember_unlock_key(backend.as_ref())
+    } else {
+        state.forget_unlock_key(backend.as_ref())?;
+        settings.set("General", "RememberUnlock", "false")
+    }
+}
+
+fn configured_kdf(settings: &SettingsStore) -> Result<crypto::KdfParams, String> {
+    let method = settings.get_string("General", "EncryptionKdf");
+    crypto::KdfParams::from_method(&method)
+        .ok_or_else(|| format!("Unknown key derivation preset: {}", method))
+}
+
+fn configured_scope(settings: &SettingsStore) -> Result<EncryptionScope, String> {
+    let scope = settings.get_string("General", "EncryptionScope");
+    EncryptionScope::from_setting(&scope)
+        .ok_or_else(|| format!("Unknown encryption scope: {}", scope))
+}
+
 #[tauri::command]
 pub fn set_encryption_password(
     state: tauri::State<'_, AccountStore>,
+    settings: tauri::State<'_, SettingsStore>,
     password: Option<String>,
 ) -> Result<(), String> {
-    state.set_password(password.as_deref())
+    if password.is_some() {
+        state.set_format(crypto::ContainerFormat::Versioned(configured_kdf(
+            &settings,
+        )?))?;
+        state.set_scope(configured_scope(&settings)?)?;
+    }
+    state.set_password(password.as_deref())?;
+    refresh_remembered_unlock_key(&state, &settings)
+}
+
+#[tauri::command]
+pub fn get_remember_unlock(
+    state: tauri::State<'_, AccountStore>,
+    settings: tauri::State<'_, SettingsStore>,
+) -> RememberUnlockStatus {
+    remember_unlock_status(&state, &settings)
+}
+
+#[tauri::command]
+pub fn set_remember_unlock(
+    state: tauri::State<'_, AccountStore>,
+    settings: tauri::State<'_, SettingsStore>,
+    enabled: bool,
+) -> Result<RememberUnlockStatus, String> {
+    let backend = unlock_key_backend(&state, &settings);
+    if enabled {
+        state.remember_unlock_key(backend.as_ref())?;
+    } else {
+        state.forget_unlock_key(backend.as_ref())?;
+    }
+    settings.set(
+        "General",
+        "RememberUnlock",
+        if enabled { "true" } else { "false" },
+    )?;
+    Ok(remember_unlock_status(&state, &settings))
+}
+
+#[tauri::command]
+pub fn get_account_encryption_info(
+    state: tauri::State<'_, AccountStore>,
+) -> Result<Option<crypto::ContainerInfo>, String> {
+    state.container_info()
+}
+
+/// Switches between sealing the whole file and sealing only cookies and
+/// passwords. An unlocked, protected file is rewritten in the new layout.
+#[tauri::command]
+pub fn set_account_encryption_scope(
+    state: tauri::State<'_, AccountStore>,
+    settings: tauri::State<'_, SettingsStore>,
+    scope: String,
+) -> Result<Option<crypto::ContainerInfo>, String> {
+    let scope = EncryptionScope::from_setting(&scope)
+        .ok_or_else(|| format!("Unknown encryption scope: {}", scope))?;
+    if state.needs_password()? {
+        return Err("Unlock the account file first".to_string());
+    }
+    state.set_scope(scope)?;
+    if state.is_encrypted()? {
+        state.save()?;
+    }
+    settings.set("General", "EncryptionScope", scope.as_str())?;
+    state.container_info()
+}
+
+#[tauri::command]
+pub fn get_account_file_recovery(
+    state: tauri::State<'_, AccountStore>,
+) -> Result<AccountFileRecovery, String> {
+    state.recovery()
+}
+
+#[tauri::command]
+pub fn restore_account_backup(
+    state: tauri::State<'_, AccountStore>,
+    name: String,
+) -> Result<bool, String> {
+    state.restore_backup(&name)
+}
+
+#[tauri::command]
+pub fn rekey_account_file(
+    state: tauri::State<'_, AccountStore>,
+    settings: tauri::State<'_, SettingsStore>,
+    password: String,
+    new_password: Option<String>,
+    kdf: Option<String>,
+) -> Result<crypto::ContainerInfo, String> {
+    let params = match kdf.as_deref() {
+        Some(method) => crypto::KdfParams::from_method(method)
+            .ok_or_else(|| format!("Unknown key derivation preset: {}", method))?,
+        None => configured_kdf(&settings)?,
+    };
+    let info = state.rekey(&password, new_password.as_deref(), params)?;
+    if let Some(method) = kdf {
+        settings.set("General", "EncryptionKdf", &method)?;
+    }
+    if new_password.is_some() {
+        refresh_remembered_unlock_key(&state, &settings)?;
+    }
+    Ok(info)
 }
 
 #[tauri::command]
@@ -82,3 +282,54 @@ pub fn import_old_account_data(
 ) -> Result<OldAccountImportSummary, String> {
     state.import_old_account_data(&file_data, password.as_deref())
 }
+
+#[tauri::command]
+pub fn export_accounts_csv(
+    state: tauri::State<'_, AccountStore>,
+    user_ids: Vec<i64>,
+    include_secrets: bool,
+) -> Result<String, String> {
+    state.export_csv(&user_ids, include_secrets)
+}
+
+#[tauri::command]
+pub fn query_accounts(
+    state: tauri::State<'_, AccountStore>,
+    query: String,
+) -> Result<Vec<i64>, String> {
+    Ok(state.query(&query)?.iter().map(|a| a.user_id).collect())
+}
+
+#[tauri::command]
+pub fn list_smart_groups(
+    state: tauri::State<'_, AccountStore>,
+) -> Result<Vec<SmartGroupInfo>, String> {
+    state.list_smart_groups()
+}
+
+#[tauri::command]
+pub fn save_smart_group(
+    state: tauri::State<'_, AccountStore>,
+    name: String,
+    query: String,
+) -> Result<(), String> {
+    state.save_smart_group(&name, &query)
+}
+
+#[tauri::command]
+pub fn delete_smart_group(
+    state: tauri::State<'_, AccountStore>,
+    name: String,
+) -> Result<bool, String> {
+    state.delete_smart_group(&name)
+}
+
+#[tauri::command]
+pub fn edit_account_tags(
+    state: tauri::State<'_, AccountStore>,
+    user_ids: Vec<i64>,
+    add: Vec<String>,
+    remove: Vec<String>,
+) -> Result<usize, String> {
+    state.edit_tags(&user_ids, &add, &remove)
+}
@@ -0,0 +1,257 @@
+const COOKIE_WARNING_PREFIX: &str = "_|WARNING";
+const CSV_COLUMNS: [&str; 6] = [
+    "username",
+    "password",
+    "cookie",
+    "group",
+    "alias",
+    "description",
+];
+
+/// One account read from a CSV file or cookie list, before its cookie has
+/// been validated.
+#[derive(Debug, Clone, Default)]
+pub struct AccountImportRow {
+    pub line: usize,
+    pub username: String,
+    pub password: String,
+    pub cookie: String,
+    pub group: String,
+    pub alias: String,
+    pub description: String,
+    pub fields: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountImportRowResult {
+    pub line: usize,
+    pub username: String,
+    pub user_id: Option<i64>,
+    /// `ready` on a dry run, otherwise `added`, `exists` or `failed`.
+    pub status: &'static str,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountImportReport {
+    pub dry_run: bool,
+    pub added: usize,
+    pub existing: usize,
+    pub failed: usize,
+    pub rows: Vec<AccountImportRowResult>,
+}
+
+impl AccountImportRow {
+    pub fn failed(&self, message: impl Into<String>) -> AccountImportRowResult {
+        AccountImportRowResult {
+            line: self.line,
+            username: self.username.clone(),
+            user_id: None,
+            status: "failed",
+            message: message.into(),
+        }
+    }
+
+    pub fn into_account(self, username: String, user_id: i64) -> Account {
+        let mut account = Account::new(self.cookie, username, user_id);
+        account.password = self.password;
+        if !self.group.is_empty() {
+            account.group = self.group;
+        }
+        account.alias = self.alias;
+        account.description = self.description;
+        account.fields = self.fields;
+        account
+    }
+}
+
+fn csv_column(header: &str) -> Option<&'static str> {
+    match header.trim().to_ascii_lowercase().as_str() {
+        "username" | "user" | "name" => Some("username"),
+        "password" | "pass" => Some("password"),
+        "cookie" | "securitytoken" | "security_token" | ".roblosecurity" | "roblosecurity" => {
+            Some("cookie")
+        }
+        "group" => Some("group"),
+        "alias" => Some("alias"),
+        "description" => Some("description"),
+        _ => None,
+    }
+}
+
+/// Reads a CSV file with a header row. Known columns map onto the account;
+/// any other non-empty column becomes a field. Rows that cannot be read are
+/// returned as failures alongside the parsed ones.
+pub fn parse_account_csv(
+    text: &str,
+) -> Result<(Vec<AccountImportRow>, Vec<AccountImportRowResult>), String> {
+    let mut records = csv::parse(text)?.into_iter();
+    let Some((_, header)) = records.next() else {
+        return Err("CSV file is empty".to_string());
+    };
+    if !header.iter().any(|h| csv_column(h) == Some("cookie")) {
+        return Err("CSV file needs a cookie column".to_string());
+    }
+
+    let mut rows = Vec::new();
+    let mut failures = Vec::new();
+    for (line, record) in records {
+        let mut row = AccountImportRow {
+            line,
+            ..Default::default()
+        };
+        if record.len() != header.len() {
+            failures.push(row.failed(format!(
+                "Expected {} columns, found {}",
+                header.len(),
+                record.len()
+            )));
+            continue;
+        }
+
+        for (name, value) in header.iter().zip(record) {
+            let value = value.trim().to_string();
+            match csv_column(name) {
+                Some("username") => row.username = value,
+                Some("password") => row.password = value,
+                Some("cookie") => row.cookie = value,
+                Some("group") => row.group = value,
+                Some("alias") => row.alias = value,
+                Some("description") => row.description = value,
+                _ if name.trim().is_empty() || value.is_empty() => {}
+                _ => {
+                    row.fields.insert(name.trim().to_string(), value);
+                }
+            }
+        }
+
+        if row.cookie.is_empty() {
+            failures.push(row.failed("Missing cookie"));
+        } else {
+            rows.push(row);
+        }
+    }
+    Ok((rows, failures))
+}
+
+/// Reads one account per line, either a bare cookie or `user:pass:cookie`.
+/// Cookies carrying Roblox's warning prefix may themselves contain colons.
+pub fn parse_account_list(text: &str) -> (Vec<AccountImportRow>, Vec<AccountImportRowResult>) {
+    let mut rows = Vec::new();
+    let mut failures = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let mut row = AccountImportRow {
+            line: index + 1,
+            ..Default::default()
+        };
+
+        let (prefix, cookie) = match line.find(COOKIE_WARNING_PREFIX) {
+            Some(at) => (line[..at].trim_end_matches(':'), &line[at..]),
+            None => match line.splitn(3, ':').count() {
+                1 => ("", line),
+                3 => line.rsplit_once(':').unwrap_or(("", line)),
+                _ => {
+                    failures.push(row.failed("Expected a cookie or user:pass:cookie"));
+                    continue;
+                }
+            },
+        };
+        if !prefix.is_empty() {
+            let (username, password) = prefix.split_once(':').unwrap_or((prefix, ""));
+            row.username = username.trim().to_string();
+            row.password = password.to_string();
+        }
+        row.cookie = cookie.trim().to_string();
+
+        if row.cookie.is_empty() {
+            failures.push(row.failed("Missing cookie"));
+        } else {
+            rows.push(row);
+        }
+    }
+    (rows, failures)
+}
+
+impl AccountStore {
+    /// Adds validated accounts to the main file in one save, skipping any
+    /// already listed in the main file or an open vault.
+    pub fn add_imported(&self, imported: Vec<Account>) -> Result<usize, String> {
+        let vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        if self.is_locked() {
+            return Err(LOCKED_ERROR.to_string());
+        }
+
+        let mut added = 0;
+        {
+            let mut accounts = self.accounts.lock().map_err(|e| e.to_string())?;
+            for account in imported {
+                if accounts.iter().any(|a| a.user_id == account.user_id)
+                    || Self::vault_holding(&vaults, account.user_id).is_some()
+                {
+                    continue;
+                }
+                accounts.push(account);
+                added += 1;
+            }
+        }
+        if added > 0 {
+            self.save()?;
+        }
+        Ok(added)
+    }
+
+    /// Renders the selected accounts as CSV in the import column layout. The
+    /// password and cookie columns stay empty unless `include_secrets` is set;
+    /// the caller decides where the text is saved.
+    pub fn export_csv(&self, user_ids: &[i64], include_secrets: bool) -> Result<String, String> {
+        if user_ids.is_empty() {
+            return Err("Select the accounts to export".to_string());
+        }
+        let accounts: Vec<Account> = self
+            .get_all()?
+            .into_iter()
+            .filter(|a| user_ids.contains(&a.user_id))
+            .collect();
+        if accounts.is_empty() {
+            return Err("No accounts to export".to_string());
+        }
+
+        let field_names: BTreeSet<&String> =
+            accounts.iter().flat_map(|a| a.fields.keys()).collect();
+        let mut out = String::new();
+        let header: Vec<&str> = CSV_COLUMNS
+            .iter()
+            .copied()
+            .chain(field_names.iter().map(|name| name.as_str()))
+            .collect();
+        csv::write_record(&mut out, &header);
+        for account in &accounts {
+            let (password, cookie) = if include_secrets {
+                (account.password.as_str(), account.security_token.as_str())
+            } else {
+                ("", "")
+            };
+            let mut record: Vec<&str> = vec![
+                &account.username,
+                password,
+                cookie,
+                &account.group,
+                &account.alias,
+                &account.description,
+            ];
+            record.extend(
+                field_names
+                    .iter()
+                    .map(|name| account.fields.get(*name).map_or("", |v| v.as_str())),
+            );
+            csv::write_record(&mut out, &record);
+        }
+        Ok(out)
+    }
+}
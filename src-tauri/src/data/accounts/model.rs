@@ -18,10 +18,16 @@ pub struct Account {
     pub user_id: i64,
     #[serde(default)]
     pub fields: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
     #[serde(with = "csharp_datetime")]
     pub last_attempted_refresh: DateTime<Utc>,
     #[serde(rename = "BrowserTrackerID", alias = "BrowserTrackerId", default)]
     pub browser_tracker_id: String,
+    /// Open vault the account was listed from; empty for the main file and
+    /// never written to disk.
+    #[serde(rename = "Vault", default, skip_serializing_if = "String::is_empty")]
+    pub vault: String,
 }
 
 fn default_group() -> String {
@@ -84,8 +90,10 @@ impl Default for Account {
             group: default_group(),
             user_id: 0,
             fields: HashMap::new(),
+            tags: Vec::new(),
             last_attempted_refresh: Utc::now(),
             browser_tracker_id: String::new(),
+            vault: String::new(),
         }
     }
 }
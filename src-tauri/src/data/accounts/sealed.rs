@@ -0,0 +1,135 @@
+const SEALED_FILE_FORMAT: &str = "RAMFields";
+const SEALED_FILE_VERSION: u8 = 1;
+const SEALED_CHECK_VALUE: &str = "Roblox Account Manager";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncryptionScope {
+    File,
+    Fields,
+}
+
+impl EncryptionScope {
+    pub fn from_setting(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "file" => Some(Self::File),
+            "fields" => Some(Self::Fields),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::File => "file",
+            Self::Fields => "fields",
+        }
+    }
+}
+
+/// Account file that keeps usernames, groups, aliases and fields readable and
+/// only seals each account's `SecurityToken` and `Password`. `Check` holds a
+/// sealed known value so a wrong password is caught even without secrets.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SealedAccountFile {
+    format: String,
+    version: u8,
+    ops_limit: u64,
+    mem_limit: u64,
+    salt: String,
+    check: String,
+    accounts: Vec<Account>,
+}
+
+impl SealedAccountFile {
+    fn parse(data: &[u8]) -> Option<Self> {
+        if !data.trim_ascii_start().starts_with(b"{") {
+            return None;
+        }
+        serde_json::from_slice::<Self>(data)
+            .ok()
+            .filter(|file| file.format == SEALED_FILE_FORMAT)
+    }
+
+    fn params(&self) -> crypto::KdfParams {
+        crypto::KdfParams {
+            ops_limit: self.ops_limit,
+            mem_limit: self.mem_limit,
+        }
+    }
+
+    fn cipher(&self, password_hash: &[u8]) -> Result<crypto::FieldCipher, String> {
+        if self.version != SEALED_FILE_VERSION {
+            return Err(format!("Unsupported account file version {}", self.version));
+        }
+        let salt = sodiumoxide::hex::decode(&self.salt)
+            .map_err(|_| format!("Failed to decrypt: {}", crypto::CryptoError::InvalidData))?;
+        let cipher = crypto::FieldCipher::derive(password_hash, &salt, self.params())
+            .map_err(|e| format!("Failed to decrypt: {}", e))?;
+        match cipher.open(&self.check) {
+            Ok(check) if check == SEALED_CHECK_VALUE => Ok(cipher),
+            _ => Err(format!(
+                "Failed to decrypt: {}",
+                crypto::CryptoError::DecryptionFailed
+            )),
+        }
+    }
+
+    fn seal(
+        accounts: &[Account],
+        password_hash: &[u8],
+        params: crypto::KdfParams,
+    ) -> Result<Vec<u8>, String> {
+        let salt = crypto::gen_field_salt();
+        let cipher = crypto::FieldCipher::derive(password_hash, &salt, params)
+            .map_err(|e| format!("Failed to encrypt: {}", e))?;
+
+        let accounts = accounts
+            .iter()
+            .cloned()
+            .map(|mut account| {
+                account.security_token = cipher.seal(&account.security_token);
+                account.password = cipher.seal(&account.password);
+                account
+            })
+            .collect();
+
+        let file = Self {
+            format: SEALED_FILE_FORMAT.to_string(),
+            version: SEALED_FILE_VERSION,
+            ops_limit: params.ops_limit,
+            mem_limit: params.mem_limit,
+            salt: sodiumoxide::hex::encode(&salt),
+            check: cipher.seal(SEALED_CHECK_VALUE),
+            accounts,
+        };
+        serde_json::to_vec_pretty(&file).map_err(|e| format!("Failed to serialize accounts: {}", e))
+    }
+
+    fn open(self, password_hash: &[u8]) -> Result<Vec<Account>, String> {
+        let cipher = self.cipher(password_hash)?;
+        self.accounts
+            .into_iter()
+            .map(|mut account| {
+                account.security_token = cipher
+                    .open(&account.security_token)
+                    .map_err(|e| format!("Failed to decrypt: {}", e))?;
+                account.password = cipher
+                    .open(&account.password)
+                    .map_err(|e| format!("Failed to decrypt: {}", e))?;
+                Ok(account)
+            })
+            .collect()
+    }
+
+    /// The accounts as stored, with the sealed values blanked out.
+    fn readable_accounts(self) -> Vec<Account> {
+        self.accounts
+            .into_iter()
+            .map(|mut account| {
+                account.security_token.clear();
+                account.password.clear();
+                account
+            })
+            .collect()
+    }
+}
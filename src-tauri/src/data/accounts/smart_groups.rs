@@ -0,0 +1,215 @@
+const SMART_GROUPS_FILE_NAME: &str = "SmartGroups.json";
+const TAG_MAX_LEN: usize = 32;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmartGroupInfo {
+    pub name: String,
+    pub query: String,
+    pub user_ids: Vec<i64>,
+    pub error: Option<String>,
+}
+
+fn normalize_tag(tag: &str) -> Result<String, String> {
+    let tag = tag.trim();
+    let valid = !tag.is_empty()
+        && tag.chars().count() <= TAG_MAX_LEN
+        && tag
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_' || c == '.');
+    if !valid {
+        return Err(format!(
+            "Tags are 1-{} letters, digits, dashes, dots or underscores",
+            TAG_MAX_LEN
+        ));
+    }
+    Ok(tag.to_string())
+}
+
+impl AccountStore {
+    fn smart_groups_path(&self) -> PathBuf {
+        self.data_dir().join(SMART_GROUPS_FILE_NAME)
+    }
+
+    fn read_smart_groups(&self) -> Result<BTreeMap<String, String>, String> {
+        match fs::read(self.smart_groups_path()) {
+            Ok(data) => serde_json::from_slice(&data)
+                .map_err(|e| format!("Failed to parse smart groups: {}", e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(e) => Err(format!("Failed to read smart groups: {}", e)),
+        }
+    }
+
+    fn write_smart_groups(&self, groups: &BTreeMap<String, String>) -> Result<(), String> {
+        let data = serde_json::to_vec_pretty(groups)
+            .map_err(|e| format!("Failed to serialize smart groups: {}", e))?;
+        write_atomic(&self.smart_groups_path(), &data)
+            .map_err(|e| format!("Failed to write smart groups: {}", e))
+    }
+
+    /// Remembers the latest known balance so `robux` queries can use it.
+    pub fn record_robux(&self, user_id: i64, robux: i64) -> Result<(), String> {
+        self.robux_balances
+            .lock()
+            .map_err(|e| e.to_string())?
+            .insert(user_id, robux);
+        Ok(())
+    }
+
+    pub fn filter_accounts(
+        &self,
+        accounts: Vec<Account>,
+        query: &AccountQuery,
+    ) -> Result<Vec<Account>, String> {
+        let balances = self.robux_balances.lock().map_err(|e| e.to_string())?;
+        let now = Utc::now();
+        Ok(accounts
+            .into_iter()
+            .filter(|account| {
+                query.matches(&QuerySubject {
+                    user_id: account.user_id,
+                    username: &account.username,
+                    alias: &account.alias,
+                    group: &account.group,
+                    vault: &account.vault,
+                    tags: &account.tags,
+                    fields: &account.fields,
+                    valid: account.valid,
+                    has_password: !account.password.is_empty(),
+                    last_use_age: (now - account.last_use).num_seconds(),
+                    robux: balances.get(&account.user_id).copied(),
+                })
+            })
+            .collect())
+    }
+
+    /// Parses `query`, or the saved query of the smart group with that name.
+    pub fn parse_query(&self, query: &str) -> Result<AccountQuery, String> {
+        let query = query.trim();
+        match self.read_smart_groups()?.get(query) {
+            Some(saved) => AccountQuery::parse(saved),
+            None => AccountQuery::parse(query),
+        }
+    }
+
+    /// Accounts matching a query or smart group name, in list order.
+    pub fn query(&self, query: &str) -> Result<Vec<Account>, String> {
+        self.query_parsed(&self.parse_query(query)?)
+    }
+
+    pub fn query_parsed(&self, query: &AccountQuery) -> Result<Vec<Account>, String> {
+        self.filter_accounts(self.get_all()?, query)
+    }
+
+    /// Explicit ids followed by any query matches not already listed.
+    pub fn resolve_selection(
+        &self,
+        user_ids: &[i64],
+        query: Option<&str>,
+    ) -> Result<Vec<i64>, String> {
+        let mut resolved = user_ids.to_vec();
+        if let Some(query) = query.filter(|q| !q.trim().is_empty()) {
+            for account in self.query(query)? {
+                if !resolved.contains(&account.user_id) {
+                    resolved.push(account.user_id);
+                }
+            }
+        }
+        Ok(resolved)
+    }
+
+    pub fn list_smart_groups(&self) -> Result<Vec<SmartGroupInfo>, String> {
+        let accounts = self.get_all()?;
+        self.read_smart_groups()?
+            .into_iter()
+            .map(|(name, query)| {
+                let (user_ids, error) = match AccountQuery::parse(&query) {
+                    Ok(parsed) => (
+                        self.filter_accounts(accounts.clone(), &parsed)?
+                            .iter()
+                            .map(|a| a.user_id)
+                            .collect(),
+                        None,
+                    ),
+                    Err(e) => (Vec::new(), Some(e)),
+                };
+                Ok(SmartGroupInfo {
+                    name,
+                    query,
+                    user_ids,
+                    error,
+                })
+            })
+            .collect()
+    }
+
+    pub fn save_smart_group(&self, name: &str, query: &str) -> Result<(), String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Smart group name cannot be empty".to_string());
+        }
+        AccountQuery::parse(query)?;
+        let mut groups = self.read_smart_groups()?;
+        groups.insert(name.to_string(), query.trim().to_string());
+        self.write_smart_groups(&groups)
+    }
+
+    pub fn delete_smart_group(&self, name: &str) -> Result<bool, String> {
+        let mut groups = self.read_smart_groups()?;
+        if groups.remove(name).is_none() {
+            return Ok(false);
+        }
+        self.write_smart_groups(&groups)?;
+        Ok(true)
+    }
+
+    /// Adds and removes tags on each listed account, saving each store once.
+    pub fn edit_tags(
+        &self,
+        user_ids: &[i64],
+        add: &[String],
+        remove: &[String],
+    ) -> Result<usize, String> {
+        let add = add
+            .iter()
+            .map(|t| normalize_tag(t))
+            .collect::<Result<Vec<_>, _>>()?;
+        let vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        if self.is_locked() {
+            return Err(LOCKED_ERROR.to_string());
+        }
+
+        let mut changed = 0;
+        let mut touched: Vec<&AccountStore> = Vec::new();
+        for store in std::iter::once(self).chain(vaults.values()) {
+            let mut accounts = store.accounts.lock().map_err(|e| e.to_string())?;
+            let mut store_changed = false;
+            for account in accounts
+                .iter_mut()
+                .filter(|a| user_ids.contains(&a.user_id))
+            {
+                let before = account.tags.clone();
+                account
+                    .tags
+                    .retain(|t| !remove.iter().any(|r| r.trim().eq_ignore_ascii_case(t)));
+                for tag in &add {
+                    if !account.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                        account.tags.push(tag.clone());
+                    }
+                }
+                if account.tags != before {
+                    changed += 1;
+                    store_changed = true;
+                }
+            }
+            if store_changed {
+                touched.push(store);
+            }
+        }
+
+        for store in touched {
+            store.save()?;
+        }
+        Ok(changed)
+    }
+}
@@ -1,6 +1,13 @@
 pub struct AccountStore {
     accounts: Mutex<Vec<Account>>,
     password_hash: Mutex<Option<Vec<u8>>>,
+    format: Mutex<crypto::ContainerFormat>,
+    scope: Mutex<EncryptionScope>,
+    damaged: Mutex<Option<String>>,
+    backup_limit: Mutex<usize>,
+    locked: std::sync::atomic::AtomicBool,
+    vaults: Mutex<BTreeMap<String, AccountStore>>,
+    robux_balances: Mutex<HashMap<i64, i64>>,
     file_path: PathBuf,
 }
 
@@ -14,12 +21,26 @@ pub struct OldAccountImportSummary {
 }
 
 const IMPORT_PASSWORD_REQUIRED: &str = "IMPORT_PASSWORD_REQUIRED";
+pub const LOCKED_ERROR: &str = "LOCKED";
+
+fn wipe(value: &mut String) {
+    // Zero bytes are valid UTF-8, so the string stays well formed.
+    unsafe { sodiumoxide::utils::memzero(value.as_bytes_mut()) };
+    value.clear();
+}
 
 impl AccountStore {
     pub fn new(file_path: PathBuf) -> Self {
         Self {
             accounts: Mutex::new(Vec::new()),
             password_hash: Mutex::new(None),
+            format: Mutex::new(crypto::ContainerFormat::default()),
+            scope: Mutex::new(EncryptionScope::File),
+            damaged: Mutex::new(None),
+            backup_limit: Mutex::new(DEFAULT_BACKUP_LIMIT),
+            locked: std::sync::atomic::AtomicBool::new(false),
+            vaults: Mutex::new(BTreeMap::new()),
+            robux_balances: Mutex::new(HashMap::new()),
             file_path,
         }
     }
@@ -32,7 +53,11 @@ impl AccountStore {
         let data =
             fs::read(&self.file_path).map_err(|e| format!("Failed to read account file: {}", e))?;
 
-        Ok(crypto::is_encrypted(&data))
+        Ok(Self::is_protected(&data))
+    }
+
+    fn is_protected(data: &[u8]) -> bool {
+        crypto::is_encrypted(data) || SealedAccountFile::parse(data).is_some()
     }
 
     pub fn needs_password(&self) -> Result<bool, String> {
@@ -64,7 +89,17 @@ impl AccountStore {
     }
 
     pub fn load_with_password(&self, password: &str) -> Result<(), String> {
-        let hash = crypto::hash_password(password.trim());
+        self.load_with_key(crypto::hash_password(password.trim()))
+    }
+
+    fn load_with_key(&self, hash: Vec<u8>) -> Result<(), String> {
+        self.read_with_key(hash)?;
+        self.locked
+            .store(false, std::sync::atomic::Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn read_with_key(&self, hash: Vec<u8>) -> Result<(), String> {
         if !self.file_path.exists() {
             let mut password_hash = self.password_hash.lock().map_err(|e| e.to_string())?;
             *password_hash = Some(hash);
@@ -83,13 +118,13 @@ impl AccountStore {
             return Ok(());
         }
 
-        let accounts = if crypto::is_encrypted(&data) {
-            let decrypted =
-                crypto::decrypt(&data, &hash).map_err(|e| format!("Failed to decrypt: {}", e))?;
-            serde_json::from_slice::<Vec<Account>>(&decrypted)
-                .map_err(|e| format!("Failed to parse account JSON: {}", e))?
-        } else {
-            Self::decode_plain_or_legacy_accounts(&data)?
+        let accounts = match Self::decode_protected(&data, &hash)? {
+            Some((accounts, format, scope)) => {
+                self.set_format(format)?;
+                self.set_scope(scope)?;
+                accounts
+            }
+            None => Self::decode_plain_or_legacy_accounts(&data)?,
         };
 
         let mut store = self.accounts.lock().map_err(|e| e.to_string())?;
@@ -101,35 +136,114 @@ impl AccountStore {
         Ok(())
     }
 
+    pub fn is_locked(&self) -> bool {
+        self.locked.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Drops the decrypted accounts and the password hash from memory. Until
+    /// the next unlock, reads and saves fail with `LOCKED_ERROR`. Returns
+    /// false when the file is not password protected and cannot be locked.
+    pub fn lock(&self) -> Result<bool, String> {
+        if !self.is_encrypted()? {
+            return Ok(false);
+        }
+
+        let mut accounts = self.accounts.lock().map_err(|e| e.to_string())?;
+        for account in accounts.iter_mut() {
+            wipe(&mut account.security_token);
+            wipe(&mut account.password);
+        }
+        accounts.clear();
+
+        let mut password_hash = self.password_hash.lock().map_err(|e| e.to_string())?;
+        if let Some(hash) = password_hash.as_mut() {
+            sodiumoxide::utils::memzero(hash);
+        }
+        *password_hash = None;
+        self.locked.store(true, std::sync::atomic::Ordering::SeqCst);
+        Ok(true)
+    }
+
     pub fn save(&self) -> Result<(), String> {
+        if self.is_locked() {
+            return Err(LOCKED_ERROR.to_string());
+        }
         let accounts = self.accounts.lock().map_err(|e| e.to_string())?;
 
-        let json = serde_json::to_string_pretty(&*accounts)
-            .map_err(|e| format!("Failed to serialize accounts: {}", e))?;
-
         let password_hash = self.password_hash.lock().map_err(|e| e.to_string())?;
+        let format = *self.format.lock().map_err(|e| e.to_string())?;
+        let scope = *self.scope.lock().map_err(|e| e.to_string())?;
 
-        let data = if let Some(hash) = password_hash.as_ref() {
-            crypto::encrypt(&json, hash).map_err(|e| format!("Failed to encrypt: {}", e))?
-        } else {
-            json.into_bytes()
+        let data = Self::encode_accounts(&accounts, password_hash.as_deref(), format, scope)?;
+        drop(password_hash);
+
+        self.write_account_file(&data)
+    }
+
+    fn encode_accounts(
+        accounts: &[Account],
+        password_hash: Option<&[u8]>,
+        format: crypto::ContainerFormat,
+        scope: EncryptionScope,
+    ) -> Result<Vec<u8>, String> {
+        let Some(hash) = password_hash else {
+            return serde_json::to_vec_pretty(accounts)
+                .map_err(|e| format!("Failed to serialize accounts: {}", e));
         };
 
-        fs::write(&self.file_path, data)
-            .map_err(|e| format!("Failed to write account file: {}", e))?;
+        match (scope, format) {
+            (EncryptionScope::Fields, crypto::ContainerFormat::Versioned(params)) => {
+                SealedAccountFile::seal(accounts, hash, params)
+            }
+            (EncryptionScope::Fields, crypto::ContainerFormat::Legacy) => {
+                SealedAccountFile::seal(accounts, hash, crypto::KdfParams::default())
+            }
+            (EncryptionScope::File, _) => {
+                let json = serde_json::to_string_pretty(accounts)
+                    .map_err(|e| format!("Failed to serialize accounts: {}", e))?;
+                crypto::encrypt(&json, hash, format)
+                    .map_err(|e| format!("Failed to encrypt: {}", e))
+            }
+        }
+    }
+
+    /// Opens a password protected file, returning `None` for plain JSON.
+    fn decode_protected(
+        data: &[u8],
+        password_hash: &[u8],
+    ) -> Result<Option<(Vec<Account>, crypto::ContainerFormat, EncryptionScope)>, String> {
+        if crypto::is_encrypted(data) {
+            let decrypted = crypto::decrypt(data, password_hash)
+                .map_err(|e| format!("Failed to decrypt: {}", e))?;
+            let format = crypto::inspect(data).map_err(|e| format!("Failed to decrypt: {}", e))?;
+            let accounts = serde_json::from_slice::<Vec<Account>>(&decrypted)
+                .map_err(|e| format!("Failed to parse account JSON: {}", e))?;
+            return Ok(Some((accounts, format, EncryptionScope::File)));
+        }
 
+        if let Some(file) = SealedAccountFile::parse(data) {
+            let format = crypto::ContainerFormat::Versioned(file.params());
+            let accounts = file.open(password_hash)?;
+            return Ok(Some((accounts, format, EncryptionScope::Fields)));
+        }
+
+        Ok(None)
+    }
+
+    fn validate_password(value: &str) -> Result<(), String> {
+        let trimmed = value.trim();
+        if trimmed.is_empty() {
+            return Err("Password cannot be empty".to_string());
+        }
+        if trimmed.chars().count() < 8 {
+            return Err("Password must be at least 8 characters".to_string());
+        }
         Ok(())
     }
 
     pub fn set_password(&self, password: Option<&str>) -> Result<(), String> {
         if let Some(value) = password {
-            let trimmed = value.trim();
-            if trimmed.is_empty() {
-                return Err("Password cannot be empty".to_string());
-            }
-            if trimmed.chars().count() < 8 {
-                return Err("Password must be at least 8 characters".to_string());
-            }
+            Self::validate_password(value)?;
         }
         let mut password_hash = self.password_hash.lock().map_err(|e| e.to_string())?;
         *password_hash = password.map(|p| crypto::hash_password(p.trim()));
@@ -137,12 +251,264 @@ impl AccountStore {
         self.save()
     }
 
+    fn keychain_account(&self) -> String {
+        self.file_path.display().to_string()
+    }
+
+    pub fn data_dir(&self) -> PathBuf {
+        self.file_path
+            .parent()
+            .map(|p| p.to_path_buf())
+            .unwrap_or_default()
+    }
+
+    /// Saves the current unlock key to `backend` so the next start can open
+    /// the file without asking for the password.
+    pub fn remember_unlock_key(&self, backend: &dyn SecretBackend) -> Result<(), String> {
+        let password_hash = self.password_hash.lock().map_err(|e| e.to_string())?;
+        let hash = password_hash
+            .as_ref()
+            .ok_or_else(|| "Accounts are not password protected".to_string())?;
+        backend.store(&self.keychain_account(), hash)
+    }
+
+    pub fn forget_unlock_key(&self, backend: &dyn SecretBackend) -> Result<(), String> {
+        backend.delete(&self.keychain_account())
+    }
+
+    pub fn has_remembered_unlock_key(&self, backend: &dyn SecretBackend) -> bool {
+        backend
+            .load(&self.keychain_account())
+            .is_ok_and(|key| key.is_some())
+    }
+
+    /// Tries to unlock with a key from `backend`. A key that no longer opens
+    /// the file (the password changed elsewhere) is removed; read errors and
+    /// the like keep it for the next start.
+    pub fn unlock_with_remembered_key(&self, backend: &dyn SecretBackend) -> Result<bool, String> {
+        let Some(key) = backend.load(&self.keychain_account())? else {
+            return Ok(false);
+        };
+        if let Err(e) = self.load_with_key(key) {
+            if e == format!(
+                "Failed to decrypt: {}",
+                crypto::CryptoError::DecryptionFailed
+            ) {
+                let _ = self.forget_unlock_key(backend);
+            }
+            return Err(e);
+        }
+        Ok(true)
+    }
+
+    pub fn set_format(&self, format: crypto::ContainerFormat) -> Result<(), String> {
+        *self.format.lock().map_err(|e| e.to_string())? = format;
+        Ok(())
+    }
+
+    pub fn set_scope(&self, scope: EncryptionScope) -> Result<(), String> {
+        *self.scope.lock().map_err(|e| e.to_string())? = scope;
+        Ok(())
+    }
+
+    /// Describes the container of the account file on disk, or `None` when the
+    /// file is missing or stored as plain JSON.
+    pub fn container_info(&self) -> Result<Option<crypto::ContainerInfo>, String> {
+        if !self.file_path.exists() {
+            return Ok(None);
+        }
+        let data =
+            fs::read(&self.file_path).map_err(|e| format!("Failed to read account file: {}", e))?;
+        if let Some(file) = SealedAccountFile::parse(&data) {
+            let mut info =
+                crypto::ContainerInfo::from(crypto::ContainerFormat::Versioned(file.params()));
+            info.scope = EncryptionScope::Fields.as_str();
+            return Ok(Some(info));
+        }
+        if !crypto::is_encrypted(&data) {
+            return Ok(None);
+        }
+        crypto::inspect(&data)
+            .map(|format| Some(format.into()))
+            .map_err(|e| format!("Failed to read account file: {}", e))
+    }
+
+    /// Lists accounts without needing the password when the file keeps its
+    /// metadata readable. Secrets are blank while the file is locked, and open
+    /// vaults are listed as in `get_all`.
+    pub fn list_readable(&self) -> Result<Vec<Account>, String> {
+        if !self.needs_password()? {
+            return self.get_all();
+        }
+        let data =
+            fs::read(&self.file_path).map_err(|e| format!("Failed to read account file: {}", e))?;
+        let vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        let readable = match SealedAccountFile::parse(&data) {
+            Some(file) => file.readable_accounts(),
+            None if !vaults.is_empty() => Vec::new(),
+            None => return Err(LOCKED_ERROR.to_string()),
+        };
+        Ok(Self::with_vault_accounts(&vaults, readable))
+    }
+
+    /// Re-encrypts the account file with argon2id at `params`, optionally under
+    /// a new password. The current password must open the file on disk, and
+    /// the new container replaces it atomically.
+    pub fn rekey(
+        &self,
+        current_password: &str,
+        new_password: Option<&str>,
+        params: crypto::KdfParams,
+    ) -> Result<crypto::ContainerInfo, String> {
+        if self.is_locked() {
+            return Err(LOCKED_ERROR.to_string());
+        }
+        if let Some(value) = new_password {
+            Self::validate_password(value)?;
+        }
+
+        // Held for the whole rekey so no save can land between read and rename.
+        let _accounts = self.accounts.lock().map_err(|e| e.to_string())?;
+
+        let data =
+            fs::read(&self.file_path).map_err(|e| format!("Failed to read account file: {}", e))?;
+        if !Self::is_protected(&data) {
+            return Err("Account file is not password protected".to_string());
+        }
+
+        let current_hash = crypto::hash_password(current_password.trim());
+        let (accounts, _, scope) = Self::decode_protected(&data, &current_hash)
+            .ok()
+            .flatten()
+            .ok_or_else(|| "Current password is incorrect".to_string())?;
+
+        let new_hash = new_password
+            .map(|p| crypto::hash_password(p.trim()))
+            .unwrap_or(current_hash);
+        let format = crypto::ContainerFormat::Versioned(params);
+        let encrypted = Self::encode_accounts(&accounts, Some(&new_hash), format, scope)?;
+        self.write_account_file(&encrypted)?;
+
+        *self.password_hash.lock().map_err(|e| e.to_string())? = Some(new_hash);
+        self.set_format(format)?;
+        self.set_scope(scope)?;
+        self.container_info()?
+            .ok_or_else(|| "Account file is not password protected".to_string())
+    }
+
+    /// Accounts from this file and every open vault. Vault accounts carry the
+    /// vault name; a user already listed earlier is not repeated.
     pub fn get_all(&self) -> Result<Vec<Account>, String> {
+        let vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        let own = match self.own_accounts() {
+            Ok(accounts) => accounts,
+            Err(e) if e == LOCKED_ERROR && !vaults.is_empty() => Vec::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(Self::with_vault_accounts(&vaults, own))
+    }
+
+    fn with_vault_accounts(
+        vaults: &BTreeMap<String, AccountStore>,
+        mut all: Vec<Account>,
+    ) -> Vec<Account> {
+        for (name, vault) in vaults.iter() {
+            let Ok(accounts) = vault.own_accounts() else {
+                continue;
+            };
+            for mut account in accounts {
+                if all.iter().any(|a| a.user_id == account.user_id) {
+                    continue;
+                }
+                account.vault = name.clone();
+                all.push(account);
+            }
+        }
+        all
+    }
+
+    pub fn add(&self, mut account: Account) -> Result<(), String> {
+        let vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        account.vault.clear();
+        match Self::vault_holding(&vaults, account.user_id) {
+            Some(vault) => vault.own_add(account),
+            None => self.own_add(account),
+        }
+    }
+
+    pub fn remove(&self, user_id: i64) -> Result<bool, String> {
+        let vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        match Self::vault_holding(&vaults, user_id) {
+            Some(vault) if !self.own_contains(user_id) => vault.own_remove(user_id),
+            _ => self.own_remove(user_id),
+        }
+    }
+
+    pub fn update(&self, mut account: Account) -> Result<bool, String> {
+        let vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        let hint = std::mem::take(&mut account.vault);
+        if let Some(vault) = vaults.get(&hint) {
+            if vault.own_contains(account.user_id) {
+                return vault.own_update(account);
+            }
+        }
+        match Self::vault_holding(&vaults, account.user_id) {
+            Some(vault) if !self.own_contains(account.user_id) => vault.own_update(account),
+            _ => self.own_update(account),
+        }
+    }
+
+    pub fn reorder(&self, user_ids: &[i64]) -> Result<(), String> {
+        let vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        if !self.is_locked() {
+            self.own_reorder(user_ids)?;
+        }
+        for vault in vaults.values() {
+            vault.own_reorder(user_ids)?;
+        }
+        Ok(())
+    }
+
+    pub fn rename_group(&self, old_name: &str, new_name: &str) -> Result<usize, String> {
+        let vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        let mut renamed = if self.is_locked() {
+            0
+        } else {
+            self.own_rename_group(old_name, new_name)?
+        };
+        for vault in vaults.values() {
+            renamed += vault.own_rename_group(old_name, new_name)?;
+        }
+        Ok(renamed)
+    }
+
+    /// Saves this file and every open vault.
+    pub fn save_all(&self) -> Result<(), String> {
+        let vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        if !self.is_locked() {
+            self.save()?;
+        }
+        for vault in vaults.values() {
+            vault.save()?;
+        }
+        Ok(())
+    }
+
+    fn own_contains(&self, user_id: i64) -> bool {
+        self.accounts
+            .lock()
+            .is_ok_and(|accounts| accounts.iter().any(|a| a.user_id == user_id))
+    }
+
+    fn own_accounts(&self) -> Result<Vec<Account>, String> {
+        if self.is_locked() {
+            return Err(LOCKED_ERROR.to_string());
+        }
         let accounts = self.accounts.lock().map_err(|e| e.to_string())?;
         Ok(accounts.clone())
     }
 
-    pub fn add(&self, account: Account) -> Result<(), String> {
+    fn own_add(&self, account: Account) -> Result<(), String> {
         let mut accounts = self.accounts.lock().map_err(|e| e.to_string())?;
 
         if let Some(existing) = accounts.iter_mut().find(|a| a.user_id == account.user_id) {
@@ -158,7 +524,7 @@ impl AccountStore {
         self.save()
     }
 
-    pub fn remove(&self, user_id: i64) -> Result<bool, String> {
+    fn own_remove(&self, user_id: i64) -> Result<bool, String> {
         let mut accounts = self.accounts.lock().map_err(|e| e.to_string())?;
         let initial_len = accounts.len();
         accounts.retain(|a| a.user_id != user_id);
@@ -172,7 +538,7 @@ impl AccountStore {
         Ok(removed)
     }
 
-    pub fn update(&self, account: Account) -> Result<bool, String> {
+    fn own_update(&self, account: Account) -> Result<bool, String> {
         let mut accounts = self.accounts.lock().map_err(|e| e.to_string())?;
 
         if let Some(existing) = accounts.iter_mut().find(|a| a.user_id == account.user_id) {
@@ -185,7 +551,7 @@ impl AccountStore {
         }
     }
 
-    pub fn reorder(&self, user_ids: &[i64]) -> Result<(), String> {
+    fn own_reorder(&self, user_ids: &[i64]) -> Result<(), String> {
         let mut accounts = self.accounts.lock().map_err(|e| e.to_string())?;
 
         if accounts.is_empty() || user_ids.is_empty() {
@@ -207,6 +573,23 @@ impl AccountStore {
         self.save()
     }
 
+    fn own_rename_group(&self, old_name: &str, new_name: &str) -> Result<usize, String> {
+        let mut accounts = self.accounts.lock().map_err(|e| e.to_string())?;
+        let mut renamed = 0usize;
+
+        for account in accounts.iter_mut().filter(|a| a.group == old_name) {
+            account.group = new_name.to_string();
+            renamed += 1;
+        }
+
+        drop(accounts);
+        if renamed > 0 {
+            self.save()?;
+        }
+
+        Ok(renamed)
+    }
+
     fn decode_plain_or_legacy_accounts(data: &[u8]) -> Result<Vec<Account>, String> {
         if let Ok(accounts) = serde_json::from_slice::<Vec<Account>>(data) {
             return Ok(accounts);
@@ -225,15 +608,17 @@ impl AccountStore {
             return Ok(Vec::new());
         }
 
-        if crypto::is_encrypted(data) {
+        if Self::is_protected(data) {
             let password_hash = self.password_hash.lock().map_err(|e| e.to_string())?;
             let hash = password_hash
-                .as_ref()
+                .clone()
                 .ok_or_else(|| "Password required for encrypted file".to_string())?;
-            let decrypted =
-                crypto::decrypt(data, hash).map_err(|e| format!("Failed to decrypt: {}", e))?;
-            return serde_json::from_slice::<Vec<Account>>(&decrypted)
-                .map_err(|e| format!("Failed to parse account JSON: {}", e));
+            drop(password_hash);
+            if let Some((accounts, format, scope)) = Self::decode_protected(data, &hash)? {
+                self.set_format(format)?;
+                self.set_scope(scope)?;
+                return Ok(accounts);
+            }
         }
 
         Self::decode_plain_or_legacy_accounts(data)
@@ -248,15 +633,15 @@ impl AccountStore {
             return Ok(Vec::new());
         }
 
-        if crypto::is_encrypted(data) {
+        if Self::is_protected(data) {
             let Some(password) = import_password else {
                 return Err(IMPORT_PASSWORD_REQUIRED.to_string());
             };
             let hash = crypto::hash_password(password);
-            let decrypted = crypto::decrypt(data, &hash)
-                .map_err(|_| "Import password is incorrect".to_string())?;
-            return serde_json::from_slice::<Vec<Account>>(&decrypted)
-                .map_err(|e| format!("Failed to parse account JSON: {}", e));
+            return match Self::decode_protected(data, &hash) {
+                Ok(Some((accounts, _, _))) => Ok(accounts),
+                _ => Err("Import password is incorrect".to_string()),
+            };
         }
 
         Self::decode_plain_or_legacy_accounts(data)
@@ -0,0 +1,220 @@
+const VAULT_DIR_NAME: &str = "Vaults";
+const VAULT_NAME_MAX_LEN: usize = 32;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultInfo {
+    pub name: String,
+    pub open: bool,
+    pub encrypted: bool,
+    pub account_count: Option<usize>,
+}
+
+impl AccountStore {
+    fn vault_dir(&self) -> PathBuf {
+        self.data_dir().join(VAULT_DIR_NAME)
+    }
+
+    fn vault_path(&self, name: &str) -> Result<PathBuf, String> {
+        let valid = !name.is_empty()
+            && name.chars().count() <= VAULT_NAME_MAX_LEN
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '-' || c == '_')
+            && name.trim() == name;
+        if !valid {
+            return Err(format!(
+                "Vault names are 1-{} letters, digits, spaces, dashes or underscores",
+                VAULT_NAME_MAX_LEN
+            ));
+        }
+        Ok(self.vault_dir().join(format!("{}.json", name)))
+    }
+
+    fn vault_holding(
+        vaults: &BTreeMap<String, AccountStore>,
+        user_id: i64,
+    ) -> Option<&AccountStore> {
+        vaults.values().find(|vault| vault.own_contains(user_id))
+    }
+
+    /// Lists the vault files next to the account file, open ones first by name.
+    pub fn list_vaults(&self) -> Result<Vec<VaultInfo>, String> {
+        let vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        let mut names: Vec<String> = match fs::read_dir(self.vault_dir()) {
+            Ok(entries) => entries
+                .flatten()
+                .filter_map(|entry| {
+                    let name = entry.file_name().to_string_lossy().into_owned();
+                    let name = name.strip_suffix(".json")?.to_string();
+                    self.vault_path(&name).ok().map(|_| name)
+                })
+                .collect(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(format!("Failed to read vaults: {}", e)),
+        };
+        names.sort();
+
+        let mut list: Vec<VaultInfo> = names
+            .into_iter()
+            .map(|name| match vaults.get(&name) {
+                Some(vault) => VaultInfo {
+                    open: true,
+                    encrypted: vault.is_encrypted().unwrap_or(false),
+                    account_count: vault.own_accounts().ok().map(|a| a.len()),
+                    name,
+                },
+                None => VaultInfo {
+                    open: false,
+                    encrypted: AccountStore::new(self.vault_dir().join(format!("{}.json", name)))
+                        .is_encrypted()
+                        .unwrap_or(false),
+                    account_count: None,
+                    name,
+                },
+            })
+            .collect();
+        list.sort_by_key(|v| !v.open);
+        Ok(list)
+    }
+
+    pub fn create_vault(&self, name: &str, password: &str) -> Result<(), String> {
+        let path = self.vault_path(name)?;
+        if path.exists() {
+            return Err(format!("Vault {} already exists", name));
+        }
+        Self::validate_password(password)?;
+        fs::create_dir_all(self.vault_dir())
+            .map_err(|e| format!("Failed to create vault folder: {}", e))?;
+
+        let vault = AccountStore::new(path);
+        vault.set_format(*self.format.lock().map_err(|e| e.to_string())?)?;
+        vault.set_scope(*self.scope.lock().map_err(|e| e.to_string())?)?;
+        vault.set_password(Some(password))?;
+
+        let mut vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        vaults.insert(name.to_string(), vault);
+        Ok(())
+    }
+
+    pub fn open_vault(&self, name: &str, password: &str) -> Result<usize, String> {
+        let path = self.vault_path(name)?;
+        if !path.exists() {
+            return Err(format!("Vault {} not found", name));
+        }
+        let mut vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        if let Some(vault) = vaults.get(name) {
+            return vault.own_accounts().map(|a| a.len());
+        }
+
+        let vault = AccountStore::new(path);
+        if vault.needs_password()? {
+            vault.load_with_password(password)?;
+        } else {
+            vault.load()?;
+        }
+        let count = vault.own_accounts()?.len();
+        vaults.insert(name.to_string(), vault);
+        Ok(count)
+    }
+
+    /// Wipes the vault's decrypted accounts and stops listing them.
+    pub fn close_vault(&self, name: &str) -> Result<bool, String> {
+        let mut vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        let Some(vault) = vaults.remove(name) else {
+            return Ok(false);
+        };
+        vault.lock()?;
+        Ok(true)
+    }
+
+    pub fn close_all_vaults(&self) -> Result<usize, String> {
+        let mut vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        let closed = std::mem::take(&mut *vaults);
+        for vault in closed.values() {
+            vault.lock()?;
+        }
+        Ok(closed.len())
+    }
+
+    /// Deletes an open, empty vault.
+    pub fn delete_vault(&self, name: &str) -> Result<(), String> {
+        let path = self.vault_path(name)?;
+        let mut vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        let vault = vaults
+            .get(name)
+            .ok_or_else(|| "Open the vault before deleting it".to_string())?;
+        if !vault.own_accounts()?.is_empty() {
+            return Err("Move the vault's accounts out before deleting it".to_string());
+        }
+        vaults.remove(name);
+        fs::remove_file(&path).map_err(|e| format!("Failed to delete vault: {}", e))
+    }
+
+    /// Moves accounts into `target`, an open vault or the main file when
+    /// empty. The target is saved before the sources, so a failure part way
+    /// leaves a copy in both rather than in neither.
+    pub fn move_accounts_to_vault(&self, user_ids: &[i64], target: &str) -> Result<usize, String> {
+        let vaults = self.vaults.lock().map_err(|e| e.to_string())?;
+        let target_store = if target.is_empty() {
+            self
+        } else {
+            vaults
+                .get(target)
+                .ok_or_else(|| format!("Vault {} is not open", target))?
+        };
+        if target_store.is_locked() {
+            return Err(LOCKED_ERROR.to_string());
+        }
+
+        let mut moving: Vec<(&AccountStore, Account)> = Vec::new();
+        for user_id in user_ids {
+            let source = if self.own_contains(*user_id) {
+                self
+            } else {
+                match Self::vault_holding(&vaults, *user_id) {
+                    Some(vault) => vault,
+                    None => continue,
+                }
+            };
+            if std::ptr::eq(source, target_store) {
+                continue;
+            }
+            let account = source
+                .own_accounts()?
+                .into_iter()
+                .find(|a| a.user_id == *user_id);
+            if let Some(account) = account {
+                moving.push((source, account));
+            }
+        }
+        if moving.is_empty() {
+            return Ok(0);
+        }
+
+        {
+            let mut accounts = target_store.accounts.lock().map_err(|e| e.to_string())?;
+            for (_, account) in &moving {
+                accounts.retain(|a| a.user_id != account.user_id);
+                accounts.push(account.clone());
+            }
+        }
+        target_store.save()?;
+
+        let mut sources: Vec<&AccountStore> = Vec::new();
+        for (source, account) in &moving {
+            source
+                .accounts
+                .lock()
+                .map_err(|e| e.to_string())?
+                .retain(|a| a.user_id != account.user_id);
+            if !sources.iter().any(|s| std::ptr::eq(*s, *source)) {
+                sources.push(source);
+            }
+        }
+        for source in sources {
+            source.save()?;
+        }
+        Ok(moving.len())
+    }
+}
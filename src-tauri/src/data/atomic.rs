@@ -0,0 +1,26 @@
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+/// Writes next to the target, syncs, and renames over it, so a crash or a
+/// full disk mid-write leaves the previous file intact.
+pub fn write_atomic(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let tmp_path = path.with_file_name(format!("{}.tmp", file_name));
+
+    let result = (|| {
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(data)?;
+        file.sync_all()?;
+        drop(file);
+        fs::rename(&tmp_path, path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+    result
+}
@@ -0,0 +1,160 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+use super::csv;
+
+const HEADER: [&str; 5] = ["at", "user_id", "robux", "premium", "limiteds"];
+
+/// One account's balance at one snapshot. Every account recorded in the same
+/// snapshot shares its `at`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceSample {
+    pub at: DateTime<Utc>,
+    pub user_id: i64,
+    pub robux: i64,
+    pub premium: Option<bool>,
+    pub limiteds: Option<i64>,
+}
+
+/// The sum over all accounts recorded in one snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceTotal {
+    pub at: DateTime<Utc>,
+    pub accounts: usize,
+    pub robux: i64,
+    pub premium: usize,
+    pub limiteds: i64,
+}
+
+/// A fall between two consecutive samples of one account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceDrop {
+    pub user_id: i64,
+    pub from_at: DateTime<Utc>,
+    pub to_at: DateTime<Utc>,
+    pub from_robux: i64,
+    pub to_robux: i64,
+    pub robux_lost: i64,
+    pub limiteds_lost: i64,
+    pub premium_lost: bool,
+}
+
+/// A drop is flagged when at least `min_robux` Robux and `min_percent` of the
+/// previous balance are gone, or when `min_limiteds` limiteds are. A zero
+/// `min_limiteds` ignores inventory.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DropThreshold {
+    pub min_robux: i64,
+    pub min_percent: f64,
+    pub min_limiteds: i64,
+}
+
+/// Reads the history file. Unreadable rows, such as a line cut short by a
+/// crash, are skipped so one bad write never hides the rest of the history.
+pub fn parse(text: &str) -> Result<Vec<BalanceSample>, String> {
+    Ok(csv::parse(text)?
+        .into_iter()
+        .filter_map(|(_, record)| parse_record(&record))
+        .collect())
+}
+
+fn parse_record(record: &[String]) -> Option<BalanceSample> {
+    let [at, user_id, robux, premium, limiteds] = record else {
+        return None;
+    };
+    Some(BalanceSample {
+        at: DateTime::parse_from_rfc3339(at).ok()?.with_timezone(&Utc),
+        user_id: user_id.parse().ok()?,
+        robux: robux.parse().ok()?,
+        premium: match premium.as_str() {
+            "" => None,
+            value => Some(value.parse().ok()?),
+        },
+        limiteds: match limiteds.as_str() {
+            "" => None,
+            value => Some(value.parse().ok()?),
+        },
+    })
+}
+
+/// Serializes samples as a complete history file, header included.
+pub fn write(samples: &[BalanceSample]) -> String {
+    let mut out = String::new();
+    csv::write_record(&mut out, &HEADER);
+    for sample in samples {
+        csv::write_record(
+            &mut out,
+            &[
+                sample.at.to_rfc3339_opts(SecondsFormat::Secs, true),
+                sample.user_id.to_string(),
+                sample.robux.to_string(),
+                sample.premium.map(|p| p.to_string()).unwrap_or_default(),
+                sample.limiteds.map(|l| l.to_string()).unwrap_or_default(),
+            ],
+        );
+    }
+    out
+}
+
+pub fn totals(samples: &[BalanceSample]) -> Vec<BalanceTotal> {
+    let mut totals: BTreeMap<DateTime<Utc>, BalanceTotal> = BTreeMap::new();
+    for sample in samples {
+        let total = totals.entry(sample.at).or_insert(BalanceTotal {
+            at: sample.at,
+            accounts: 0,
+            robux: 0,
+            premium: 0,
+            limiteds: 0,
+        });
+        total.accounts += 1;
+        total.robux += sample.robux;
+        total.premium += usize::from(sample.premium == Some(true));
+        total.limiteds += sample.limiteds.unwrap_or(0);
+    }
+    totals.into_values().collect()
+}
+
+/// Compares each account's consecutive samples and returns the drops that
+/// meet `threshold`, oldest first.
+pub fn find_drops(samples: &[BalanceSample], threshold: &DropThreshold) -> Vec<BalanceDrop> {
+    let mut by_account: HashMap<i64, Vec<&BalanceSample>> = HashMap::new();
+    for sample in samples {
+        by_account.entry(sample.user_id).or_default().push(sample);
+    }
+
+    let mut drops = Vec::new();
+    for series in by_account.values_mut() {
+        series.sort_by_key(|s| s.at);
+        for pair in series.windows(2) {
+            let (before, after) = (pair[0], pair[1]);
+            let robux_lost = before.robux - after.robux;
+            let limiteds_lost = match (before.limiteds, after.limiteds) {
+                (Some(before), Some(after)) => (before - after).max(0),
+                _ => 0,
+            };
+            let robux_flagged = robux_lost > 0
+                && robux_lost >= threshold.min_robux
+                && robux_lost as f64 * 100.0 >= before.robux as f64 * threshold.min_percent;
+            let limiteds_flagged =
+                threshold.min_limiteds > 0 && limiteds_lost >= threshold.min_limiteds;
+            if robux_flagged || limiteds_flagged {
+                drops.push(BalanceDrop {
+                    user_id: before.user_id,
+                    from_at: before.at,
+                    to_at: after.at,
+                    from_robux: before.robux,
+                    to_robux: after.robux,
+                    robux_lost: robux_lost.max(0),
+                    limiteds_lost,
+                    premium_lost: before.premium == Some(true) && after.premium == Some(false),
+                });
+            }
+        }
+    }
+    drops.sort_by_key(|d| (d.to_at, d.user_id));
+    drops
+}
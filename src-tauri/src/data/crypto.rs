@@ -1,11 +1,115 @@
 use sodiumoxide::crypto::hash::sha512;
-use sodiumoxide::crypto::pwhash::argon2i13;
+use sodiumoxide::crypto::pwhash::{argon2i13, argon2id13};
 use sodiumoxide::crypto::secretbox;
 
 pub const RAM_HEADER: &[u8] = b"Roblox Account Manager created by ic3w0lf22 @ github.com .......";
 const TRANSITION_RAM_HEADER: &[u8] =
     b"Roblox Account Manager created by ic3w0lf2 and continued by niccdevs @ github.com .......";
 
+// Versioned containers follow RAM_HEADER with this marker, a version byte, the
+// KDF id and its limits, and the cipher id. Files without it are version 1:
+// argon2i13 at moderate limits over a secretbox.
+const CONTAINER_MAGIC: &[u8] = b"RAMCRYPT";
+const CONTAINER_VERSION: u8 = 2;
+const KDF_ARGON2ID13: u8 = 1;
+const CIPHER_XSALSA20POLY1305: u8 = 1;
+const CONTAINER_PARAMS_LEN: usize = 1 + 1 + 8 + 8 + 1;
+const SALT_LEN: usize = 16;
+const FIELD_PREFIX: &str = "enc:";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KdfParams {
+    pub ops_limit: u64,
+    pub mem_limit: u64,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self::from_method("moderate").unwrap()
+    }
+}
+
+impl KdfParams {
+    /// Maps an `EncryptionKdf` setting value to argon2id limits. The preset has
+    /// its own key because `EncryptionMethod` only records whether a password
+    /// is set. Empty or `default` is the moderate preset; anything unknown
+    /// yields `None`.
+    pub fn from_method(method: &str) -> Option<Self> {
+        let (ops, mem) = match method.trim().to_ascii_lowercase().as_str() {
+            "interactive" | "argon2id-interactive" => (
+                argon2id13::OPSLIMIT_INTERACTIVE,
+                argon2id13::MEMLIMIT_INTERACTIVE,
+            ),
+            "" | "default" | "moderate" | "argon2id" | "argon2id-moderate" => {
+                (argon2id13::OPSLIMIT_MODERATE, argon2id13::MEMLIMIT_MODERATE)
+            }
+            "sensitive" | "argon2id-sensitive" => (
+                argon2id13::OPSLIMIT_SENSITIVE,
+                argon2id13::MEMLIMIT_SENSITIVE,
+            ),
+            _ => return None,
+        };
+        Some(Self {
+            ops_limit: ops.0 as u64,
+            mem_limit: mem.0 as u64,
+        })
+    }
+
+    // Rejects limits a crafted file could use to stall or exhaust the machine.
+    pub fn is_sane(&self) -> bool {
+        (argon2id13::OPSLIMIT_INTERACTIVE.0 as u64..=argon2id13::OPSLIMIT_SENSITIVE.0 as u64 * 4)
+            .contains(&self.ops_limit)
+            && (argon2id13::MEMLIMIT_INTERACTIVE.0 as u64..=argon2id13::MEMLIMIT_SENSITIVE.0 as u64)
+                .contains(&self.mem_limit)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerFormat {
+    Legacy,
+    Versioned(KdfParams),
+}
+
+impl Default for ContainerFormat {
+    fn default() -> Self {
+        Self::Versioned(KdfParams::default())
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContainerInfo {
+    pub version: u8,
+    pub kdf: &'static str,
+    pub ops_limit: u64,
+    pub mem_limit: u64,
+    pub cipher: &'static str,
+    pub scope: &'static str,
+}
+
+impl From<ContainerFormat> for ContainerInfo {
+    fn from(format: ContainerFormat) -> Self {
+        match format {
+            ContainerFormat::Legacy => Self {
+                version: 1,
+                kdf: "argon2i13",
+                ops_limit: argon2i13::OPSLIMIT_MODERATE.0 as u64,
+                mem_limit: argon2i13::MEMLIMIT_MODERATE.0 as u64,
+                cipher: "xsalsa20poly1305",
+                scope: "file",
+            },
+            ContainerFormat::Versioned(params) => Self {
+                version: CONTAINER_VERSION,
+                kdf: "argon2id13",
+                ops_limit: params.ops_limit,
+                mem_limit: params.mem_limit,
+                cipher: "xsalsa20poly1305",
+                scope: "file",
+            },
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum CryptoError {
     MissingHeader,
@@ -32,6 +136,26 @@ pub fn hash_password(password: &str) -> Vec<u8> {
     digest.as_ref().to_vec()
 }
 
+fn derive_key_argon2id(
+    password_hash: &[u8],
+    salt: &[u8],
+    params: KdfParams,
+) -> Result<secretbox::Key, CryptoError> {
+    let salt = argon2id13::Salt::from_slice(salt).ok_or(CryptoError::InvalidData)?;
+
+    let mut key_bytes = [0u8; secretbox::KEYBYTES];
+    argon2id13::derive_key(
+        &mut key_bytes,
+        password_hash,
+        &salt,
+        argon2id13::OpsLimit(params.ops_limit as usize),
+        argon2id13::MemLimit(params.mem_limit as usize),
+    )
+    .map_err(|_| CryptoError::InvalidPassword)?;
+
+    secretbox::Key::from_slice(&key_bytes).ok_or(CryptoError::InvalidData)
+}
+
 pub fn derive_key(password_hash: &[u8], salt: &[u8]) -> Result<secretbox::Key, CryptoError> {
     let salt = argon2i13::Salt::from_slice(salt).ok_or(CryptoError::InvalidData)?;
 
@@ -52,43 +176,118 @@ pub fn is_encrypted(data: &[u8]) -> bool {
     data.starts_with(RAM_HEADER) || data.starts_with(TRANSITION_RAM_HEADER)
 }
 
-pub fn decrypt(encrypted: &[u8], password_hash: &[u8]) -> Result<Vec<u8>, CryptoError> {
-    let header = if encrypted.starts_with(RAM_HEADER) {
-        RAM_HEADER
-    } else if encrypted.starts_with(TRANSITION_RAM_HEADER) {
-        TRANSITION_RAM_HEADER
+fn header_len(data: &[u8]) -> Result<usize, CryptoError> {
+    if data.starts_with(RAM_HEADER) {
+        Ok(RAM_HEADER.len())
+    } else if data.starts_with(TRANSITION_RAM_HEADER) {
+        Ok(TRANSITION_RAM_HEADER.len())
     } else {
-        return Err(CryptoError::MissingHeader);
+        Err(CryptoError::MissingHeader)
+    }
+}
+
+fn parse_container_params(params: &[u8]) -> Result<KdfParams, CryptoError> {
+    if params[0] != CONTAINER_VERSION
+        || params[1] != KDF_ARGON2ID13
+        || params[CONTAINER_PARAMS_LEN - 1] != CIPHER_XSALSA20POLY1305
+    {
+        return Err(CryptoError::InvalidData);
+    }
+    let read_u64 = |at: usize| {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&params[at..at + 8]);
+        u64::from_le_bytes(bytes)
     };
+    let kdf = KdfParams {
+        ops_limit: read_u64(2),
+        mem_limit: read_u64(10),
+    };
+    if kdf.is_sane() {
+        Ok(kdf)
+    } else {
+        Err(CryptoError::InvalidData)
+    }
+}
 
-    if encrypted.len() < header.len() + 16 + 24 + 16 {
+/// Splits an encrypted file into its format, salt and sealed payload.
+fn split_container(encrypted: &[u8]) -> Result<(ContainerFormat, &[u8], &[u8]), CryptoError> {
+    let mut offset = header_len(encrypted)?;
+    let mut format = ContainerFormat::Legacy;
+
+    if encrypted[offset..].starts_with(CONTAINER_MAGIC) {
+        offset += CONTAINER_MAGIC.len();
+        let params = encrypted
+            .get(offset..offset + CONTAINER_PARAMS_LEN)
+            .ok_or(CryptoError::InvalidData)?;
+        format = ContainerFormat::Versioned(parse_container_params(params)?);
+        offset += CONTAINER_PARAMS_LEN;
+    }
+
+    if encrypted.len() < offset + SALT_LEN + secretbox::NONCEBYTES + secretbox::MACBYTES {
         return Err(CryptoError::InvalidData);
     }
+    let (salt, sealed) = encrypted[offset..].split_at(SALT_LEN);
+    Ok((format, salt, sealed))
+}
 
-    let offset = header.len();
-    let salt = &encrypted[offset..offset + 16];
-    let nonce_bytes = &encrypted[offset + 16..offset + 16 + 24];
-    let ciphertext = &encrypted[offset + 16 + 24..];
+/// Reports the container format of an encrypted file without decrypting it.
+pub fn inspect(encrypted: &[u8]) -> Result<ContainerFormat, CryptoError> {
+    split_container(encrypted).map(|(format, _, _)| format)
+}
 
-    let key = derive_key(password_hash, salt)?;
+pub fn decrypt(encrypted: &[u8], password_hash: &[u8]) -> Result<Vec<u8>, CryptoError> {
+    let (format, salt, sealed) = split_container(encrypted)?;
+    let (nonce_bytes, ciphertext) = sealed.split_at(secretbox::NONCEBYTES);
+
+    let key = match format {
+        ContainerFormat::Legacy => derive_key(password_hash, salt)?,
+        ContainerFormat::Versioned(params) => derive_key_argon2id(password_hash, salt, params)?,
+    };
 
     let nonce = secretbox::Nonce::from_slice(nonce_bytes).ok_or(CryptoError::InvalidData)?;
 
     secretbox::open(ciphertext, &nonce, &key).map_err(|_| CryptoError::DecryptionFailed)
 }
 
-pub fn encrypt(content: &str, password_hash: &[u8]) -> Result<Vec<u8>, CryptoError> {
+pub fn encrypt(
+    content: &str,
+    password_hash: &[u8],
+    format: ContainerFormat,
+) -> Result<Vec<u8>, CryptoError> {
     if content.is_empty() {
         return Err(CryptoError::InvalidData);
     }
 
-    let salt = argon2i13::gen_salt();
-    let key = derive_key(password_hash, salt.as_ref())?;
+    let salt = argon2id13::gen_salt();
+    let key = match format {
+        ContainerFormat::Legacy => derive_key(password_hash, salt.as_ref())?,
+        ContainerFormat::Versioned(params) => {
+            if !params.is_sane() {
+                return Err(CryptoError::InvalidData);
+            }
+            derive_key_argon2id(password_hash, salt.as_ref(), params)?
+        }
+    };
     let nonce = secretbox::gen_nonce();
     let ciphertext = secretbox::seal(content.as_bytes(), &nonce, &key);
 
-    let mut output = Vec::with_capacity(RAM_HEADER.len() + 16 + 24 + ciphertext.len());
+    let mut output = Vec::with_capacity(
+        RAM_HEADER.len()
+            + CONTAINER_MAGIC.len()
+            + CONTAINER_PARAMS_LEN
+            + SALT_LEN
+            + secretbox::NONCEBYTES
+            + ciphertext.len(),
+    );
     output.extend_from_slice(RAM_HEADER);
+    if let ContainerFormat::Versioned(params) = format {
+        output.extend_from_slice(CONTAINER_MAGIC);
+        output.push(CONTAINER_VERSION);
+        output.push(KDF_ARGON2ID13);
+        output.extend_from_slice(&params.ops_limit.to_le_bytes());
+        output.extend_from_slice(&params.mem_limit.to_le_bytes());
+        output.push(CIPHER_XSALSA20POLY1305);
+    }
     output.extend_from_slice(salt.as_ref());
     output.extend_from_slice(nonce.as_ref());
     output.extend_from_slice(&ciphertext);
@@ -96,6 +295,60 @@ pub fn encrypt(content: &str, password_hash: &[u8]) -> Result<Vec<u8>, CryptoErr
     Ok(output)
 }
 
+/// Seals individual values under one argon2id key, for files that keep
+/// everything but their secrets readable. Sealed values are `enc:` followed by
+/// the hex of nonce and ciphertext; empty values stay empty.
+pub struct FieldCipher {
+    key: secretbox::Key,
+}
+
+impl FieldCipher {
+    pub fn derive(
+        password_hash: &[u8],
+        salt: &[u8],
+        params: KdfParams,
+    ) -> Result<Self, CryptoError> {
+        if !params.is_sane() || salt.len() != SALT_LEN {
+            return Err(CryptoError::InvalidData);
+        }
+        Ok(Self {
+            key: derive_key_argon2id(password_hash, salt, params)?,
+        })
+    }
+
+    pub fn seal(&self, value: &str) -> String {
+        if value.is_empty() {
+            return String::new();
+        }
+        let nonce = secretbox::gen_nonce();
+        let mut sealed = nonce.as_ref().to_vec();
+        sealed.extend_from_slice(&secretbox::seal(value.as_bytes(), &nonce, &self.key));
+        format!("{}{}", FIELD_PREFIX, sodiumoxide::hex::encode(sealed))
+    }
+
+    pub fn open(&self, value: &str) -> Result<String, CryptoError> {
+        if value.is_empty() {
+            return Ok(String::new());
+        }
+        let encoded = value
+            .strip_prefix(FIELD_PREFIX)
+            .ok_or(CryptoError::InvalidData)?;
+        let sealed = sodiumoxide::hex::decode(encoded).map_err(|_| CryptoError::InvalidData)?;
+        if sealed.len() < secretbox::NONCEBYTES + secretbox::MACBYTES {
+            return Err(CryptoError::InvalidData);
+        }
+        let (nonce_bytes, ciphertext) = sealed.split_at(secretbox::NONCEBYTES);
+        let nonce = secretbox::Nonce::from_slice(nonce_bytes).ok_or(CryptoError::InvalidData)?;
+        let plain = secretbox::open(ciphertext, &nonce, &self.key)
+            .map_err(|_| CryptoError::DecryptionFailed)?;
+        String::from_utf8(plain).map_err(|_| CryptoError::InvalidData)
+    }
+}
+
+pub fn gen_field_salt() -> Vec<u8> {
+    argon2id13::gen_salt().as_ref().to_vec()
+}
+
 #[cfg(target_os = "windows")]
 pub fn try_decrypt_legacy_dpapi(data: &[u8]) -> Option<Vec<u8>> {
     use windows_sys::Win32::Foundation::LocalFree;
@@ -0,0 +1,84 @@
+/// Splits CSV text into records, each tagged with the line it starts on.
+/// Quoted fields may contain commas, doubled quotes and line breaks; blank
+/// lines are skipped.
+pub fn parse(text: &str) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut records = Vec::new();
+    let mut record: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut quoted = false;
+    let mut line = 1;
+    let mut start_line = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push(c);
+                }
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() && !quoted => {
+                in_quotes = true;
+                quoted = true;
+            }
+            '"' => return Err(format!("Line {}: unexpected quote", line)),
+            ',' => {
+                record.push(std::mem::take(&mut field));
+                quoted = false;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                quoted = false;
+                if !(record.len() == 1 && record[0].is_empty()) {
+                    records.push((start_line, std::mem::take(&mut record)));
+                }
+                record.clear();
+                line += 1;
+                start_line = line;
+            }
+            _ if quoted => return Err(format!("Line {}: text after closing quote", line)),
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(format!("Line {}: unterminated quote", start_line));
+    }
+    record.push(field);
+    if !(record.len() == 1 && record[0].is_empty()) {
+        records.push((start_line, record));
+    }
+    Ok(records)
+}
+
+/// Appends one CSV record, quoting fields only where needed.
+pub fn write_record<S: AsRef<str>>(out: &mut String, fields: &[S]) {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let field = field.as_ref();
+        if field.contains([',', '"', '\n', '\r']) || field.trim() != field {
+            out.push('"');
+            out.push_str(&field.replace('"', "\"\""));
+            out.push('"');
+        } else {
+            out.push_str(field);
+        }
+    }
+    out.push_str("\r\n");
+}
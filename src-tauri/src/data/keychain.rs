@@ -0,0 +1,422 @@
+use std::fs;
+use std::path::PathBuf;
+
+const SERVICE_NAME: &str = "Roblox Account Manager";
+
+/// Somewhere to keep the account file's unlock key between runs. Keys are
+/// stored hex-encoded under `SERVICE_NAME` and the given account name.
+pub trait SecretBackend: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn store(&self, account: &str, secret: &[u8]) -> Result<(), String>;
+    fn load(&self, account: &str) -> Result<Option<Vec<u8>>, String>;
+    fn delete(&self, account: &str) -> Result<(), String>;
+}
+
+fn decode_secret(encoded: &str) -> Result<Vec<u8>, String> {
+    sodiumoxide::hex::decode(encoded.trim())
+        .map_err(|_| "Stored unlock key is corrupted".to_string())
+}
+
+/// Keeps keys in plain files under `dir`. It offers no protection beyond file
+/// permissions and exists for tests and machines without a secret store.
+pub struct FileBackend {
+    dir: PathBuf,
+}
+
+impl FileBackend {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn path(&self, account: &str) -> PathBuf {
+        let digest = sodiumoxide::crypto::hash::sha256::hash(account.as_bytes());
+        self.dir.join(format!(
+            "UnlockKey-{}.key",
+            &sodiumoxide::hex::encode(digest)[..16]
+        ))
+    }
+}
+
+impl SecretBackend for FileBackend {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    fn store(&self, account: &str, secret: &[u8]) -> Result<(), String> {
+        let path = self.path(account);
+        fs::write(&path, sodiumoxide::hex::encode(secret))
+            .map_err(|e| format!("Failed to write unlock key: {}", e))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let _ = fs::set_permissions(&path, fs::Permissions::from_mode(0o600));
+        }
+        Ok(())
+    }
+
+    fn load(&self, account: &str) -> Result<Option<Vec<u8>>, String> {
+        match fs::read_to_string(self.path(account)) {
+            Ok(encoded) => decode_secret(&encoded).map(Some),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(format!("Failed to read unlock key: {}", e)),
+        }
+    }
+
+    fn delete(&self, account: &str) -> Result<(), String> {
+        match fs::remove_file(self.path(account)) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(format!("Failed to remove unlock key: {}", e)),
+        }
+    }
+}
+
+/// The platform secret store: Credential Manager on Windows, the login
+/// keychain on macOS and the Secret Service (through `secret-tool`) on Linux.
+pub struct OsBackend;
+
+#[cfg(target_os = "windows")]
+fn credential_target(account: &str) -> Vec<u16> {
+    format!("{}/{}", SERVICE_NAME, account)
+        .encode_utf16()
+        .chain(std::iter::once(0))
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+impl SecretBackend for OsBackend {
+    fn name(&self) -> &'static str {
+        "windows-credential-manager"
+    }
+
+    fn store(&self, account: &str, secret: &[u8]) -> Result<(), String> {
+        use windows_sys::Win32::Security::Credentials::{
+            CredWriteW, CREDENTIALW, CRED_PERSIST_LOCAL_MACHINE, CRED_TYPE_GENERIC,
+        };
+
+        let mut target = credential_target(account);
+        let mut blob = sodiumoxide::hex::encode(secret).into_bytes();
+        unsafe {
+            let mut credential: CREDENTIALW = std::mem::zeroed();
+            credential.Type = CRED_TYPE_GENERIC;
+            credential.TargetName = target.as_mut_ptr();
+            credential.CredentialBlobSize = blob.len() as u32;
+            credential.CredentialBlob = blob.as_mut_ptr();
+            credential.Persist = CRED_PERSIST_LOCAL_MACHINE;
+            if CredWriteW(&credential, 0) == 0 {
+                return Err(format!(
+                    "Failed to save unlock key to Credential Manager: {}",
+                    std::io::Error::last_os_error()
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn load(&self, account: &str) -> Result<Option<Vec<u8>>, String> {
+        use windows_sys::Win32::Foundation::ERROR_NOT_FOUND;
+        use windows_sys::Win32::Security::Credentials::{
+            CredFree, CredReadW, CREDENTIALW, CRED_TYPE_GENERIC,
+        };
+
+        let target = credential_target(account);
+        unsafe {
+            let mut credential: *mut CREDENTIALW = std::ptr::null_mut();
+            if CredReadW(target.as_ptr(), CRED_TYPE_GENERIC, 0, &mut credential) == 0 {
+                let err = std::io::Error::last_os_error();
+                if err.raw_os_error() == Some(ERROR_NOT_FOUND as i32) {
+                    return Ok(None);
+                }
+                return Err(format!(
+                    "Failed to read unlock key from Credential Manager: {}",
+                    err
+                ));
+            }
+            let blob = std::slice::from_raw_parts(
+                (*credential).CredentialBlob,
+                (*credential).CredentialBlobSize as usize,
+            )
+            .to_vec();
+            CredFree(credential as *const core::ffi::c_void);
+            decode_secret(&String::from_utf8_lossy(&blob)).map(Some)
+        }
+    }
+
+    fn delete(&self, account: &str) -> Result<(), String> {
+        use windows_sys::Win32::Foundation::ERROR_NOT_FOUND;
+        use windows_sys::Win32::Security::Credentials::{CredDeleteW, CRED_TYPE_GENERIC};
+
+        let target = credential_target(account);
+        if unsafe { CredDeleteW(target.as_ptr(), CRED_TYPE_GENERIC, 0) } == 0 {
+            let err = std::io::Error::last_os_error();
+            if err.raw_os_error() != Some(ERROR_NOT_FOUND as i32) {
+                return Err(format!(
+                    "Failed to remove unlock key from Credential Manager: {}",
+                    err
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos_keychain {
+    use std::os::raw::{c_char, c_void};
+
+    pub const ERR_SEC_ITEM_NOT_FOUND: i32 = -25300;
+
+    #[link(name = "Security", kind = "framework")]
+    unsafe extern "C" {
+        pub fn SecKeychainAddGenericPassword(
+            keychain: *const c_void,
+            service_name_length: u32,
+            service_name: *const c_char,
+            account_name_length: u32,
+            account_name: *const c_char,
+            password_length: u32,
+            password_data: *const c_void,
+            item_ref: *mut *mut c_void,
+        ) -> i32;
+        pub fn SecKeychainFindGenericPassword(
+            keychain_or_array: *const c_void,
+            service_name_length: u32,
+            service_name: *const c_char,
+            account_name_length: u32,
+            account_name: *const c_char,
+            password_length: *mut u32,
+            password_data: *mut *mut c_void,
+            item_ref: *mut *mut c_void,
+        ) -> i32;
+        pub fn SecKeychainItemModifyAttributesAndData(
+            item_ref: *mut c_void,
+            attr_list: *const c_void,
+            length: u32,
+            data: *const c_void,
+        ) -> i32;
+        pub fn SecKeychainItemDelete(item_ref: *mut c_void) -> i32;
+        pub fn SecKeychainItemFreeContent(attr_list: *const c_void, data: *mut c_void) -> i32;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    unsafe extern "C" {
+        pub fn CFRelease(cf: *const c_void);
+    }
+
+    /// Looks up the item, returning its reference and, when `with_data` is set,
+    /// its password bytes. A null reference means the item does not exist.
+    pub unsafe fn find(
+        service: &str,
+        account: &str,
+        with_data: bool,
+    ) -> Result<(*mut c_void, Option<Vec<u8>>), i32> {
+        let mut item: *mut c_void = std::ptr::null_mut();
+        let mut length: u32 = 0;
+        let mut data: *mut c_void = std::ptr::null_mut();
+        let status = SecKeychainFindGenericPassword(
+            std::ptr::null(),
+            service.len() as u32,
+            service.as_ptr() as *const c_char,
+            account.len() as u32,
+            account.as_ptr() as *const c_char,
+            if with_data {
+                &mut length
+            } else {
+                std::ptr::null_mut()
+            },
+            if with_data {
+                &mut data
+            } else {
+                std::ptr::null_mut()
+            },
+            &mut item,
+        );
+        match status {
+            0 => {
+                let bytes = (!data.is_null()).then(|| {
+                    let bytes =
+                        std::slice::from_raw_parts(data as *const u8, length as usize).to_vec();
+                    SecKeychainItemFreeContent(std::ptr::null(), data);
+                    bytes
+                });
+                Ok((item, bytes))
+            }
+            ERR_SEC_ITEM_NOT_FOUND => Ok((std::ptr::null_mut(), None)),
+            other => Err(other),
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+impl SecretBackend for OsBackend {
+    fn name(&self) -> &'static str {
+        "macos-keychain"
+    }
+
+    fn store(&self, account: &str, secret: &[u8]) -> Result<(), String> {
+        use macos_keychain::*;
+
+        let encoded = sodiumoxide::hex::encode(secret);
+        unsafe {
+            let (item, _) = find(SERVICE_NAME, account, false)
+                .map_err(|s| format!("Failed to access the keychain (status {})", s))?;
+            let status = if item.is_null() {
+                SecKeychainAddGenericPassword(
+                    std::ptr::null(),
+                    SERVICE_NAME.len() as u32,
+                    SERVICE_NAME.as_ptr() as *const _,
+                    account.len() as u32,
+                    account.as_ptr() as *const _,
+                    encoded.len() as u32,
+                    encoded.as_ptr() as *const _,
+                    std::ptr::null_mut(),
+                )
+            } else {
+                let status = SecKeychainItemModifyAttributesAndData(
+                    item,
+                    std::ptr::null(),
+                    encoded.len() as u32,
+                    encoded.as_ptr() as *const _,
+                );
+                CFRelease(item);
+                status
+            };
+            if status != 0 {
+                return Err(format!(
+                    "Failed to save unlock key to the keychain (status {})",
+                    status
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn load(&self, account: &str) -> Result<Option<Vec<u8>>, String> {
+        use macos_keychain::*;
+
+        unsafe {
+            let (item, data) = find(SERVICE_NAME, account, true).map_err(|s| {
+                format!("Failed to read unlock key from the keychain (status {})", s)
+            })?;
+            if !item.is_null() {
+                CFRelease(item);
+            }
+            data.map(|bytes| decode_secret(&String::from_utf8_lossy(&bytes)))
+                .transpose()
+        }
+    }
+
+    fn delete(&self, account: &str) -> Result<(), String> {
+        use macos_keychain::*;
+
+        unsafe {
+            let (item, _) = find(SERVICE_NAME, account, false)
+                .map_err(|s| format!("Failed to access the keychain (status {})", s))?;
+            if item.is_null() {
+                return Ok(());
+            }
+            let status = SecKeychainItemDelete(item);
+            CFRelease(item);
+            if status != 0 {
+                return Err(format!(
+                    "Failed to remove unlock key from the keychain (status {})",
+                    status
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn secret_tool(args: &[&str], input: Option<&str>) -> Result<std::process::Output, String> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let mut child = Command::new("secret-tool")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Secret Service is unavailable (secret-tool: {})", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        if let Some(input) = input {
+            stdin
+                .write_all(input.as_bytes())
+                .map_err(|e| format!("Failed to talk to secret-tool: {}", e))?;
+        }
+    }
+    child
+        .wait_with_output()
+        .map_err(|e| format!("Failed to talk to secret-tool: {}", e))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+impl SecretBackend for OsBackend {
+    fn name(&self) -> &'static str {
+        "secret-service"
+    }
+
+    fn store(&self, account: &str, secret: &[u8]) -> Result<(), String> {
+        let label = format!("{} unlock key", SERVICE_NAME);
+        let output = secret_tool(
+            &[
+                "store",
+                "--label",
+                &label,
+                "service",
+                SERVICE_NAME,
+                "account",
+                account,
+            ],
+            Some(&sodiumoxide::hex::encode(secret)),
+        )?;
+        if !output.status.success() {
+            return Err(format!(
+                "Failed to save unlock key to the Secret Service: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(())
+    }
+
+    fn load(&self, account: &str) -> Result<Option<Vec<u8>>, String> {
+        let output = secret_tool(
+            &["lookup", "service", SERVICE_NAME, "account", account],
+            None,
+        )?;
+        let encoded = String::from_utf8_lossy(&output.stdout);
+        if !output.status.success() || encoded.trim().is_empty() {
+            return Ok(None);
+        }
+        decode_secret(&encoded).map(Some)
+    }
+
+    fn delete(&self, account: &str) -> Result<(), String> {
+        secret_tool(
+            &["clear", "service", SERVICE_NAME, "account", account],
+            None,
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+impl SecretBackend for OsBackend {
+    fn name(&self) -> &'static str {
+        "unsupported"
+    }
+
+    fn store(&self, _account: &str, _secret: &[u8]) -> Result<(), String> {
+        Err("No secret store is available on this platform".into())
+    }
+
+    fn load(&self, _account: &str) -> Result<Option<Vec<u8>>, String> {
+        Ok(None)
+    }
+
+    fn delete(&self, _account: &str) -> Result<(), String> {
+        Ok(())
+    }
+}
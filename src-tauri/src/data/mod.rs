@@ -1,4 +1,9 @@
+pub mod account_query;
 pub mod accounts;
+pub mod atomic;
+pub mod balance_history;
 pub mod crypto;
+pub mod csv;
+pub mod keychain;
 pub mod scripts;
 pub mod settings;
@@ -3,6 +3,8 @@ use std::fs;
 use std::path::PathBuf;
 use std::sync::Mutex;
 
+use super::atomic::write_atomic;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 #[serde(rename_all = "camelCase")]
@@ -207,7 +209,7 @@ impl ScriptStore {
         let scripts = self.scripts.lock().map_err(|e| e.to_string())?;
         let bytes = serde_json::to_vec_pretty(&*scripts)
             .map_err(|e| format!("Failed to serialize scripts: {}", e))?;
-        fs::write(&self.file_path, bytes)
+        write_atomic(&self.file_path, &bytes)
             .map_err(|e| format!("Failed to write scripts file: {}", e))?;
         Ok(())
     }
@@ -284,6 +286,187 @@ impl ScriptStore {
     }
 }
 
+const MAX_LUA_SCRIPT_HISTORY: usize = 20;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LuaScriptVersion {
+    pub version: u32,
+    pub source: String,
+    pub updated_at_ms: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LuaScript {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub source: String,
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default)]
+    pub history: Vec<LuaScriptVersion>,
+    #[serde(default = "now_ms")]
+    pub created_at_ms: i64,
+    #[serde(default = "now_ms")]
+    pub updated_at_ms: i64,
+}
+
+pub struct LuaScriptLibrary {
+    scripts: Mutex<Vec<LuaScript>>,
+    file_path: PathBuf,
+}
+
+impl LuaScriptLibrary {
+    pub fn new(file_path: PathBuf) -> Self {
+        let library = Self {
+            scripts: Mutex::new(Vec::new()),
+            file_path,
+        };
+        let _ = library.load_from_disk();
+        library
+    }
+
+    fn load_from_disk(&self) -> Result<(), String> {
+        if !self.file_path.exists() {
+            return Ok(());
+        }
+
+        let metadata = fs::metadata(&self.file_path)
+            .map_err(|e| format!("Failed to read Lua library metadata: {}", e))?;
+        if metadata.len() > MAX_SCRIPTS_FILE_BYTES {
+            return Err(format!(
+                "Lua library file is too large (max {} bytes)",
+                MAX_SCRIPTS_FILE_BYTES
+            ));
+        }
+
+        let data = fs::read(&self.file_path)
+            .map_err(|e| format!("Failed to read Lua library file: {}", e))?;
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let parsed = serde_json::from_slice::<Vec<LuaScript>>(&data)
+            .map_err(|e| format!("Failed to parse Lua library file: {}", e))?;
+        let mut scripts = self.scripts.lock().map_err(|e| e.to_string())?;
+        *scripts = parsed;
+        Ok(())
+    }
+
+    fn save_to_disk(&self) -> Result<(), String> {
+        let scripts = self.scripts.lock().map_err(|e| e.to_string())?;
+        let bytes = serde_json::to_vec_pretty(&*scripts)
+            .map_err(|e| format!("Failed to serialize Lua library: {}", e))?;
+        write_atomic(&self.file_path, &bytes)
+            .map_err(|e| format!("Failed to write Lua library file: {}", e))?;
+        Ok(())
+    }
+
+    pub fn get_all(&self) -> Result<Vec<LuaScript>, String> {
+        let mut scripts = self.scripts.lock().map_err(|e| e.to_string())?.clone();
+        scripts.sort_by_key(|s| s.name.to_lowercase());
+        Ok(scripts)
+    }
+
+    pub fn get(&self, script_id: &str) -> Option<LuaScript> {
+        self.scripts
+            .lock()
+            .ok()?
+            .iter()
+            .find(|s| s.id == script_id)
+            .cloned()
+    }
+
+    pub fn upsert(
+        &self,
+        id: &str,
+        name: &str,
+        description: &str,
+        source: &str,
+    ) -> Result<LuaScript, String> {
+        let id = id.trim();
+        validate_script_id(id)?;
+        let name = name.trim();
+        validate_script_name(name)?;
+        let description = description.trim();
+        validate_script_description(description)?;
+        validate_script_source(source)?;
+
+        let mut scripts = self.scripts.lock().map_err(|e| e.to_string())?;
+        let now = now_ms();
+
+        if let Some(existing) = scripts.iter_mut().find(|s| s.id == id) {
+            if existing.source != source {
+                existing.history.push(LuaScriptVersion {
+                    version: existing.version,
+                    source: std::mem::take(&mut existing.source),
+                    updated_at_ms: existing.updated_at_ms,
+                });
+                if existing.history.len() > MAX_LUA_SCRIPT_HISTORY {
+                    let excess = existing.history.len() - MAX_LUA_SCRIPT_HISTORY;
+                    existing.history.drain(..excess);
+                }
+                existing.source = source.to_string();
+                existing.version += 1;
+            }
+            existing.name = name.to_string();
+            existing.description = description.to_string();
+            existing.updated_at_ms = now;
+            let out = existing.clone();
+            drop(scripts);
+            self.save_to_disk()?;
+            return Ok(out);
+        }
+
+        if scripts.len() >= MAX_SCRIPT_COUNT {
+            return Err(format!("Script limit reached (max {})", MAX_SCRIPT_COUNT));
+        }
+
+        let script = LuaScript {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: description.to_string(),
+            source: source.to_string(),
+            version: 1,
+            history: Vec::new(),
+            created_at_ms: now,
+            updated_at_ms: now,
+        };
+        scripts.push(script.clone());
+        drop(scripts);
+        self.save_to_disk()?;
+        Ok(script)
+    }
+
+    pub fn remove(&self, script_id: &str) -> Result<bool, String> {
+        let mut scripts = self.scripts.lock().map_err(|e| e.to_string())?;
+        let before = scripts.len();
+        scripts.retain(|s| s.id != script_id.trim());
+        let removed = scripts.len() < before;
+        drop(scripts);
+        if removed {
+            self.save_to_disk()?;
+        }
+        Ok(removed)
+    }
+}
+
+pub fn render_lua_script(source: &str, params: &[(&str, &str)]) -> String {
+    let mut out = source.to_string();
+    for (key, value) in params {
+        out = out.replace(&format!("{{{{{}}}}}", key), value);
+    }
+    out
+}
+
+pub fn get_lua_scripts_path() -> PathBuf {
+    get_scripts_path().with_file_name("NexusScripts.json")
+}
+
 pub fn get_scripts_path() -> PathBuf {
     std::env::current_exe()
         .ok()
@@ -312,3 +495,29 @@ pub fn delete_script(
 ) -> Result<bool, String> {
     state.remove(&script_id)
 }
+
+#[tauri::command]
+pub fn get_lua_scripts(
+    state: tauri::State<'_, LuaScriptLibrary>,
+) -> Result<Vec<LuaScript>, String> {
+    state.get_all()
+}
+
+#[tauri::command]
+pub fn save_lua_script(
+    state: tauri::State<'_, LuaScriptLibrary>,
+    id: String,
+    name: String,
+    description: String,
+    source: String,
+) -> Result<LuaScript, String> {
+    state.upsert(&id, &name, &description, &source)
+}
+
+#[tauri::command]
+pub fn delete_lua_script(
+    state: tauri::State<'_, LuaScriptLibrary>,
+    script_id: String,
+) -> Result<bool, String> {
+    state.remove(&script_id)
+}
@@ -183,7 +183,8 @@ impl IniFile {
             output.push('\n');
         }
 
-        fs::write(path, output).map_err(|e| format!("Failed to save INI file: {}", e))
+        crate::data::atomic::write_atomic(path, output.as_bytes())
+            .map_err(|e| format!("Failed to save INI file: {}", e))
     }
 
     pub fn to_map(&self) -> HashMap<String, HashMap<String, String>> {
@@ -49,7 +49,7 @@ impl ThemePresetStore {
     fn save_all(&self, presets: &[ThemePresetData]) -> Result<(), String> {
         let payload = serde_json::to_string_pretty(presets)
             .map_err(|e| format!("Failed to serialize presets: {}", e))?;
-        fs::write(&self.file_path, payload).map_err(|e| {
+        crate::data::atomic::write_atomic(&self.file_path, payload.as_bytes()).map_err(|e| {
             format!(
                 "Failed to write preset file {}: {}",
                 self.file_path.display(),
@@ -45,6 +45,7 @@ impl SettingsStore {
             ("ShowPresence", "true", None),
             ("PresenceUpdateRate", "5", None),
             ("WarnOnOnlineJoin", "true", None),
+            ("OnlineJoinPolicy", "warn", None),
             ("UnlockFPS", "false", None),
             ("MaxFPSValue", "120", None),
             ("CustomClientSettings", "", None),
@@ -108,6 +109,34 @@ impl SettingsStore {
         if !general.exists("EncryptionMethod") {
             general.set("EncryptionMethod", "default", None);
         }
+        if !general.exists("EncryptionKdf") {
+            general.set("EncryptionKdf", "moderate", None);
+        }
+        if !general.exists("EncryptionScope") {
+            general.set("EncryptionScope", "file", None);
+        }
+        if !general.exists("AutoLockMinutes") {
+            general.set("AutoLockMinutes", "0", None);
+            general.set("LockOnSessionLock", "false", None);
+            general.set("AutoLockBottingPolicy", "defer", None);
+        }
+        if !general.exists("TrackBalances") {
+            general.set("TrackBalances", "false", None);
+            general.set("BalanceTrackInterval", "360", None);
+            general.set("BalanceTrackPremium", "false", None);
+            general.set("BalanceTrackLimiteds", "false", None);
+            general.set("BalanceHistoryDays", "90", None);
+            general.set("BalanceDropMinRobux", "500", None);
+            general.set("BalanceDropMinPercent", "0", None);
+            general.set("BalanceDropMinLimiteds", "1", None);
+        }
+        if !general.exists("AccountBackups") {
+            general.set("AccountBackups", "5", None);
+        }
+        if !general.exists("RememberUnlock") {
+            general.set("RememberUnlock", "false", None);
+            general.set("UnlockKeyBackend", "os", None);
+        }
         if !general.exists("ThemeWindowsNavbarAutoEnabledV1") {
             general.set("ThemeWindowsNavbar", "true", None);
             general.set("ThemeWindowsNavbarAutoEnabledV1", "true", None);
@@ -155,8 +184,19 @@ impl SettingsStore {
             ("AllowGetAccounts", "false"),
             ("AllowLaunchAccount", "false"),
             ("AllowAccountEditing", "false"),
+            ("AllowAccountManagement", "false"),
             ("EveryRequestRequiresPassword", "false"),
             ("AllowExternalConnections", "false"),
+            ("RateLimitEnabled", "true"),
+            ("RateLimitWindow", "60"),
+            ("RateLimitPerRoute", "120"),
+            ("RateLimitPerToken", "300"),
+            (
+                "RouteRateLimits",
+                "LaunchAccount:10,GetCSRFToken:30,FollowUser:10,UnblockEveryone:2,BatchLaunchAccount:2",
+            ),
+            ("OutboundBudget", "120"),
+            ("BatchConcurrency", "4"),
         ];
 
         let webserver = ini.section("WebServer");
@@ -180,6 +220,14 @@ impl SettingsStore {
             ("AutoCloseInterval", "5"),
             ("MaxInstances", "3"),
             ("AutoCloseType", "0"),
+            ("RequireAuth", "false"),
+            ("NexusKey", ""),
+            ("HeartbeatTimeout", "30"),
+            ("KillStaleClients", "false"),
+            ("PersistElements", "false"),
+            ("PersistData", "false"),
+            ("LogCapacity", "1000"),
+            ("LogMaxFileSize", "0"),
         ];
 
         let account_control = ini.section("AccountControl");
@@ -10,7 +10,7 @@ mod platform;
 use api::batch::ImageCache;
 use data::accounts::{get_account_data_path, AccountStore};
 use data::crypto;
-use data::scripts::{get_scripts_path, ScriptStore};
+use data::scripts::{get_lua_scripts_path, get_scripts_path, LuaScriptLibrary, ScriptStore};
 use data::settings::{
     get_settings_path, get_theme_path, get_theme_presets_path, SettingsStore, ThemePresetStore,
     ThemeStore,
@@ -28,11 +28,17 @@ use tauri_plugin_autostart::MacosLauncher;
 include!("commands/account_api.rs");
 include!("commands/image_cache.rs");
 include!("commands/account_helpers.rs");
+include!("commands/account_bundle.rs");
+include!("commands/account_import.rs");
 include!("commands/launch_shared.rs");
 include!("commands/botting.rs");
 include!("commands/launch.rs");
 include!("commands/watcher.rs");
+include!("commands/presence.rs");
+include!("commands/autolock.rs");
+include!("commands/balance_tracker.rs");
 include!("commands/services.rs");
+include!("commands/nexus_supervisor.rs");
 include!("commands/updater.rs");
 
 #[cfg(target_os = "windows")]
@@ -79,9 +85,36 @@ pub fn run() {
     crypto::init();
 
     let account_store = AccountStore::new(get_account_data_path());
+    let settings_store = SettingsStore::new(get_settings_path());
+
+    let backup_limit = settings_store
+        .get_int("General", "AccountBackups")
+        .map(|n| n.max(0) as usize)
+        .unwrap_or(data::accounts::DEFAULT_BACKUP_LIMIT);
+    let _ = account_store.set_backup_limit(backup_limit);
+    match account_store.detect_damage() {
+        Ok(Some(reason)) => eprintln!("Warning: Account file is damaged: {}", reason),
+        Ok(None) => {}
+        Err(e) => eprintln!("Warning: Failed to check account file: {}", e),
+    }
 
     match account_store.needs_password() {
-        Ok(true) => eprintln!("Encrypted account file detected, password required"),
+        Ok(true) => {
+            let remembered = settings_store.get_bool("General", "RememberUnlock")
+                && match account_store.unlock_with_remembered_key(
+                    data::accounts::unlock_key_backend(&account_store, &settings_store).as_ref(),
+                ) {
+                    Ok(unlocked) => unlocked,
+                    Err(e) => {
+                        eprintln!("Warning: Remembered unlock key was rejected: {}", e);
+                        false
+                    }
+                };
+            if !remembered {
+                eprintln!("Encrypted account file detected, password required");
+                let _ = account_store.lock();
+            }
+        }
         Ok(false) => {
             if let Err(e) = account_store.load() {
                 eprintln!("Warning: Failed to load accounts: {}", e);
@@ -90,10 +123,10 @@ pub fn run() {
         Err(e) => eprintln!("Warning: Failed to check encryption: {}", e),
     }
 
-    let settings_store = SettingsStore::new(get_settings_path());
     let theme_store = ThemeStore::new(get_theme_path());
     let theme_preset_store = ThemePresetStore::new(get_theme_presets_path());
     let script_store = ScriptStore::new(get_scripts_path());
+    let lua_library = LuaScriptLibrary::new(get_lua_scripts_path());
     let image_cache = ImageCache::new();
 
     tauri::Builder::default()
@@ -116,6 +149,7 @@ pub fn run() {
         .manage(theme_store)
         .manage(theme_preset_store)
         .manage(script_store)
+        .manage(lua_library)
         .manage(image_cache)
         .manage(UpdaterRuntimeState::default())
         .setup(|app| {
@@ -159,6 +193,10 @@ pub fn run() {
                 })
                 .build(app)?;
 
+            spawn_presence_monitor(app.handle().clone());
+            spawn_auto_lock(app.handle().clone());
+            spawn_balance_tracker(app.handle().clone());
+
             #[cfg(any(feature = "nexus", feature = "webserver"))]
             let settings = app.state::<SettingsStore>();
             #[cfg(feature = "nexus")]
@@ -169,12 +207,34 @@ pub fn run() {
                     .unwrap_or(5242) as u16;
                 let allow_external =
                     settings.get_bool("AccountControl", "AllowExternalConnections");
+                nexus::websocket::nexus().configure_auth(
+                    settings.get_bool("AccountControl", "RequireAuth"),
+                    &settings.get_string("AccountControl", "NexusKey"),
+                );
+                nexus::websocket::nexus().set_element_persistence(
+                    settings.get_bool("AccountControl", "PersistElements"),
+                );
+                nexus::websocket::nexus()
+                    .set_data_persistence(settings.get_bool("AccountControl", "PersistData"));
+                nexus::websocket::nexus().configure_log(
+                    settings
+                        .get_int("AccountControl", "LogCapacity")
+                        .unwrap_or(1000)
+                        .max(0) as usize,
+                    settings
+                        .get_int("AccountControl", "LogMaxFileSize")
+                        .unwrap_or(0)
+                        .max(0) as u64,
+                );
                 tauri::async_runtime::spawn(async move {
                     match nexus::websocket::nexus()
-                        .start(port, allow_external, handle)
+                        .start(port, allow_external, handle.clone())
                         .await
                     {
-                        Ok(port) => eprintln!("Nexus server started on port {}", port),
+                        Ok(port) => {
+                            eprintln!("Nexus server started on port {}", port);
+                            spawn_nexus_supervisors(handle);
+                        }
                         Err(e) => eprintln!("Failed to start Nexus server: {}", e),
                     }
                 });
@@ -208,11 +268,36 @@ pub fn run() {
             data::accounts::is_accounts_encrypted,
             data::accounts::needs_password,
             data::accounts::set_encryption_password,
+            data::accounts::get_account_encryption_info,
+            data::accounts::rekey_account_file,
+            data::accounts::set_account_encryption_scope,
+            data::accounts::get_account_file_recovery,
+            data::accounts::list_vaults,
+            data::accounts::create_vault,
+            data::accounts::open_vault,
+            data::accounts::close_vault,
+            data::accounts::delete_vault,
+            data::accounts::move_accounts_to_vault,
+            data::accounts::restore_account_backup,
+            data::accounts::get_remember_unlock,
+            data::accounts::set_remember_unlock,
             data::accounts::reorder_accounts,
             data::accounts::import_old_account_data,
+            export_accounts,
+            import_accounts,
+            import_accounts_text,
+            data::accounts::export_accounts_csv,
+            data::accounts::query_accounts,
+            data::accounts::list_smart_groups,
+            data::accounts::save_smart_group,
+            data::accounts::delete_smart_group,
+            data::accounts::edit_account_tags,
             data::scripts::get_scripts,
             data::scripts::save_script,
             data::scripts::delete_script,
+            data::scripts::get_lua_scripts,
+            data::scripts::save_lua_script,
+            data::scripts::delete_lua_script,
             data::settings::get_all_settings,
             data::settings::get_setting,
             data::settings::update_setting,
@@ -258,6 +343,17 @@ pub fn run() {
             parse_private_server_link_code,
             join_group,
             get_presence,
+            get_cached_presence,
+            get_presence_history,
+            set_followed_presence,
+            refresh_presence,
+            report_activity,
+            lock_accounts,
+            snapshot_balances,
+            refresh_balance_tracker,
+            get_balance_history,
+            get_balance_totals,
+            get_balance_drops,
             batch_thumbnails,
             get_avatar_headshots,
             get_asset_thumbnails,
@@ -309,11 +405,25 @@ pub fn run() {
             update_nexus_account,
             nexus_send_command,
             nexus_send_to_all,
+            nexus_send_targeted,
+            get_nexus_broadcast_groups,
+            set_nexus_broadcast_group,
+            delete_nexus_broadcast_group,
+            push_nexus_script,
             get_nexus_log,
+            export_nexus_log,
             clear_nexus_log,
             get_nexus_elements,
             set_nexus_element_value,
+            click_nexus_element,
+            remove_nexus_element,
+            clear_nexus_elements,
+            get_nexus_data,
+            set_nexus_data,
+            delete_nexus_data,
             export_nexus_lua,
+            rotate_nexus_secret,
+            set_nexus_require_auth,
             open_repo_url,
             sync_windows_navbar_theme,
         ])
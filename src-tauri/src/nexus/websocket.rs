@@ -1,15 +1,25 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{LazyLock, Mutex};
 use std::time::{Duration, Instant};
 
 use futures_util::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
-use tauri::Emitter;
+use tauri::{Emitter, Manager};
 use tokio::net::TcpListener;
 use tokio::sync::mpsc;
 
 include!("websocket/core.rs");
 include!("websocket/server_impl.rs");
+include!("websocket/auth.rs");
+include!("websocket/protocol.rs");
+include!("websocket/relaunch.rs");
+include!("websocket/heartbeat.rs");
+include!("websocket/log.rs");
+include!("websocket/elements.rs");
+include!("websocket/data.rs");
+include!("websocket/targeting.rs");
+include!("websocket/autoexec.rs");
 include!("websocket/connection.rs");
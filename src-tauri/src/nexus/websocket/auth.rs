@@ -0,0 +1,125 @@
+const NEXUS_AUTH_WINDOW_SECS: u64 = 120;
+const NEXUS_NONCE_MIN_LEN: usize = 16;
+
+#[derive(Debug, Clone, Default)]
+struct NexusAuthConfig {
+    required: bool,
+    shared_key: String,
+}
+
+pub fn generate_nexus_key() -> String {
+    let bytes = sodiumoxide::randombytes::randombytes(32);
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        out.push_str(&format!("{:02x}", b));
+    }
+    out
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    if !value.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(value.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+fn verify_nexus_signature(secret: &str, message: &str, signature: &str) -> bool {
+    use sodiumoxide::crypto::auth::hmacsha256;
+    use sodiumoxide::crypto::hash::sha256;
+
+    let Some(tag) = decode_hex(signature).and_then(|b| hmacsha256::Tag::from_slice(&b)) else {
+        return false;
+    };
+    let key = hmacsha256::Key(sha256::hash(secret.as_bytes()).0);
+    hmacsha256::verify(&tag, message.as_bytes(), &key)
+}
+
+fn unix_now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+impl NexusServer {
+    pub fn configure_auth(&self, required: bool, shared_key: &str) {
+        let mut auth = self.auth.lock().unwrap();
+        auth.required = required;
+        auth.shared_key = shared_key.trim().to_string();
+    }
+
+    pub fn rotate_secret(&self, username: &str) -> Result<String, String> {
+        let secret = generate_nexus_key();
+        {
+            let mut accounts = self.accounts.lock().unwrap();
+            let account = accounts
+                .iter_mut()
+                .find(|a| a.username == username)
+                .ok_or_else(|| format!("{} is not in the control list", username))?;
+            account.secret = secret.clone();
+        }
+        self.save_accounts();
+
+        // Dropping the sender ends the socket loop, forcing the client to re-authenticate.
+        self.connections.lock().unwrap().remove(username);
+
+        Ok(secret)
+    }
+
+    fn verify_handshake(
+        &self,
+        name: &str,
+        id: &str,
+        job_id: &str,
+        params: &HashMap<String, String>,
+    ) -> Result<(), &'static str> {
+        let auth = self.auth.lock().unwrap().clone();
+        if !auth.required {
+            return Ok(());
+        }
+
+        let secret = {
+            let accounts = self.accounts.lock().unwrap();
+            accounts
+                .iter()
+                .find(|a| a.username == name)
+                .map(|a| a.secret.clone())
+                .filter(|s| !s.is_empty())
+                .unwrap_or(auth.shared_key)
+        };
+        if secret.is_empty() {
+            return Err("no secret configured");
+        }
+
+        let nonce = params.get("nonce").map(|s| s.as_str()).unwrap_or("");
+        let ts_raw = params.get("ts").map(|s| s.as_str()).unwrap_or("");
+        let signature = params.get("sig").map(|s| s.as_str()).unwrap_or("");
+
+        if nonce.len() < NEXUS_NONCE_MIN_LEN {
+            return Err("missing nonce");
+        }
+
+        let ts: u64 = ts_raw.parse().map_err(|_| "invalid timestamp")?;
+        if unix_now_secs().abs_diff(ts) > NEXUS_AUTH_WINDOW_SECS {
+            return Err("stale timestamp");
+        }
+
+        let message = format!("{}:{}:{}:{}:{}", name, id, job_id, nonce, ts_raw);
+        if !verify_nexus_signature(&secret, &message, signature) {
+            return Err("invalid signature");
+        }
+
+        let mut nonces = self.seen_nonces.lock().unwrap();
+        let window = Duration::from_secs(NEXUS_AUTH_WINDOW_SECS * 2);
+        nonces.retain(|_, seen| seen.elapsed() < window);
+        if nonces.contains_key(nonce) {
+            return Err("nonce reused");
+        }
+        nonces.insert(nonce.to_string(), Instant::now());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+impl NexusServer {
+    fn render_for_account(
+        account: &ControlledAccount,
+        entry: &AutoExecuteEntry,
+        script: &crate::data::scripts::LuaScript,
+    ) -> String {
+        let user_id = account.in_game_user_id.to_string();
+        let place_id = if account.in_game_place_id > 0 {
+            account.in_game_place_id
+        } else {
+            account.place_id
+        }
+        .to_string();
+        let job_id = if account.in_game_job_id.is_empty() {
+            account.job_id.as_str()
+        } else {
+            account.in_game_job_id.as_str()
+        };
+
+        let builtins = ["username", "userId", "placeId", "jobId"];
+        let mut params: Vec<(&str, &str)> = vec![
+            ("username", account.username.as_str()),
+            ("userId", user_id.as_str()),
+            ("placeId", place_id.as_str()),
+            ("jobId", job_id),
+        ];
+        params.extend(
+            entry
+                .params
+                .iter()
+                .filter(|(k, _)| !builtins.contains(&k.as_str()))
+                .map(|(k, v)| (k.as_str(), v.as_str())),
+        );
+        crate::data::scripts::render_lua_script(&script.source, &params)
+    }
+
+    pub fn auto_execute_messages(
+        &self,
+        username: &str,
+        library: &crate::data::scripts::LuaScriptLibrary,
+    ) -> Vec<String> {
+        let accounts = self.accounts.lock().unwrap();
+        let Some(account) = accounts.iter().find(|a| a.username == username) else {
+            return Vec::new();
+        };
+
+        let mut messages = Vec::new();
+        if !account.auto_execute.is_empty() {
+            messages.push(format!("execute {}", account.auto_execute));
+        }
+        for entry in &account.auto_execute_scripts {
+            let Some(script) = library.get(&entry.script_id) else {
+                continue;
+            };
+            if script.source.is_empty() {
+                continue;
+            }
+            messages.push(format!(
+                "execute {}",
+                Self::render_for_account(account, entry, &script)
+            ));
+        }
+        messages
+    }
+
+    pub fn push_script(&self, script: &crate::data::scripts::LuaScript) -> usize {
+        let rendered: Vec<(String, String)> = {
+            let accounts = self.accounts.lock().unwrap();
+            accounts
+                .iter()
+                .filter(|a| a.status == AccountStatus::Online)
+                .flat_map(|a| {
+                    a.auto_execute_scripts
+                        .iter()
+                        .filter(|e| e.script_id == script.id)
+                        .map(move |e| {
+                            (
+                                a.username.clone(),
+                                format!("execute {}", Self::render_for_account(a, e, script)),
+                            )
+                        })
+                })
+                .collect()
+        };
+
+        let conns = self.connections.lock().unwrap();
+        let mut sent = 0;
+        for (username, message) in &rendered {
+            if let Some(conn) = conns.get(username) {
+                conn.send(message);
+                sent += 1;
+            }
+        }
+        sent
+    }
+}
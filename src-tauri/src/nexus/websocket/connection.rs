@@ -1,3 +1,5 @@
+const NEXUS_SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
 async fn handle_connection(
     stream: tokio::net::TcpStream,
     app: tauri::AppHandle,
@@ -40,6 +42,11 @@ async fn handle_connection(
         .cloned()
         .unwrap_or_else(|| "UNKNOWN".to_string());
 
+    let place_id = params
+        .get("placeId")
+        .and_then(|p| p.parse::<i64>().ok())
+        .unwrap_or(0);
+
     let server = nexus();
 
     {
@@ -49,25 +56,45 @@ async fn handle_connection(
         }
     }
 
+    if let Err(reason) = server.verify_handshake(&name, &id_str, &job_id, &params) {
+        server.log(
+            NexusLogLevel::Warn,
+            Some(&name),
+            &format!("Rejected connection for {}: {}", name, reason),
+            None,
+            Some(&app),
+        );
+        return;
+    }
+
     {
         let mut accounts = server.accounts.lock().unwrap();
         if let Some(account) = accounts.iter_mut().find(|a| a.username == name) {
             account.status = AccountStatus::Online;
             account.last_ping = Some(Instant::now());
             account.in_game_job_id = job_id;
+            account.in_game_place_id = place_id;
+            account.in_game_user_id = id_str.parse().unwrap_or(0);
             account.client_can_receive = false;
+            account.disconnected_at = None;
+            account.latency_ms = None;
         }
     }
 
     let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+    let connection_id = server.next_connection_id.fetch_add(1, Ordering::SeqCst);
 
+    server.reset_element_positions(&name);
     {
         let mut conns = server.connections.lock().unwrap();
         conns.insert(
             name.clone(),
             NexusConnection {
+                id: connection_id,
                 sender: tx,
                 username: name.clone(),
+                protocol: 1,
+                capabilities: Vec::new(),
             },
         );
     }
@@ -77,41 +104,38 @@ async fn handle_connection(
         serde_json::json!({ "username": &name }),
     );
 
-    let auto_exec = {
-        let accounts = server.accounts.lock().unwrap();
-        accounts
-            .iter()
-            .find(|a| a.username == name)
-            .map(|a| a.auto_execute.clone())
-            .unwrap_or_default()
-    };
+    let auto_exec_msgs = app
+        .try_state::<crate::data::scripts::LuaScriptLibrary>()
+        .map(|library| server.auto_execute_messages(&name, &library))
+        .unwrap_or_default();
 
-    if !auto_exec.is_empty() {
-        let auto_exec_msg = format!("execute {}", auto_exec);
+    if !auto_exec_msgs.is_empty() {
         let name_clone = name.clone();
-        let tx_clone = {
-            let conns = server.connections.lock().unwrap();
-            conns.get(&name_clone).map(|c| c.sender.clone())
-        };
-        if let Some(sender) = tx_clone {
-            tokio::spawn(async move {
-                loop {
-                    let ready = {
-                        let accounts = nexus().accounts.lock().unwrap();
-                        accounts
-                            .iter()
-                            .find(|a| a.username == name_clone)
-                            .map(|a| a.client_can_receive)
-                            .unwrap_or(false)
-                    };
-                    if ready {
-                        let _ = sender.send(auto_exec_msg);
-                        break;
+        tokio::spawn(async move {
+            loop {
+                let ready = {
+                    let accounts = nexus().accounts.lock().unwrap();
+                    accounts
+                        .iter()
+                        .find(|a| a.username == name_clone)
+                        .map(|a| a.client_can_receive)
+                        .unwrap_or(false)
+                };
+                if ready {
+                    let conns = nexus().connections.lock().unwrap();
+                    if let Some(conn) = conns.get(&name_clone) {
+                        for message in &auto_exec_msgs {
+                            conn.send(message);
+                        }
                     }
-                    tokio::time::sleep(Duration::from_millis(80)).await;
+                    break;
                 }
-            });
-        }
+                if !nexus().connections.lock().unwrap().contains_key(&name_clone) {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(80)).await;
+            }
+        });
     }
 
     let (mut ws_write, mut ws_read) = ws_stream.split();
@@ -134,11 +158,19 @@ async fn handle_connection(
             outgoing = rx.recv() => {
                 match outgoing {
                     Some(msg) => {
-                        if ws_write.send(tokio_tungstenite::tungstenite::Message::Text(msg.into())).await.is_err() {
+                        let sent = tokio::time::timeout(
+                            NEXUS_SEND_TIMEOUT,
+                            ws_write.send(tokio_tungstenite::tungstenite::Message::Text(msg.into())),
+                        )
+                        .await;
+                        if !matches!(sent, Ok(Ok(()))) {
                             break;
                         }
                     }
-                    None => break,
+                    None => {
+                        let _ = tokio::time::timeout(NEXUS_SEND_TIMEOUT, ws_write.close()).await;
+                        break;
+                    }
                 }
             }
             _ = shutdown.changed() => {
@@ -151,14 +183,23 @@ async fn handle_connection(
 
     {
         let mut conns = server.connections.lock().unwrap();
+        // A reconnect may already have replaced this socket; leave the new one alone.
+        if conns.get(&name).is_some_and(|c| c.id != connection_id) {
+            return;
+        }
         conns.remove(&name);
     }
+    server.clear_data_watches(&name);
 
     {
         let mut accounts = server.accounts.lock().unwrap();
         if let Some(account) = accounts.iter_mut().find(|a| a.username == name) {
             account.status = AccountStatus::Offline;
             account.client_can_receive = false;
+            account.latency_ms = None;
+            if server.is_running() {
+                account.disconnected_at = Some(Instant::now());
+            }
         }
     }
 
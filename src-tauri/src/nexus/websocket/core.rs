@@ -8,8 +8,20 @@ pub struct NexusServer {
     connections: Mutex<HashMap<String, NexusConnection>>,
     accounts: Mutex<Vec<ControlledAccount>>,
     custom_elements: Mutex<Vec<CustomElement>>,
-    log_messages: Mutex<Vec<String>>,
+    element_positions: Mutex<HashMap<(String, String), usize>>,
+    log_messages: Mutex<NexusLog>,
     server_handle: Mutex<Option<ServerHandle>>,
+    auth: Mutex<NexusAuthConfig>,
+    seen_nonces: Mutex<HashMap<String, Instant>>,
+    relaunch_supervisor: AtomicBool,
+    heartbeat_monitor: AtomicBool,
+    instance_controller: AtomicBool,
+    internet_available: AtomicBool,
+    persist_elements: AtomicBool,
+    shared_data: Mutex<BTreeMap<String, BTreeMap<String, serde_json::Value>>>,
+    data_watchers: Mutex<HashMap<String, Vec<DataWatch>>>,
+    persist_data: AtomicBool,
+    next_connection_id: AtomicU64,
 }
 
 struct ServerHandle {
@@ -18,9 +30,23 @@ struct ServerHandle {
 }
 
 struct NexusConnection {
+    id: u64,
     sender: mpsc::UnboundedSender<String>,
     #[allow(dead_code)]
     username: String,
+    protocol: u32,
+    capabilities: Vec<String>,
+}
+
+impl NexusConnection {
+    fn send(&self, message: &str) {
+        let encoded = if self.protocol >= 2 {
+            ServerFrame::new("command", None, serde_json::json!({ "message": message })).encode()
+        } else {
+            message.to_string()
+        };
+        let _ = self.sender.send(encoded);
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,16 +55,29 @@ pub struct ControlledAccount {
     pub username: String,
     #[serde(rename = "AutoExecute", default)]
     pub auto_execute: String,
+    #[serde(rename = "AutoExecuteScripts", default, skip_serializing_if = "Vec::is_empty")]
+    pub auto_execute_scripts: Vec<AutoExecuteEntry>,
     #[serde(rename = "PlaceId", default)]
     pub place_id: i64,
     #[serde(rename = "JobId", default)]
     pub job_id: String,
-    #[serde(rename = "RelaunchDelay", default = "default_relaunch_delay")]
-    pub relaunch_delay: f64,
+    /// Seconds to wait before relaunching; `None` uses the global
+    /// `[AccountControl] RelaunchDelay`.
+    #[serde(
+        rename = "RelaunchDelay",
+        default,
+        deserialize_with = "deserialize_relaunch_delay",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub relaunch_delay: Option<f64>,
     #[serde(rename = "AutoRelaunch", default)]
     pub auto_relaunch: bool,
     #[serde(rename = "IsChecked", default)]
     pub is_checked: bool,
+    #[serde(rename = "Secret", default, skip_serializing_if = "String::is_empty")]
+    pub secret: String,
+    #[serde(rename = "BroadcastGroups", default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<String>,
     #[serde(skip)]
     pub status: AccountStatus,
     #[serde(skip)]
@@ -46,11 +85,33 @@ pub struct ControlledAccount {
     #[serde(skip)]
     pub in_game_job_id: String,
     #[serde(skip)]
+    pub in_game_place_id: i64,
+    #[serde(skip)]
+    pub in_game_user_id: i64,
+    #[serde(skip)]
     pub client_can_receive: bool,
+    #[serde(skip)]
+    pub disconnected_at: Option<Instant>,
+    #[serde(skip)]
+    pub latency_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AutoExecuteEntry {
+    #[serde(rename = "ScriptId", alias = "script_id")]
+    pub script_id: String,
+    #[serde(rename = "Params", alias = "params", default)]
+    pub params: BTreeMap<String, String>,
 }
 
-fn default_relaunch_delay() -> f64 {
-    30.0
+// Older files stored 30 for every account whether or not it was chosen,
+// so that value and non-positive ones fall back to the global delay.
+fn deserialize_relaunch_delay<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let delay = Option::<f64>::deserialize(deserializer)?;
+    Ok(delay.and_then(valid_relaunch_delay).filter(|d| *d != 30.0))
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -68,6 +129,10 @@ impl Default for AccountStatus {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CustomElement {
     pub name: String,
+    #[serde(default)]
+    pub script: String,
+    #[serde(default)]
+    pub owners: Vec<String>,
     pub element_type: String,
     pub content: String,
     pub size: Option<(i32, i32)>,
@@ -89,13 +154,22 @@ pub struct NexusStatus {
 pub struct AccountView {
     pub username: String,
     pub auto_execute: String,
+    #[serde(default)]
+    pub auto_execute_scripts: Vec<AutoExecuteEntry>,
     pub place_id: i64,
     pub job_id: String,
-    pub relaunch_delay: f64,
+    #[serde(default)]
+    pub relaunch_delay: Option<f64>,
     pub auto_relaunch: bool,
     pub is_checked: bool,
     pub status: String,
     pub in_game_job_id: String,
+    #[serde(default)]
+    pub has_secret: bool,
+    #[serde(default)]
+    pub latency_ms: Option<u64>,
+    #[serde(default)]
+    pub broadcast_groups: Vec<String>,
 }
 
 #[derive(Deserialize)]
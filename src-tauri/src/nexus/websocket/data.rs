@@ -0,0 +1,249 @@
+const MAX_DATA_KEYS_PER_SCRIPT: usize = 1000;
+const MAX_DATA_VALUE_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DataWatch {
+    script: String,
+    key: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DataKeyData {
+    #[serde(default)]
+    script: String,
+    key: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NexusDataWrite {
+    #[serde(default)]
+    pub script: String,
+    pub key: String,
+    #[serde(default)]
+    pub value: serde_json::Value,
+    #[serde(default)]
+    pub if_absent: bool,
+    #[serde(default)]
+    pub expected: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DataWatchData {
+    #[serde(default)]
+    script: String,
+    #[serde(default)]
+    key: Option<String>,
+}
+
+impl NexusServer {
+    fn shared_data_path() -> PathBuf {
+        Self::data_path().with_file_name("NexusData.json")
+    }
+
+    pub fn set_data_persistence(&self, enabled: bool) {
+        let was_enabled = self.persist_data.swap(enabled, Ordering::SeqCst);
+        if !enabled || was_enabled {
+            return;
+        }
+
+        let mut data = self.shared_data.lock().unwrap();
+        if data.is_empty() {
+            if let Some(saved) = fs::read_to_string(Self::shared_data_path())
+                .ok()
+                .and_then(|raw| serde_json::from_str(&raw).ok())
+            {
+                *data = saved;
+            }
+        }
+        drop(data);
+        self.save_shared_data();
+    }
+
+    fn save_shared_data(&self) {
+        if !self.persist_data.load(Ordering::SeqCst) {
+            return;
+        }
+        let data = self.shared_data.lock().unwrap();
+        if let Ok(json) = serde_json::to_string_pretty(&*data) {
+            let _ = crate::data::atomic::write_atomic(&Self::shared_data_path(), json.as_bytes());
+        }
+    }
+
+    pub fn get_shared_data(
+        &self,
+        script: Option<&str>,
+    ) -> BTreeMap<String, BTreeMap<String, serde_json::Value>> {
+        let data = self.shared_data.lock().unwrap();
+        data.iter()
+            .filter(|(s, _)| script.is_none_or(|script| script == s.as_str()))
+            .map(|(s, values)| (s.clone(), values.clone()))
+            .collect()
+    }
+
+    pub fn get_data_value(&self, script: &str, key: &str) -> Option<serde_json::Value> {
+        self.shared_data
+            .lock()
+            .unwrap()
+            .get(script)
+            .and_then(|values| values.get(key))
+            .cloned()
+    }
+
+    // Returns whether the write went through and the value now stored, so a
+    // rejected `ifAbsent`/`expected` write tells the caller who holds the key.
+    pub fn set_data_value(
+        &self,
+        write: NexusDataWrite,
+        by: Option<&str>,
+        app: Option<&tauri::AppHandle>,
+    ) -> Result<(bool, serde_json::Value), String> {
+        let NexusDataWrite {
+            script,
+            key,
+            value,
+            if_absent,
+            expected,
+        } = write;
+        let (script, key) = (script.as_str(), key.as_str());
+        if key.is_empty() {
+            return Err("Key cannot be empty".into());
+        }
+        if value.is_null() {
+            let removed = self.delete_data_value(script, key, by, app);
+            return Ok((removed, serde_json::Value::Null));
+        }
+        if serde_json::to_string(&value).map_or(0, |s| s.len()) > MAX_DATA_VALUE_BYTES {
+            return Err(format!(
+                "Value is too large (max {} bytes)",
+                MAX_DATA_VALUE_BYTES
+            ));
+        }
+
+        {
+            let mut data = self.shared_data.lock().unwrap();
+            let values = data.entry(script.to_string()).or_default();
+            let current = values.get(key);
+            if (if_absent && current.is_some())
+                || expected
+                    .as_ref()
+                    .is_some_and(|e| current.unwrap_or(&serde_json::Value::Null) != e)
+            {
+                return Ok((false, current.cloned().unwrap_or_default()));
+            }
+            if current.is_none() && values.len() >= MAX_DATA_KEYS_PER_SCRIPT {
+                return Err(format!(
+                    "Key limit reached (max {})",
+                    MAX_DATA_KEYS_PER_SCRIPT
+                ));
+            }
+            values.insert(key.to_string(), value.clone());
+        }
+
+        self.save_shared_data();
+        self.notify_data_watchers(script, key, &value, by, app);
+        Ok((true, value))
+    }
+
+    pub fn delete_data_value(
+        &self,
+        script: &str,
+        key: &str,
+        by: Option<&str>,
+        app: Option<&tauri::AppHandle>,
+    ) -> bool {
+        let removed = {
+            let mut data = self.shared_data.lock().unwrap();
+            let Some(values) = data.get_mut(script) else {
+                return false;
+            };
+            let removed = values.remove(key).is_some();
+            if values.is_empty() {
+                data.remove(script);
+            }
+            removed
+        };
+
+        if removed {
+            self.save_shared_data();
+            self.notify_data_watchers(script, key, &serde_json::Value::Null, by, app);
+        }
+        removed
+    }
+
+    pub fn watch_data(&self, username: &str, script: &str, key: Option<&str>) {
+        let watch = DataWatch {
+            script: script.to_string(),
+            key: key.map(str::to_string),
+        };
+        let mut watchers = self.data_watchers.lock().unwrap();
+        let entry = watchers.entry(username.to_string()).or_default();
+        if !entry.contains(&watch) {
+            entry.push(watch);
+        }
+    }
+
+    pub fn unwatch_data(&self, username: &str, script: &str, key: Option<&str>) {
+        let mut watchers = self.data_watchers.lock().unwrap();
+        if let Some(entry) = watchers.get_mut(username) {
+            entry.retain(|w| !(w.script == script && (key.is_none() || w.key.as_deref() == key)));
+            if entry.is_empty() {
+                watchers.remove(username);
+            }
+        }
+    }
+
+    fn clear_data_watches(&self, username: &str) {
+        self.data_watchers.lock().unwrap().remove(username);
+    }
+
+    fn notify_data_watchers(
+        &self,
+        script: &str,
+        key: &str,
+        value: &serde_json::Value,
+        by: Option<&str>,
+        app: Option<&tauri::AppHandle>,
+    ) {
+        let subscribers: Vec<String> = {
+            let watchers = self.data_watchers.lock().unwrap();
+            watchers
+                .iter()
+                .filter(|(_, watches)| {
+                    watches
+                        .iter()
+                        .any(|w| w.script == script && w.key.as_deref().is_none_or(|k| k == key))
+                })
+                .map(|(username, _)| username.clone())
+                .collect()
+        };
+
+        if !subscribers.is_empty() {
+            let frame = ServerFrame::new(
+                "data",
+                None,
+                serde_json::json!({
+                    "script": script,
+                    "key": key,
+                    "value": value,
+                    "by": by,
+                }),
+            )
+            .encode();
+
+            let conns = self.connections.lock().unwrap();
+            for username in &subscribers {
+                if let Some(conn) = conns.get(username).filter(|c| c.protocol >= 2) {
+                    let _ = conn.sender.send(frame.clone());
+                }
+            }
+        }
+
+        if let Some(app) = app {
+            let _ = app.emit(
+                "nexus-data-changed",
+                serde_json::json!({ "script": script, "key": key, "value": value, "by": by }),
+            );
+        }
+    }
+}
@@ -0,0 +1,240 @@
+impl NexusServer {
+    fn elements_path() -> PathBuf {
+        Self::data_path().with_file_name("NexusElements.json")
+    }
+
+    pub fn set_element_persistence(&self, enabled: bool) {
+        let was_enabled = self.persist_elements.swap(enabled, Ordering::SeqCst);
+        if !enabled || was_enabled {
+            return;
+        }
+
+        let mut elements = self.custom_elements.lock().unwrap();
+        if elements.is_empty() {
+            if let Some(saved) = fs::read_to_string(Self::elements_path())
+                .ok()
+                .and_then(|data| serde_json::from_str::<Vec<CustomElement>>(&data).ok())
+            {
+                *elements = saved;
+            }
+        }
+        drop(elements);
+        self.save_elements();
+    }
+
+    fn save_elements(&self) {
+        if !self.persist_elements.load(Ordering::SeqCst) {
+            return;
+        }
+        let elements = self.custom_elements.lock().unwrap();
+        if let Ok(json) = serde_json::to_string_pretty(&*elements) {
+            let _ = crate::data::atomic::write_atomic(&Self::elements_path(), json.as_bytes());
+        }
+    }
+
+    fn emit_elements_changed(&self, app: Option<&tauri::AppHandle>) {
+        if let Some(app) = app {
+            let _ = app.emit("nexus-elements-changed", serde_json::json!({}));
+        }
+    }
+
+    fn get_element_text(&self, username: &str, script: Option<&str>, name: &str) -> String {
+        let elements = self.custom_elements.lock().unwrap();
+        let mut candidates = elements
+            .iter()
+            .filter(|e| e.name == name && script.is_none_or(|s| e.script == s));
+        let first = candidates.clone().next();
+        candidates
+            .find(|e| e.owners.iter().any(|o| o == username))
+            .or(first)
+            .map(|e| e.value.clone())
+            .unwrap_or_default()
+    }
+
+    /// Elements a connection has sent for `script` so far. A reconnecting
+    /// script re-sends its layout in the same order, so positions repeat.
+    fn element_position(&self, username: &str, script: &str) -> usize {
+        let positions = self.element_positions.lock().unwrap();
+        positions
+            .get(&(username.to_string(), script.to_string()))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    fn reset_element_positions(&self, username: &str) {
+        let mut positions = self.element_positions.lock().unwrap();
+        positions.retain(|(owner, _), _| owner != username);
+    }
+
+    fn add_element(&self, username: &str, mut element: CustomElement, app: Option<&tauri::AppHandle>) {
+        *self
+            .element_positions
+            .lock()
+            .unwrap()
+            .entry((username.to_string(), element.script.clone()))
+            .or_insert(0) += 1;
+
+        let mut elements = self.custom_elements.lock().unwrap();
+        if let Some(existing) = elements
+            .iter_mut()
+            .find(|e| e.script == element.script && e.name == element.name)
+        {
+            // Several clients running the same script share one element.
+            if !existing.owners.iter().any(|o| o == username) {
+                existing.owners.push(username.to_string());
+            }
+            drop(elements);
+            self.save_elements();
+            return;
+        }
+
+        element.owners = vec![username.to_string()];
+        elements.push(element.clone());
+        drop(elements);
+        self.save_elements();
+
+        if let Some(app) = app {
+            let _ = app.emit(
+                "nexus-element-created",
+                serde_json::json!({
+                    "name": element.name,
+                    "script": element.script,
+                    "elementType": element.element_type,
+                    "content": element.content,
+                }),
+            );
+        }
+    }
+
+    fn add_newline(&self, username: &str, script: &str, app: Option<&tauri::AppHandle>) {
+        let position = self.element_position(username, script);
+        self.add_element(
+            username,
+            CustomElement {
+                name: format!("__newline_{}", position),
+                script: script.to_string(),
+                owners: Vec::new(),
+                element_type: "NewLine".to_string(),
+                content: String::new(),
+                size: None,
+                margin: None,
+                decimal_places: None,
+                increment: None,
+                value: String::new(),
+                is_newline: true,
+            },
+            None,
+        );
+
+        if let Some(app) = app {
+            let _ = app.emit("nexus-element-newline", serde_json::json!({ "script": script }));
+        }
+    }
+
+    pub fn remove_element(&self, script: &str, name: &str, app: Option<&tauri::AppHandle>) -> bool {
+        let mut elements = self.custom_elements.lock().unwrap();
+        let before = elements.len();
+        elements.retain(|e| !(e.script == script && e.name == name));
+        let removed = elements.len() < before;
+        drop(elements);
+
+        if removed {
+            self.save_elements();
+            self.emit_elements_changed(app);
+        }
+        removed
+    }
+
+    /// Clears elements of `script`, or of every script. With an `owner` only
+    /// that client's elements are affected: it stops owning them and the
+    /// ones nobody else owns are removed.
+    pub fn clear_elements(
+        &self,
+        owner: Option<&str>,
+        script: Option<&str>,
+        app: Option<&tauri::AppHandle>,
+    ) -> usize {
+        let mut elements = self.custom_elements.lock().unwrap();
+        let before = elements.len();
+        elements.retain_mut(|element| {
+            if script.is_some_and(|s| element.script != s) {
+                return true;
+            }
+            let Some(owner) = owner else {
+                return false;
+            };
+            if !element.owners.iter().any(|o| o == owner) {
+                return true;
+            }
+            element.owners.retain(|o| o != owner);
+            !element.owners.is_empty()
+        });
+        let removed = before - elements.len();
+        drop(elements);
+
+        if removed > 0 {
+            self.save_elements();
+            self.emit_elements_changed(app);
+        }
+        removed
+    }
+
+    pub fn set_element_value(&self, script: &str, name: &str, value: &str) -> usize {
+        {
+            let mut elements = self.custom_elements.lock().unwrap();
+            let Some(el) = elements
+                .iter_mut()
+                .find(|e| e.script == script && e.name == name)
+            else {
+                return 0;
+            };
+            el.value = value.to_string();
+        }
+        self.save_elements();
+        self.dispatch_element_event(script, name, "change", Some(value))
+    }
+
+    pub fn click_element(&self, script: &str, name: &str) -> usize {
+        self.dispatch_element_event(script, name, "click", None)
+    }
+
+    fn dispatch_element_event(&self, script: &str, name: &str, kind: &str, value: Option<&str>) -> usize {
+        let owners = {
+            let elements = self.custom_elements.lock().unwrap();
+            match elements.iter().find(|e| e.script == script && e.name == name) {
+                Some(el) => el.owners.clone(),
+                None => return 0,
+            }
+        };
+
+        let frame = ServerFrame::new(
+            "event",
+            None,
+            serde_json::json!({
+                "script": script,
+                "name": name,
+                "kind": kind,
+                "value": value,
+            }),
+        )
+        .encode();
+        let legacy = (kind == "click").then(|| format!("ButtonClicked:{}", name));
+
+        let conns = self.connections.lock().unwrap();
+        let mut delivered = 0;
+        for owner in &owners {
+            let Some(conn) = conns.get(owner) else {
+                continue;
+            };
+            if conn.protocol >= 2 {
+                let _ = conn.sender.send(frame.clone());
+            } else if let Some(message) = &legacy {
+                let _ = conn.sender.send(message.clone());
+            } else {
+                continue;
+            }
+            delivered += 1;
+        }
+        delivered
+    }
+}
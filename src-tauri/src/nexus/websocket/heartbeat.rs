@@ -0,0 +1,96 @@
+fn unix_now_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[derive(Debug, Deserialize)]
+struct PongData {
+    sent: u64,
+}
+
+impl NexusServer {
+    pub fn try_start_heartbeat_monitor(&self) -> bool {
+        self.heartbeat_monitor
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+    }
+
+    pub fn finish_heartbeat_monitor(&self) {
+        self.heartbeat_monitor.store(false, Ordering::SeqCst);
+    }
+
+    pub fn reap_stale_connections(&self, timeout: Duration, app: &tauri::AppHandle) -> Vec<String> {
+        let mut stale = Vec::new();
+        {
+            let mut accounts = self.accounts.lock().unwrap();
+            for account in accounts.iter_mut() {
+                if account.status != AccountStatus::Online {
+                    continue;
+                }
+                if account.last_ping.is_some_and(|t| t.elapsed() < timeout) {
+                    continue;
+                }
+                account.status = AccountStatus::Offline;
+                account.client_can_receive = false;
+                account.latency_ms = None;
+                account.disconnected_at = Some(Instant::now());
+                stale.push(account.username.clone());
+            }
+        }
+
+        if stale.is_empty() {
+            return stale;
+        }
+
+        // Dropping the sender closes the socket; the connection task emits the disconnect.
+        {
+            let mut conns = self.connections.lock().unwrap();
+            for username in &stale {
+                conns.remove(username);
+            }
+        }
+
+        for username in &stale {
+            self.log(
+                NexusLogLevel::Warn,
+                Some(username),
+                &format!(
+                    "{} timed out after {}s without a heartbeat",
+                    username,
+                    timeout.as_secs()
+                ),
+                None,
+                Some(app),
+            );
+        }
+
+        stale
+    }
+
+    pub fn probe_latency(&self) {
+        let frame = ServerFrame::new(
+            "ping",
+            None,
+            serde_json::json!({ "sent": unix_now_millis() }),
+        )
+        .encode();
+
+        let conns = self.connections.lock().unwrap();
+        for conn in conns.values() {
+            if conn.capabilities.iter().any(|c| c == "latency") {
+                let _ = conn.sender.send(frame.clone());
+            }
+        }
+    }
+
+    fn record_pong(&self, username: &str, pong: PongData) {
+        let latency = unix_now_millis().saturating_sub(pong.sent);
+        let mut accounts = self.accounts.lock().unwrap();
+        if let Some(account) = accounts.iter_mut().find(|a| a.username == username) {
+            account.latency_ms = Some(latency);
+            account.last_ping = Some(Instant::now());
+        }
+    }
+}
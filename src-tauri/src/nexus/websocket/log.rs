@@ -0,0 +1,220 @@
+const DEFAULT_LOG_CAPACITY: usize = 1000;
+const MAX_ROTATED_LOG_FILES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NexusLogLevel {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl NexusLogLevel {
+    fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "debug" | "trace" => Some(Self::Debug),
+            "info" | "print" => Some(Self::Info),
+            "warn" | "warning" => Some(Self::Warn),
+            "error" => Some(Self::Error),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NexusLogEntry {
+    pub timestamp_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    pub level: NexusLogLevel,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+impl NexusLogEntry {
+    fn to_line(&self) -> String {
+        format!(
+            "{} [{}] {}{}",
+            self.timestamp_ms,
+            self.level.label(),
+            self.username
+                .as_deref()
+                .map(|u| format!("<{}> ", u))
+                .unwrap_or_default(),
+            self.message
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NexusLogFilter {
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub min_level: Option<NexusLogLevel>,
+    #[serde(default)]
+    pub since_ms: Option<u64>,
+    #[serde(default)]
+    pub until_ms: Option<u64>,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+impl NexusLogFilter {
+    fn matches(&self, entry: &NexusLogEntry) -> bool {
+        self.username.as_deref().is_none_or(|u| {
+            entry
+                .username
+                .as_deref()
+                .is_some_and(|e| e.eq_ignore_ascii_case(u))
+        }) && self.min_level.is_none_or(|l| entry.level >= l)
+            && self.since_ms.is_none_or(|t| entry.timestamp_ms >= t)
+            && self.until_ms.is_none_or(|t| entry.timestamp_ms <= t)
+    }
+}
+
+struct NexusLog {
+    entries: std::collections::VecDeque<NexusLogEntry>,
+    capacity: usize,
+    max_file_bytes: Option<u64>,
+}
+
+impl Default for NexusLog {
+    fn default() -> Self {
+        Self {
+            entries: std::collections::VecDeque::new(),
+            capacity: DEFAULT_LOG_CAPACITY,
+            max_file_bytes: None,
+        }
+    }
+}
+
+impl NexusLog {
+    fn push(&mut self, entry: NexusLogEntry) {
+        while self.entries.len() >= self.capacity.max(1) {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+}
+
+impl NexusServer {
+    fn log_path() -> PathBuf {
+        Self::data_path().with_file_name("NexusLog.jsonl")
+    }
+
+    fn rotated_log_path(index: usize) -> PathBuf {
+        Self::data_path().with_file_name(format!("NexusLog.{}.jsonl", index))
+    }
+
+    pub fn configure_log(&self, capacity: usize, max_file_kb: u64) {
+        let mut log = self.log_messages.lock().unwrap();
+        log.capacity = if capacity == 0 {
+            DEFAULT_LOG_CAPACITY
+        } else {
+            capacity
+        };
+        log.max_file_bytes = (max_file_kb > 0).then_some(max_file_kb * 1024);
+        while log.entries.len() > log.capacity {
+            log.entries.pop_front();
+        }
+    }
+
+    fn append_to_disk(entry: &NexusLogEntry, max_bytes: u64) {
+        use std::io::Write;
+
+        let path = Self::log_path();
+        if fs::metadata(&path).is_ok_and(|m| m.len() >= max_bytes) {
+            let _ = fs::remove_file(Self::rotated_log_path(MAX_ROTATED_LOG_FILES));
+            for index in (1..MAX_ROTATED_LOG_FILES).rev() {
+                let _ = fs::rename(Self::rotated_log_path(index), Self::rotated_log_path(index + 1));
+            }
+            let _ = fs::rename(&path, Self::rotated_log_path(1));
+        }
+
+        let Ok(line) = serde_json::to_string(entry) else {
+            return;
+        };
+        if let Ok(mut file) = fs::OpenOptions::new().create(true).append(true).open(&path) {
+            let _ = writeln!(file, "{}", line);
+        }
+    }
+
+    pub fn log(
+        &self,
+        level: NexusLogLevel,
+        username: Option<&str>,
+        message: &str,
+        data: Option<serde_json::Value>,
+        app: Option<&tauri::AppHandle>,
+    ) {
+        let entry = NexusLogEntry {
+            timestamp_ms: unix_now_millis(),
+            username: username.map(str::to_string),
+            level,
+            message: message.to_string(),
+            data,
+        };
+
+        let max_file_bytes = {
+            let mut log = self.log_messages.lock().unwrap();
+            log.push(entry.clone());
+            log.max_file_bytes
+        };
+        if let Some(max_bytes) = max_file_bytes {
+            Self::append_to_disk(&entry, max_bytes);
+        }
+
+        if let Some(app) = app {
+            let _ = app.emit("nexus-log", &entry);
+        }
+    }
+
+    pub fn get_log(&self, filter: &NexusLogFilter) -> Vec<NexusLogEntry> {
+        let log = self.log_messages.lock().unwrap();
+        let mut entries: Vec<NexusLogEntry> = log
+            .entries
+            .iter()
+            .filter(|e| filter.matches(e))
+            .cloned()
+            .collect();
+        if let Some(limit) = filter.limit {
+            let excess = entries.len().saturating_sub(limit);
+            entries.drain(..excess);
+        }
+        entries
+    }
+
+    pub fn clear_log(&self) {
+        self.log_messages.lock().unwrap().entries.clear();
+    }
+
+    pub fn export_log(&self, path: &std::path::Path, filter: &NexusLogFilter, as_text: bool) -> Result<usize, String> {
+        let entries = self.get_log(filter);
+        let mut out = String::new();
+        for entry in &entries {
+            let line = if as_text {
+                entry.to_line()
+            } else {
+                serde_json::to_string(entry).map_err(|e| e.to_string())?
+            };
+            out.push_str(&line);
+            out.push('\n');
+        }
+        fs::write(path, out).map_err(|e| format!("Failed to write Nexus log: {}", e))?;
+        Ok(entries.len())
+    }
+}
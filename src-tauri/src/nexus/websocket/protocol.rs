@@ -0,0 +1,401 @@
+pub const NEXUS_PROTOCOL_VERSION: u32 = 2;
+
+const NEXUS_CAPABILITIES: &[&str] = &[
+    "log",
+    "elements",
+    "get_text",
+    "relaunch",
+    "echo",
+    "errors",
+    "latency",
+    "element_events",
+    "targeting",
+    "data",
+];
+
+#[derive(Debug, Deserialize)]
+struct ClientFrame {
+    v: u32,
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    data: serde_json::Value,
+}
+
+#[derive(Debug, Serialize)]
+struct ServerFrame {
+    v: u32,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    re: Option<String>,
+    data: serde_json::Value,
+}
+
+impl ServerFrame {
+    fn new(kind: &str, re: Option<String>, data: serde_json::Value) -> Self {
+        Self {
+            v: NEXUS_PROTOCOL_VERSION,
+            kind: kind.to_string(),
+            re,
+            data,
+        }
+    }
+
+    fn encode(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct HelloData {
+    version: u32,
+    #[serde(default)]
+    capabilities: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContentData {
+    content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct LogData {
+    content: String,
+    #[serde(default)]
+    level: Option<String>,
+    #[serde(default)]
+    data: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NameData {
+    name: String,
+    #[serde(default)]
+    script: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ScriptData {
+    #[serde(default)]
+    script: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SecondsData {
+    seconds: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct EnabledData {
+    enabled: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PlaceIdData {
+    place_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JobIdData {
+    job_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SendData {
+    target: NexusTarget,
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ElementData {
+    element_type: String,
+    name: String,
+    #[serde(default)]
+    script: String,
+    #[serde(default)]
+    content: String,
+    size: Option<(i32, i32)>,
+    margin: Option<(i32, i32, i32, i32)>,
+    decimal_places: Option<i32>,
+    increment: Option<String>,
+}
+
+fn parse_frame_data<T: serde::de::DeserializeOwned>(data: &serde_json::Value) -> Result<T, String> {
+    let data = if data.is_null() {
+        serde_json::json!({})
+    } else {
+        data.clone()
+    };
+    serde_json::from_value(data).map_err(|e| e.to_string())
+}
+
+impl NexusServer {
+    fn send_frame(&self, username: &str, frame: ServerFrame) {
+        let conns = self.connections.lock().unwrap();
+        if let Some(conn) = conns.get(username) {
+            let _ = conn.sender.send(frame.encode());
+        }
+    }
+
+    fn send_error(&self, username: &str, re: Option<String>, code: &str, message: &str) {
+        self.send_frame(
+            username,
+            ServerFrame::new(
+                "error",
+                re,
+                serde_json::json!({ "code": code, "message": message }),
+            ),
+        );
+    }
+
+    fn send_ok(&self, username: &str, re: Option<String>, data: serde_json::Value) {
+        if re.is_some() {
+            self.send_frame(username, ServerFrame::new("response", re, data));
+        }
+    }
+
+    pub fn connection_capabilities(&self, username: &str) -> Vec<String> {
+        self.connections
+            .lock()
+            .unwrap()
+            .get(username)
+            .map(|c| c.capabilities.clone())
+            .unwrap_or_default()
+    }
+
+    fn handle_frame(&self, username: &str, frame: ClientFrame, app: Option<&tauri::AppHandle>) {
+        let re = frame.id.clone();
+
+        if frame.v < 2 || frame.v > NEXUS_PROTOCOL_VERSION {
+            self.send_error(
+                username,
+                re,
+                "unsupported_version",
+                &format!(
+                    "Server supports protocol versions 2..={}",
+                    NEXUS_PROTOCOL_VERSION
+                ),
+            );
+            return;
+        }
+
+        let result: Result<serde_json::Value, (&str, String)> = match frame.kind.as_str() {
+            "hello" => parse_frame_data::<HelloData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|hello| {
+                    let version = hello.version.clamp(2, NEXUS_PROTOCOL_VERSION);
+                    let capabilities: Vec<String> = hello
+                        .capabilities
+                        .into_iter()
+                        .filter(|c| NEXUS_CAPABILITIES.contains(&c.as_str()))
+                        .collect();
+
+                    let mut conns = self.connections.lock().unwrap();
+                    if let Some(conn) = conns.get_mut(username) {
+                        conn.protocol = version;
+                        conn.capabilities = capabilities;
+                    }
+                    drop(conns);
+
+                    self.send_frame(
+                        username,
+                        ServerFrame::new(
+                            "welcome",
+                            re.clone(),
+                            serde_json::json!({
+                                "version": version,
+                                "capabilities": NEXUS_CAPABILITIES,
+                            }),
+                        ),
+                    );
+                    serde_json::Value::Null
+                }),
+            "ping" => {
+                self.touch_ping(username);
+                Ok(serde_json::Value::Null)
+            }
+            "pong" => parse_frame_data::<PongData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|pong| {
+                    self.record_pong(username, pong);
+                    serde_json::Value::Null
+                }),
+            "Log" => parse_frame_data::<LogData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|d| {
+                    let level = d
+                        .level
+                        .as_deref()
+                        .and_then(NexusLogLevel::parse)
+                        .unwrap_or(NexusLogLevel::Info);
+                    self.log(level, Some(username), &d.content, d.data, app);
+                    serde_json::Value::Null
+                }),
+            "GetText" => parse_frame_data::<NameData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .and_then(|d| {
+                    let script = d.script.as_deref();
+                    let exists = self
+                        .custom_elements
+                        .lock()
+                        .unwrap()
+                        .iter()
+                        .any(|e| e.name == d.name && script.is_none_or(|s| e.script == s));
+                    if !exists {
+                        return Err(("not_found", format!("No element named {}", d.name)));
+                    }
+                    let text = self.get_element_text(username, script, &d.name);
+                    Ok(serde_json::json!({ "name": d.name, "text": text }))
+                }),
+            "SetRelaunch" => parse_frame_data::<SecondsData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|d| {
+                    self.update_controlled(username, |a| {
+                        a.relaunch_delay = valid_relaunch_delay(d.seconds)
+                    });
+                    serde_json::Value::Null
+                }),
+            "SetAutoRelaunch" => parse_frame_data::<EnabledData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|d| {
+                    self.update_controlled(username, |a| a.auto_relaunch = d.enabled);
+                    serde_json::Value::Null
+                }),
+            "SetPlaceId" => parse_frame_data::<PlaceIdData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|d| {
+                    self.update_controlled(username, |a| a.place_id = d.place_id);
+                    serde_json::Value::Null
+                }),
+            "SetJobId" => parse_frame_data::<JobIdData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|d| {
+                    self.update_controlled(username, |a| a.job_id = d.job_id);
+                    serde_json::Value::Null
+                }),
+            "Echo" => parse_frame_data::<ContentData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|d| {
+                    self.send_to_all(&d.content);
+                    serde_json::Value::Null
+                }),
+            "Send" => parse_frame_data::<SendData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|d| {
+                    let sent = self.send_to_target(&d.target, &d.message);
+                    serde_json::json!({ "sent": sent })
+                }),
+            "CreateElement" => parse_frame_data::<ElementData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .and_then(|d| {
+                    if !matches!(
+                        d.element_type.as_str(),
+                        "Button" | "TextBox" | "Numeric" | "Label"
+                    ) {
+                        return Err((
+                            "invalid_data",
+                            format!("Unknown element type {}", d.element_type),
+                        ));
+                    }
+                    self.add_element(
+                        username,
+                        CustomElement {
+                            name: d.name,
+                            script: d.script,
+                            owners: Vec::new(),
+                            element_type: d.element_type,
+                            content: d.content.clone(),
+                            size: d.size,
+                            margin: d.margin,
+                            decimal_places: d.decimal_places,
+                            increment: d.increment,
+                            value: d.content,
+                            is_newline: false,
+                        },
+                        app,
+                    );
+                    Ok(serde_json::Value::Null)
+                }),
+            "NewLine" => parse_frame_data::<ScriptData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|d| {
+                    self.add_newline(username, d.script.as_deref().unwrap_or(""), app);
+                    serde_json::Value::Null
+                }),
+            "RemoveElement" => parse_frame_data::<NameData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .and_then(|d| {
+                    let script = d.script.unwrap_or_default();
+                    if self.remove_element(&script, &d.name, app) {
+                        Ok(serde_json::Value::Null)
+                    } else {
+                        Err(("not_found", format!("No element named {}", d.name)))
+                    }
+                }),
+            "ClearElements" => parse_frame_data::<ScriptData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|d| {
+                    let removed = self.clear_elements(Some(username), d.script.as_deref(), app);
+                    serde_json::json!({ "removed": removed })
+                }),
+            "DataGet" => parse_frame_data::<DataKeyData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|d| {
+                    let value = self.get_data_value(&d.script, &d.key);
+                    serde_json::json!({ "key": d.key, "value": value })
+                }),
+            "DataSet" => parse_frame_data::<NexusDataWrite>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .and_then(|d| {
+                    let key = d.key.clone();
+                    self.set_data_value(d, Some(username), app)
+                        .map_err(|e| ("invalid_data", e))
+                        .map(|(set, value)| {
+                            serde_json::json!({ "key": key, "set": set, "value": value })
+                        })
+                }),
+            "DataDelete" => parse_frame_data::<DataKeyData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|d| {
+                    let removed = self.delete_data_value(&d.script, &d.key, Some(username), app);
+                    serde_json::json!({ "key": d.key, "removed": removed })
+                }),
+            "DataKeys" => parse_frame_data::<ScriptData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|d| {
+                    let script = d.script.unwrap_or_default();
+                    let keys: Vec<String> = self
+                        .get_shared_data(Some(&script))
+                        .remove(&script)
+                        .map(|values| values.into_keys().collect())
+                        .unwrap_or_default();
+                    serde_json::json!({ "keys": keys })
+                }),
+            "DataWatch" => parse_frame_data::<DataWatchData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|d| {
+                    self.watch_data(username, &d.script, d.key.as_deref());
+                    serde_json::Value::Null
+                }),
+            "DataUnwatch" => parse_frame_data::<DataWatchData>(&frame.data)
+                .map_err(|e| ("invalid_data", e))
+                .map(|d| {
+                    self.unwatch_data(username, &d.script, d.key.as_deref());
+                    serde_json::Value::Null
+                }),
+            other => Err(("unknown_type", format!("Unknown message type {}", other))),
+        };
+
+        match result {
+            Ok(_) if frame.kind == "hello" => {}
+            Ok(data) => self.send_ok(username, re, data),
+            Err((code, message)) => self.send_error(username, re, code, &message),
+        }
+    }
+}
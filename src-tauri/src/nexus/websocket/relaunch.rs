@@ -0,0 +1,111 @@
+const MAX_RELAUNCH_DELAY_SECS: f64 = 86_400.0;
+
+/// A per-account relaunch delay as sent by a client, or `None` to use the
+/// global `RelaunchDelay`.
+fn valid_relaunch_delay(seconds: f64) -> Option<f64> {
+    Some(seconds).filter(|s| s.is_finite() && *s > 0.0 && *s <= MAX_RELAUNCH_DELAY_SECS)
+}
+
+#[derive(Debug, Clone)]
+pub struct RelaunchTarget {
+    pub username: String,
+    pub place_id: i64,
+    pub job_id: String,
+}
+
+impl NexusServer {
+    pub fn try_start_relaunch_supervisor(&self) -> bool {
+        self.relaunch_supervisor
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+    }
+
+    pub fn finish_relaunch_supervisor(&self) {
+        self.relaunch_supervisor.store(false, Ordering::SeqCst);
+    }
+
+    pub fn try_start_instance_controller(&self) -> bool {
+        self.instance_controller
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+    }
+
+    pub fn finish_instance_controller(&self) {
+        self.instance_controller.store(false, Ordering::SeqCst);
+    }
+
+    pub fn set_internet_available(&self, available: bool, app: &tauri::AppHandle) {
+        let previous = self.internet_available.swap(available, Ordering::SeqCst);
+        if previous != available {
+            let (level, message) = if available {
+                (NexusLogLevel::Info, "Internet connection restored, resuming relaunches")
+            } else {
+                (NexusLogLevel::Warn, "Internet check failed, pausing relaunches")
+            };
+            self.log(level, None, message, None, Some(app));
+        }
+    }
+
+    pub fn is_internet_available(&self) -> bool {
+        self.internet_available.load(Ordering::SeqCst)
+    }
+
+    pub fn controlled_usernames(&self) -> Vec<String> {
+        self.accounts
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|a| a.username.clone())
+            .collect()
+    }
+
+    pub fn take_due_relaunches(&self, default_delay: f64) -> Vec<RelaunchTarget> {
+        let mut accounts = self.accounts.lock().unwrap();
+        let mut due = Vec::new();
+
+        for account in accounts.iter_mut() {
+            if !account.is_checked
+                || !account.auto_relaunch
+                || account.status == AccountStatus::Online
+                || account.place_id <= 0
+            {
+                continue;
+            }
+            let Some(since) = account.disconnected_at else {
+                continue;
+            };
+
+            let delay = account.relaunch_delay.unwrap_or(default_delay);
+            let delay = Duration::try_from_secs_f64(delay.max(0.0)).unwrap_or(Duration::MAX);
+            if since.elapsed() < delay {
+                continue;
+            }
+
+            // Re-arm so the account is retried after another delay if it never reconnects.
+            account.disconnected_at = Some(Instant::now());
+            due.push(RelaunchTarget {
+                username: account.username.clone(),
+                place_id: account.place_id,
+                job_id: account.job_id.clone(),
+            });
+        }
+
+        due
+    }
+
+    pub fn report_relaunch(&self, username: &str, result: &Result<(), String>, app: &tauri::AppHandle) {
+        let (level, message) = match result {
+            Ok(()) => (NexusLogLevel::Info, format!("Relaunched {}", username)),
+            Err(e) => (NexusLogLevel::Error, format!("Failed to relaunch {}: {}", username, e)),
+        };
+        self.log(level, Some(username), &message, None, Some(app));
+        let _ = app.emit(
+            "nexus-account-relaunched",
+            serde_json::json!({
+                "username": username,
+                "success": result.is_ok(),
+                "error": result.as_ref().err(),
+            }),
+        );
+    }
+}
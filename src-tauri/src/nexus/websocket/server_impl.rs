@@ -4,8 +4,20 @@ impl NexusServer {
             connections: Mutex::new(HashMap::new()),
             accounts: Mutex::new(Vec::new()),
             custom_elements: Mutex::new(Vec::new()),
-            log_messages: Mutex::new(Vec::new()),
+            element_positions: Mutex::new(HashMap::new()),
+            log_messages: Mutex::new(NexusLog::default()),
             server_handle: Mutex::new(None),
+            auth: Mutex::new(NexusAuthConfig::default()),
+            seen_nonces: Mutex::new(HashMap::new()),
+            relaunch_supervisor: AtomicBool::new(false),
+            heartbeat_monitor: AtomicBool::new(false),
+            instance_controller: AtomicBool::new(false),
+            internet_available: AtomicBool::new(true),
+            persist_elements: AtomicBool::new(false),
+            shared_data: Mutex::new(BTreeMap::new()),
+            data_watchers: Mutex::new(HashMap::new()),
+            persist_data: AtomicBool::new(false),
+            next_connection_id: AtomicU64::new(1),
         };
         server.load_accounts();
         server
@@ -35,7 +47,7 @@ impl NexusServer {
         let accounts = self.accounts.lock().unwrap();
         let path = Self::data_path();
         if let Ok(json) = serde_json::to_string_pretty(&*accounts) {
-            let _ = fs::write(path, json);
+            let _ = crate::data::atomic::write_atomic(&path, json.as_bytes());
         }
     }
 
@@ -69,6 +81,7 @@ impl NexusServer {
             .map(|a| AccountView {
                 username: a.username.clone(),
                 auto_execute: a.auto_execute.clone(),
+                auto_execute_scripts: a.auto_execute_scripts.clone(),
                 place_id: a.place_id,
                 job_id: a.job_id.clone(),
                 relaunch_delay: a.relaunch_delay,
@@ -80,6 +93,9 @@ impl NexusServer {
                     "Offline".into()
                 },
                 in_game_job_id: a.in_game_job_id.clone(),
+                has_secret: !a.secret.is_empty(),
+                latency_ms: a.latency_ms,
+                broadcast_groups: a.groups.clone(),
             })
             .collect()
     }
@@ -92,15 +108,22 @@ impl NexusServer {
         accounts.push(ControlledAccount {
             username: username.to_string(),
             auto_execute: String::new(),
+            auto_execute_scripts: Vec::new(),
             place_id: 0,
             job_id: String::new(),
-            relaunch_delay: 30.0,
+            relaunch_delay: None,
             auto_relaunch: false,
             is_checked: false,
+            secret: String::new(),
+            groups: Vec::new(),
             status: AccountStatus::Offline,
             last_ping: None,
             in_game_job_id: String::new(),
+            in_game_place_id: 0,
+            in_game_user_id: 0,
             client_can_receive: false,
+            disconnected_at: None,
+            latency_ms: None,
         });
         drop(accounts);
         self.save_accounts();
@@ -118,6 +141,7 @@ impl NexusServer {
         let mut accounts = self.accounts.lock().unwrap();
         if let Some(account) = accounts.iter_mut().find(|a| a.username == update.username) {
             account.auto_execute = update.auto_execute;
+            account.auto_execute_scripts = update.auto_execute_scripts;
             account.place_id = update.place_id;
             account.job_id = update.job_id;
             account.relaunch_delay = update.relaunch_delay;
@@ -128,59 +152,89 @@ impl NexusServer {
         self.save_accounts();
     }
 
-    pub fn send_command(&self, message: &str) {
+    /// Control settings for the given usernames as stored on disk, without
+    /// their connection secrets.
+    pub fn export_account_settings(&self, usernames: &[String]) -> Vec<serde_json::Value> {
         let accounts = self.accounts.lock().unwrap();
-        let conns = self.connections.lock().unwrap();
+        accounts
+            .iter()
+            .filter(|a| usernames.contains(&a.username))
+            .filter_map(|a| {
+                let mut account = a.clone();
+                account.secret.clear();
+                serde_json::to_value(account).ok()
+            })
+            .collect()
+    }
 
-        for account in accounts.iter() {
-            if account.is_checked && account.status == AccountStatus::Online {
-                if let Some(conn) = conns.get(&account.username) {
-                    let _ = conn.sender.send(message.to_string());
+    /// Adds or overwrites control settings for `usernames` from exported
+    /// entries, keeping any existing secret. Returns how many were applied.
+    pub fn import_account_settings(
+        &self,
+        entries: Vec<serde_json::Value>,
+        usernames: &[String],
+    ) -> usize {
+        let mut accounts = self.accounts.lock().unwrap();
+        let mut applied = 0;
+        for entry in entries {
+            let Ok(mut incoming) = serde_json::from_value::<ControlledAccount>(entry) else {
+                continue;
+            };
+            if !usernames.contains(&incoming.username) {
+                continue;
+            }
+            match accounts.iter_mut().find(|a| a.username == incoming.username) {
+                Some(account) => {
+                    account.auto_execute = incoming.auto_execute;
+                    account.auto_execute_scripts = incoming.auto_execute_scripts;
+                    account.place_id = incoming.place_id;
+                    account.job_id = incoming.job_id;
+                    account.relaunch_delay = incoming.relaunch_delay;
+                    account.auto_relaunch = incoming.auto_relaunch;
+                    account.is_checked = incoming.is_checked;
+                    account.groups = incoming.groups;
+                }
+                None => {
+                    incoming.secret.clear();
+                    accounts.push(incoming);
                 }
             }
+            applied += 1;
         }
+        drop(accounts);
+        if applied > 0 {
+            self.save_accounts();
+        }
+        applied
+    }
+
+    pub fn send_command(&self, message: &str) {
+        self.send_to_target(&NexusTarget::Checked, message);
     }
 
     pub fn send_to_all(&self, message: &str) {
         let conns = self.connections.lock().unwrap();
         for conn in conns.values() {
-            let _ = conn.sender.send(message.to_string());
+            conn.send(message);
         }
     }
 
-    pub fn get_log(&self) -> Vec<String> {
-        self.log_messages.lock().unwrap().clone()
-    }
-
-    pub fn clear_log(&self) {
-        self.log_messages.lock().unwrap().clear();
-    }
-
     pub fn get_elements(&self) -> Vec<CustomElement> {
         self.custom_elements.lock().unwrap().clone()
     }
 
-    pub fn set_element_value(&self, name: &str, value: &str) {
-        let mut elements = self.custom_elements.lock().unwrap();
-        if let Some(el) = elements.iter_mut().find(|e| e.name == name) {
-            el.value = value.to_string();
-        }
-    }
-
-    pub fn get_element_text(&self, name: &str) -> String {
-        let elements = self.custom_elements.lock().unwrap();
-        elements
-            .iter()
-            .find(|e| e.name == name)
-            .map(|e| e.value.clone())
-            .unwrap_or_default()
-    }
-
     fn handle_message(&self, username: &str, raw: &str, app: Option<&tauri::AppHandle>) {
         if raw.is_empty() {
             return;
         }
 
+        if raw.trim_start().starts_with('{') {
+            if let Ok(frame) = serde_json::from_str::<ClientFrame>(raw) {
+                self.handle_frame(username, frame, app);
+                return;
+            }
+        }
+
         let command: Command = match serde_json::from_str(raw) {
             Ok(c) => c,
             Err(_) => return,
@@ -189,24 +243,20 @@ impl NexusServer {
         let payload = command.payload.unwrap_or_default();
 
         match command.name.as_str() {
-            "ping" => {
-                let mut accounts = self.accounts.lock().unwrap();
-                if let Some(account) = accounts.iter_mut().find(|a| a.username == username) {
-                    account.last_ping = Some(Instant::now());
-                    account.client_can_receive = true;
-                }
-            }
+            "ping" => self.touch_ping(username),
             "Log" => {
                 if let Some(content) = payload.get("Content") {
-                    self.log_messages.lock().unwrap().push(content.clone());
-                    if let Some(app) = app {
-                        let _ = app.emit("nexus-log", serde_json::json!({ "message": content }));
-                    }
+                    let level = payload
+                        .get("Level")
+                        .and_then(|l| NexusLogLevel::parse(l))
+                        .unwrap_or(NexusLogLevel::Info);
+                    self.log(level, Some(username), content, None, app);
                 }
             }
             "GetText" => {
                 if let Some(name) = payload.get("Name") {
-                    let text = self.get_element_text(name);
+                    let script = payload.get("Script").map(|s| s.as_str());
+                    let text = self.get_element_text(username, script, name);
                     let conns = self.connections.lock().unwrap();
                     if let Some(conn) = conns.get(username) {
                         let _ = conn.sender.send(format!("ElementText:{}", text));
@@ -215,42 +265,25 @@ impl NexusServer {
             }
             "SetRelaunch" => {
                 if let Some(seconds) = payload.get("Seconds").and_then(|s| s.parse::<f64>().ok()) {
-                    let mut accounts = self.accounts.lock().unwrap();
-                    if let Some(account) = accounts.iter_mut().find(|a| a.username == username) {
-                        account.relaunch_delay = seconds;
-                    }
-                    drop(accounts);
-                    self.save_accounts();
+                    self.update_controlled(username, |a| {
+                        a.relaunch_delay = valid_relaunch_delay(seconds)
+                    });
                 }
             }
             "SetAutoRelaunch" => {
                 if let Some(val) = payload.get("Content").and_then(|s| s.parse::<bool>().ok()) {
-                    let mut accounts = self.accounts.lock().unwrap();
-                    if let Some(account) = accounts.iter_mut().find(|a| a.username == username) {
-                        account.auto_relaunch = val;
-                    }
-                    drop(accounts);
-                    self.save_accounts();
+                    self.update_controlled(username, |a| a.auto_relaunch = val);
                 }
             }
             "SetPlaceId" => {
                 if let Some(pid) = payload.get("Content").and_then(|s| s.parse::<i64>().ok()) {
-                    let mut accounts = self.accounts.lock().unwrap();
-                    if let Some(account) = accounts.iter_mut().find(|a| a.username == username) {
-                        account.place_id = pid;
-                    }
-                    drop(accounts);
-                    self.save_accounts();
+                    self.update_controlled(username, |a| a.place_id = pid);
                 }
             }
             "SetJobId" => {
                 if let Some(content) = payload.get("Content") {
-                    let mut accounts = self.accounts.lock().unwrap();
-                    if let Some(account) = accounts.iter_mut().find(|a| a.username == username) {
-                        account.job_id = content.clone();
-                    }
-                    drop(accounts);
-                    self.save_accounts();
+                    let content = content.clone();
+                    self.update_controlled(username, |a| a.job_id = content);
                 }
             }
             "Echo" => {
@@ -265,11 +298,6 @@ impl NexusServer {
                 };
                 let content = payload.get("Content").cloned().unwrap_or_default();
 
-                let mut elements = self.custom_elements.lock().unwrap();
-                if elements.iter().any(|e| e.name == name) {
-                    return;
-                }
-
                 let size = payload.get("Size").and_then(|s| {
                     let parts: Vec<i32> = s.split(',').filter_map(|p| p.trim().parse().ok()).collect();
                     if parts.len() == 2 {
@@ -293,56 +321,59 @@ impl NexusServer {
                     .and_then(|s| s.parse().ok());
                 let increment = payload.get("Increment").cloned();
 
-                let element = CustomElement {
-                    name: name.clone(),
-                    element_type: command.name.replace("Create", ""),
-                    content: content.clone(),
-                    size,
-                    margin,
-                    decimal_places,
-                    increment,
-                    value: content,
-                    is_newline: false,
-                };
-
-                elements.push(element.clone());
-                drop(elements);
-
-                if let Some(app) = app {
-                    let _ = app.emit(
-                        "nexus-element-created",
-                        serde_json::json!({
-                            "name": name,
-                            "elementType": element.element_type,
-                            "content": element.content,
-                        }),
-                    );
-                }
+                self.add_element(
+                    username,
+                    CustomElement {
+                        name,
+                        script: payload.get("Script").cloned().unwrap_or_default(),
+                        owners: Vec::new(),
+                        element_type: command.name.replace("Create", ""),
+                        content: content.clone(),
+                        size,
+                        margin,
+                        decimal_places,
+                        increment,
+                        value: content,
+                        is_newline: false,
+                    },
+                    app,
+                );
             }
             "NewLine" => {
-                let mut elements = self.custom_elements.lock().unwrap();
-                let idx = elements.len();
-                elements.push(CustomElement {
-                    name: format!("__newline_{}", idx),
-                    element_type: "NewLine".to_string(),
-                    content: String::new(),
-                    size: None,
-                    margin: None,
-                    decimal_places: None,
-                    increment: None,
-                    value: String::new(),
-                    is_newline: true,
-                });
-                drop(elements);
-
-                if let Some(app) = app {
-                    let _ = app.emit("nexus-element-newline", serde_json::json!({}));
+                let script = payload.get("Script").cloned().unwrap_or_default();
+                self.add_newline(username, &script, app);
+            }
+            "RemoveElement" => {
+                if let Some(name) = payload.get("Name") {
+                    let script = payload.get("Script").cloned().unwrap_or_default();
+                    self.remove_element(&script, name, app);
                 }
             }
+            "ClearElements" => {
+                let script = payload.get("Script").map(|s| s.as_str());
+                self.clear_elements(Some(username), script, app);
+            }
             _ => {}
         }
     }
 
+    fn touch_ping(&self, username: &str) {
+        let mut accounts = self.accounts.lock().unwrap();
+        if let Some(account) = accounts.iter_mut().find(|a| a.username == username) {
+            account.last_ping = Some(Instant::now());
+            account.client_can_receive = true;
+        }
+    }
+
+    fn update_controlled<F: FnOnce(&mut ControlledAccount)>(&self, username: &str, update: F) {
+        let mut accounts = self.accounts.lock().unwrap();
+        if let Some(account) = accounts.iter_mut().find(|a| a.username == username) {
+            update(account);
+        }
+        drop(accounts);
+        self.save_accounts();
+    }
+
     pub async fn start(&self, port: u16, allow_external: bool, app: tauri::AppHandle) -> Result<u16, String> {
         if self.is_running() {
             return Err("Nexus server is already running".into());
@@ -414,6 +445,8 @@ impl NexusServer {
         for account in accounts.iter_mut() {
             account.status = AccountStatus::Offline;
             account.client_can_receive = false;
+            account.disconnected_at = None;
+            account.latency_ms = None;
         }
     }
 }
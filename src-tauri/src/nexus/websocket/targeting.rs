@@ -0,0 +1,104 @@
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum NexusTarget {
+    Checked,
+    All,
+    Accounts { usernames: Vec<String> },
+    #[serde(rename_all = "camelCase")]
+    Server { job_id: String },
+    #[serde(rename_all = "camelCase")]
+    Place { place_id: i64 },
+    BroadcastGroup { name: String },
+}
+
+impl NexusServer {
+    pub fn resolve_target(&self, target: &NexusTarget) -> Vec<String> {
+        let accounts = self.accounts.lock().unwrap();
+        accounts
+            .iter()
+            .filter(|a| match target {
+                NexusTarget::Checked => a.is_checked,
+                NexusTarget::All => true,
+                NexusTarget::Accounts { usernames } => {
+                    usernames.iter().any(|u| u.eq_ignore_ascii_case(&a.username))
+                }
+                NexusTarget::Server { job_id } => {
+                    !job_id.is_empty() && a.in_game_job_id.eq_ignore_ascii_case(job_id)
+                }
+                NexusTarget::Place { place_id } => {
+                    let current = if a.in_game_place_id > 0 {
+                        a.in_game_place_id
+                    } else {
+                        a.place_id
+                    };
+                    current == *place_id
+                }
+                NexusTarget::BroadcastGroup { name } => a.groups.iter().any(|g| g == name),
+            })
+            .map(|a| a.username.clone())
+            .collect()
+    }
+
+    pub fn send_to_target(&self, target: &NexusTarget, message: &str) -> usize {
+        let usernames = self.resolve_target(target);
+        let conns = self.connections.lock().unwrap();
+        let mut sent = 0;
+        for username in &usernames {
+            if let Some(conn) = conns.get(username) {
+                conn.send(message);
+                sent += 1;
+            }
+        }
+        sent
+    }
+
+    pub fn broadcast_groups(&self) -> BTreeMap<String, Vec<String>> {
+        let accounts = self.accounts.lock().unwrap();
+        let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for account in accounts.iter() {
+            for group in &account.groups {
+                groups
+                    .entry(group.clone())
+                    .or_default()
+                    .push(account.username.clone());
+            }
+        }
+        groups
+    }
+
+    pub fn set_broadcast_group(&self, name: &str, usernames: &[String]) -> Result<(), String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Group name cannot be empty".into());
+        }
+
+        let mut accounts = self.accounts.lock().unwrap();
+        for account in accounts.iter_mut() {
+            let member = usernames.iter().any(|u| u.eq_ignore_ascii_case(&account.username));
+            let present = account.groups.iter().any(|g| g == name);
+            if member && !present {
+                account.groups.push(name.to_string());
+            } else if !member && present {
+                account.groups.retain(|g| g != name);
+            }
+        }
+        drop(accounts);
+        self.save_accounts();
+        Ok(())
+    }
+
+    pub fn delete_broadcast_group(&self, name: &str) -> bool {
+        let mut accounts = self.accounts.lock().unwrap();
+        let mut removed = false;
+        for account in accounts.iter_mut() {
+            let before = account.groups.len();
+            account.groups.retain(|g| g != name);
+            removed |= account.groups.len() < before;
+        }
+        drop(accounts);
+        if removed {
+            self.save_accounts();
+        }
+        removed
+    }
+}
@@ -15,3 +15,4 @@ include!("macos/launch.rs");
 include!("macos/client_settings.rs");
 include!("macos/logs.rs");
 include!("macos/tracker.rs");
+include!("macos/session.rs");
@@ -0,0 +1,49 @@
+mod session_ffi {
+    use std::ffi::c_void;
+    use std::os::raw::c_char;
+
+    pub const CF_STRING_ENCODING_UTF8: u32 = 0x0800_0100;
+
+    #[link(name = "CoreGraphics", kind = "framework")]
+    unsafe extern "C" {
+        pub fn CGSessionCopyCurrentDictionary() -> *const c_void;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    unsafe extern "C" {
+        pub fn CFStringCreateWithCString(
+            alloc: *const c_void,
+            c_str: *const c_char,
+            encoding: u32,
+        ) -> *const c_void;
+        pub fn CFDictionaryGetValue(dict: *const c_void, key: *const c_void) -> *const c_void;
+        pub fn CFBooleanGetValue(boolean: *const c_void) -> u8;
+        pub fn CFRelease(cf: *const c_void);
+    }
+}
+
+/// Reports whether the login session is showing the lock screen.
+pub fn is_session_locked() -> bool {
+    use session_ffi::*;
+
+    unsafe {
+        let session = CGSessionCopyCurrentDictionary();
+        if session.is_null() {
+            return false;
+        }
+        let key = CFStringCreateWithCString(
+            std::ptr::null(),
+            c"CGSSessionScreenIsLocked".as_ptr(),
+            CF_STRING_ENCODING_UTF8,
+        );
+        let locked = !key.is_null() && {
+            let value = CFDictionaryGetValue(session, key);
+            !value.is_null() && CFBooleanGetValue(value) != 0
+        };
+        if !key.is_null() {
+            CFRelease(key);
+        }
+        CFRelease(session);
+        locked
+    }
+}
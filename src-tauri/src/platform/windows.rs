@@ -38,3 +38,4 @@ include!("windows/launch.rs");
 include!("windows/client_settings.rs");
 include!("windows/windowing.rs");
 include!("windows/tracker.rs");
+include!("windows/session.rs");
@@ -0,0 +1,18 @@
+/// Reports whether the workstation is locked. The input desktop cannot be
+/// opened while the lock screen owns it, but the same holds for the UAC
+/// secure desktop and any other failure, so a single `true` is only a hint.
+/// Auto-lock waits for consecutive positive checks before acting on it.
+pub fn is_session_locked() -> bool {
+    use windows_sys::Win32::System::StationsAndDesktops::{
+        CloseDesktop, OpenInputDesktop, DESKTOP_SWITCHDESKTOP,
+    };
+
+    unsafe {
+        let desktop = OpenInputDesktop(0, 0, DESKTOP_SWITCHDESKTOP);
+        if desktop.is_null() {
+            return true;
+        }
+        CloseDesktop(desktop);
+        false
+    }
+}
@@ -0,0 +1,78 @@
+#[path = "../src/data/crypto.rs"]
+mod crypto;
+
+use crypto::{ContainerFormat, KdfParams};
+
+const CONTENT: &str = r#"[{"UserID":1,"Username":"probe"}]"#;
+
+fn interactive() -> ContainerFormat {
+    ContainerFormat::Versioned(KdfParams::from_method("interactive").unwrap())
+}
+
+#[test]
+fn versioned_container_round_trips_and_reports_its_parameters() {
+    assert!(crypto::init());
+    let hash = crypto::hash_password("correct horse");
+
+    let encrypted = crypto::encrypt(CONTENT, &hash, interactive()).unwrap();
+    assert!(crypto::is_encrypted(&encrypted));
+    assert_eq!(crypto::inspect(&encrypted).unwrap(), interactive());
+
+    let decrypted = crypto::decrypt(&encrypted, &hash).unwrap();
+    assert_eq!(decrypted, CONTENT.as_bytes());
+
+    let wrong = crypto::hash_password("wrong horse");
+    assert!(crypto::decrypt(&encrypted, &wrong).is_err());
+}
+
+#[test]
+fn legacy_container_stays_readable() {
+    assert!(crypto::init());
+    let hash = crypto::hash_password("correct horse");
+
+    let encrypted = crypto::encrypt(CONTENT, &hash, ContainerFormat::Legacy).unwrap();
+    assert_eq!(
+        crypto::inspect(&encrypted).unwrap(),
+        ContainerFormat::Legacy
+    );
+    assert_eq!(
+        crypto::decrypt(&encrypted, &hash).unwrap(),
+        CONTENT.as_bytes()
+    );
+}
+
+#[test]
+fn versioned_container_rejects_unbounded_kdf_limits() {
+    assert!(crypto::init());
+    let hash = crypto::hash_password("correct horse");
+    let mut encrypted = crypto::encrypt(CONTENT, &hash, interactive()).unwrap();
+
+    // The memory limit sits after the header, magic, version, KDF id and ops limit.
+    let mem_offset = crypto::RAM_HEADER.len() + 8 + 1 + 1 + 8;
+    encrypted[mem_offset..mem_offset + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+
+    assert!(crypto::inspect(&encrypted).is_err());
+    assert!(crypto::decrypt(&encrypted, &hash).is_err());
+}
+
+#[test]
+fn field_cipher_round_trips_and_rejects_the_wrong_key() {
+    assert!(crypto::init());
+    let salt = crypto::gen_field_salt();
+    let params = KdfParams::from_method("interactive").unwrap();
+    let cipher =
+        crypto::FieldCipher::derive(&crypto::hash_password("correct horse"), &salt, params)
+            .unwrap();
+
+    let sealed = cipher.seal("_|WARNING:-DO-NOT-SHARE-THIS.--cookie");
+    assert!(sealed.starts_with("enc:"));
+    assert_eq!(
+        cipher.open(&sealed).unwrap(),
+        "_|WARNING:-DO-NOT-SHARE-THIS.--cookie"
+    );
+    assert_eq!(cipher.seal(""), "");
+
+    let wrong =
+        crypto::FieldCipher::derive(&crypto::hash_password("wrong horse"), &salt, params).unwrap();
+    assert!(wrong.open(&sealed).is_err());
+}
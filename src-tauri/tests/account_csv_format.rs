@@ -0,0 +1,31 @@
+#[path = "../src/data/csv.rs"]
+mod csv;
+
+#[test]
+fn csv_round_trips_quoted_fields_and_reports_lines() {
+    let fields = [
+        "probe",
+        "pa,ss \"word\"",
+        "_|WARNING:-DO-NOT-SHARE\nline",
+        " padded",
+    ];
+    let mut out = String::new();
+    csv::write_record(&mut out, &["username", "password", "cookie", "note"]);
+    csv::write_record(&mut out, &fields);
+
+    let records = csv::parse(&format!("\u{feff}{}\r\n", out)).unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].0, 1);
+    assert_eq!(records[1].0, 2);
+    assert_eq!(records[1].1, fields);
+}
+
+#[test]
+fn csv_rejects_stray_and_unterminated_quotes() {
+    assert!(csv::parse("a,b\"c\n").is_err());
+    assert!(csv::parse("a,\"b\"c\n").is_err());
+    assert_eq!(
+        csv::parse("a\n\"b,\nc").unwrap_err(),
+        "Line 2: unterminated quote"
+    );
+}
@@ -0,0 +1,99 @@
+#[path = "../src/data/account_query.rs"]
+mod account_query;
+
+use account_query::{AccountQuery, QuerySubject};
+use std::collections::HashMap;
+
+fn subject<'a>(tags: &'a [String], fields: &'a HashMap<String, String>) -> QuerySubject<'a> {
+    QuerySubject {
+        user_id: 42,
+        username: "FarmBot01",
+        alias: "",
+        group: "Farms",
+        vault: "",
+        tags,
+        fields,
+        valid: true,
+        has_password: false,
+        last_use_age: 2 * 86400,
+        robux: Some(250),
+    }
+}
+
+#[test]
+fn queries_combine_tags_fields_and_comparisons() {
+    let tags = vec!["Farm".to_string()];
+    let fields = HashMap::from([("Server".to_string(), "EU".to_string())]);
+    let account = subject(&tags, &fields);
+
+    for query in [
+        "tag:farm and robux>100 and last_use<7d and valid",
+        "field:Server=eu",
+        "tag:farm field:Server",
+        "not has:password",
+        "(tag:alt or group:farms) and id=42",
+        "name:bot and not robux>=1000",
+    ] {
+        assert!(
+            AccountQuery::parse(query).unwrap().matches(&account),
+            "{}",
+            query
+        );
+    }
+    for query in [
+        "tag:alt",
+        "field:Server=US",
+        "last_use<1d",
+        "robux<100 or not valid",
+    ] {
+        assert!(
+            !AccountQuery::parse(query).unwrap().matches(&account),
+            "{}",
+            query
+        );
+    }
+
+    let unknown = QuerySubject {
+        robux: None,
+        ..account
+    };
+    assert!(!AccountQuery::parse("robux>0").unwrap().matches(&unknown));
+}
+
+#[test]
+fn malformed_queries_are_rejected() {
+    for query in [
+        "",
+        "tag:",
+        "robux>lots",
+        "last_use<7y",
+        "(tag:farm",
+        "tag:farm)",
+        "and valid",
+        "colour=red",
+    ] {
+        assert!(AccountQuery::parse(query).is_err(), "{}", query);
+    }
+}
+
+#[test]
+fn deep_and_long_queries_do_not_overflow() {
+    let tags = vec!["Farm".to_string()];
+    let fields = HashMap::new();
+    let account = subject(&tags, &fields);
+
+    let deep = format!("{}tag:farm{}", "(".repeat(5000), ")".repeat(5000));
+    assert_eq!(
+        AccountQuery::parse(&deep).unwrap_err(),
+        "Query is nested too deeply"
+    );
+    let allowed = format!("{}tag:farm{}", "(".repeat(64), ")".repeat(64));
+    assert!(AccountQuery::parse(&allowed).unwrap().matches(&account));
+
+    let nots = format!("{}tag:farm", "not ".repeat(100_001));
+    assert!(!AccountQuery::parse(&nots).unwrap().matches(&account));
+    let chain = vec!["tag:farm"; 100_000].join(" and ");
+    assert!(AccountQuery::parse(&chain).unwrap().matches(&account));
+    let chain = vec!["tag:alt"; 100_000].join(" or ");
+    assert!(!AccountQuery::parse(&chain).unwrap().matches(&account));
+}
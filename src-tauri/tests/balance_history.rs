@@ -0,0 +1,83 @@
+#[path = "../src/data/balance_history.rs"]
+mod balance_history;
+#[path = "../src/data/csv.rs"]
+mod csv;
+
+use balance_history::{BalanceSample, DropThreshold};
+use chrono::{DateTime, Duration, Utc};
+
+fn sample(at: DateTime<Utc>, user_id: i64, robux: i64, limiteds: Option<i64>) -> BalanceSample {
+    BalanceSample {
+        at,
+        user_id,
+        robux,
+        premium: Some(true),
+        limiteds,
+    }
+}
+
+#[test]
+fn history_round_trips_and_skips_damaged_rows() {
+    let at = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let samples = vec![
+        sample(at, 1, 1200, Some(3)),
+        BalanceSample {
+            premium: None,
+            limiteds: None,
+            ..sample(at, 2, 0, None)
+        },
+    ];
+
+    let mut text = balance_history::write(&samples);
+    assert!(text.starts_with("at,user_id,robux,premium,limiteds\r\n"));
+    assert_eq!(balance_history::parse(&text).unwrap(), samples);
+
+    text.push_str("2023-11-14T22:13:20Z,3,45");
+    assert_eq!(balance_history::parse(&text).unwrap(), samples);
+}
+
+#[test]
+fn totals_group_snapshots_and_drops_meet_thresholds() {
+    let first = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let second = first + Duration::hours(6);
+    let samples = vec![
+        sample(first, 1, 5000, Some(4)),
+        sample(first, 2, 800, Some(1)),
+        sample(first, 3, 100, None),
+        sample(second, 1, 1000, Some(4)),
+        sample(second, 2, 790, Some(0)),
+        sample(second, 3, 0, None),
+    ];
+
+    let totals = balance_history::totals(&samples);
+    assert_eq!(totals.len(), 2);
+    assert_eq!(
+        (
+            totals[0].accounts,
+            totals[0].robux,
+            totals[0].limiteds,
+            totals[0].premium
+        ),
+        (3, 5900, 5, 3)
+    );
+    assert_eq!(totals[1].robux, 1790);
+
+    let threshold = DropThreshold {
+        min_robux: 500,
+        min_percent: 0.0,
+        min_limiteds: 1,
+    };
+    let drops = balance_history::find_drops(&samples, &threshold);
+    let flagged: Vec<(i64, i64, i64)> = drops
+        .iter()
+        .map(|d| (d.user_id, d.robux_lost, d.limiteds_lost))
+        .collect();
+    assert_eq!(flagged, vec![(1, 4000, 0), (2, 10, 1)]);
+
+    let strict = DropThreshold {
+        min_percent: 90.0,
+        min_limiteds: 0,
+        ..threshold
+    };
+    assert!(balance_history::find_drops(&samples, &strict).is_empty());
+}
@@ -1,3 +1,6 @@
+#[path = "../src/data/atomic.rs"]
+mod atomic;
+
 #[path = "../src/data/scripts.rs"]
 mod scripts;
 
@@ -0,0 +1,39 @@
+#[path = "../src/data/keychain.rs"]
+mod keychain;
+
+use keychain::{FileBackend, SecretBackend};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_key_dir() -> PathBuf {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!("ram-unlock-key-probe-{}", now));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn file_backend_stores_loads_and_deletes_keys() {
+    let dir = temp_key_dir();
+    let backend = FileBackend::new(dir.clone());
+    let account = "/data/AccountData.json";
+    let key = vec![0u8, 1, 2, 0xfe, 0xff];
+
+    assert_eq!(backend.load(account).unwrap(), None);
+    backend.store(account, &key).unwrap();
+    assert_eq!(backend.load(account).unwrap(), Some(key.clone()));
+    assert_eq!(backend.load("/data/Other.json").unwrap(), None);
+
+    backend.store(account, &[9, 9]).unwrap();
+    assert_eq!(backend.load(account).unwrap(), Some(vec![9, 9]));
+
+    backend.delete(account).unwrap();
+    assert_eq!(backend.load(account).unwrap(), None);
+    backend.delete(account).unwrap();
+
+    let _ = fs::remove_dir_all(dir);
+}
@@ -2,6 +2,7 @@ import { useEffect, useRef } from "react";
 import { StoreProvider, useStore } from "./store";
 import { PromptProvider } from "./hooks/usePrompt";
 import { PasswordScreen } from "./components/layout/PasswordScreen";
+import { AccountRecoveryPrompt } from "./components/layout/AccountRecoveryPrompt";
 import { EncryptionSetupScreen } from "./components/layout/EncryptionSetupScreen";
 import { FirstRunWalkthrough } from "./components/layout/FirstRunWalkthrough";
 import { TitleBar } from "./components/layout/TitleBar";
@@ -65,7 +66,12 @@ function AppContent() {
   }
 
   if (store.needsPassword) {
-    return <PasswordScreen />;
+    return (
+      <>
+        <AccountRecoveryPrompt />
+        <PasswordScreen />
+      </>
+    );
   }
 
   if (store.encryptionSetupOpen) {
@@ -112,6 +118,8 @@ function AppContent() {
 
       <ContextMenu />
 
+      <AccountRecoveryPrompt />
+
       {store.toasts.length > 0 && (
         <div className="fixed bottom-10 right-4 z-[60] flex flex-col gap-1.5">
           {store.toasts.map((msg, i) => (
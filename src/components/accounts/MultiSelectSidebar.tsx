@@ -52,9 +52,10 @@ export function MultiSelectSidebar() {
 
   async function handleJoin() {
     const ids = accounts.map((a) => a.UserID);
-    if (!(await confirmJoinOnline(ids))) return;
+    const decision = await confirmJoinOnline(ids);
+    if (!decision) return;
     try {
-      await store.launchMultiple(ids);
+      await store.launchMultiple(ids, decision === "confirmed");
     } catch (e) {
       store.addToast(tr("Launch failed: {{error}}", { error: String(e) }));
     }
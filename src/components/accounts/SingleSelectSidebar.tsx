@@ -31,6 +31,7 @@ export function SingleSelectSidebar() {
   const [followUser, setFollowUser] = useState("");
   const [argsOpen, setArgsOpen] = useState(false);
   const [recentGames, setRecentGames] = useState<RecentGame[]>([]);
+  const [lastInGameMs, setLastInGameMs] = useState<number | null>(null);
   const argsRef = useRef<HTMLButtonElement>(null);
 
   useEffect(() => {
@@ -51,6 +52,13 @@ export function SingleSelectSidebar() {
     setRecentGames(loadRecentGames().slice(0, 12));
   }, [account.UserID]);
 
+  const presenceForAccount = store.presenceByUserId.get(account.UserID);
+  useEffect(() => {
+    invoke<{ userId: number; lastInGameMs: number | null }[]>("get_cached_presence")
+      .then((snapshots) => setLastInGameMs(snapshots.find((s) => s.userId === account.UserID)?.lastInGameMs ?? null))
+      .catch(() => setLastInGameMs(null));
+  }, [account.UserID, presenceForAccount]);
+
   function handleSetAlias() {
     store.updateAccount({ ...account, Alias: alias.slice(0, 30) });
     store.addToast(tr("Alias updated"));
@@ -68,8 +76,9 @@ export function SingleSelectSidebar() {
   }
 
   async function handleJoin() {
-    if (!(await confirmJoinOnline([account.UserID]))) return;
-    await store.joinServer(account.UserID);
+    const decision = await confirmJoinOnline([account.UserID]);
+    if (!decision) return;
+    await store.joinServer(account.UserID, decision === "confirmed");
   }
 
   async function handleFollow() {
@@ -83,7 +92,7 @@ export function SingleSelectSidebar() {
       if (followPresenceType < 2) {
         if (!(await confirm(tr("{{name}} is not in a game. Try anyway?", { name: followUser })))) return;
       }
-      await invoke("launch_roblox", {
+      const result = await invoke<{ launched: boolean; error?: string }>("launch_roblox", {
         userId: account.UserID,
         placeId: user.id,
         jobId: "",
@@ -93,6 +102,10 @@ export function SingleSelectSidebar() {
         linkCode: "",
         shuffleJob: false,
       });
+      if (!result.launched) {
+        store.addToast(tr("Follow failed: {{error}}", { error: result.error || "" }));
+        return;
+      }
       store.addToast(tr("Following {{name}}...", { name: followUser }));
     } catch (e) {
       store.addToast(tr("Follow failed: {{error}}", { error: String(e) }));
@@ -157,7 +170,10 @@ export function SingleSelectSidebar() {
                 {displayName}
               </div>
               {store.settings?.General?.ShowPresence === "true" && (
-                <div className={`inline-flex items-center gap-1 text-[10px] ${presenceMeta.text}`}>
+                <div
+                  className={`inline-flex items-center gap-1 text-[10px] ${presenceMeta.text}`}
+                  title={lastInGameMs ? t("Last in game: {{time}}", { time: new Date(lastInGameMs).toLocaleString() }) : undefined}
+                >
                   <span
                     className={`w-1.5 h-1.5 rounded-full ${presenceMeta.dot} ${presenceType >= 1 ? "animate-pulse" : ""}`}
                     style={presenceMeta.dotStyle}
@@ -5,9 +5,10 @@ import { useStore } from "../../store";
 import { useModalClose } from "../../hooks/useModalClose";
 import { usePrompt } from "../../hooks/usePrompt";
 import { SlidingTabBar } from "../ui/SlidingTabBar";
+import { Select } from "../ui/Select";
 import { useTr } from "../../i18n/text";
 
-type TabId = "cookie" | "legacy";
+type TabId = "cookie" | "csv" | "legacy" | "bundle";
 
 interface ImportResult {
   text: string;
@@ -21,6 +22,26 @@ interface OldAccountImportSummary {
   skipped: number;
 }
 
+interface AccountImportRowResult {
+  line: number;
+  username: string;
+  userId: number | null;
+  status: "ready" | "added" | "exists" | "failed";
+  message: string;
+}
+
+interface AccountImportReport {
+  dryRun: boolean;
+  added: number;
+  existing: number;
+  failed: number;
+  rows: AccountImportRowResult[];
+}
+
+interface BundleImportSummary extends OldAccountImportSummary {
+  nexus: number;
+}
+
 export function ImportDialog({
   open,
   onClose,
@@ -40,6 +61,7 @@ export function ImportDialog({
   const [progress, setProgress] = useState("");
   const [results, setResults] = useState<ImportResult[]>([]);
   const [selectedFileName, setSelectedFileName] = useState("");
+  const [strategy, setStrategy] = useState("skip");
   const textareaRef = useRef<HTMLTextAreaElement>(null);
   const fileInputRef = useRef<HTMLInputElement>(null);
 
@@ -58,47 +80,59 @@ export function ImportDialog({
   }, [open, handleClose, defaultTab]);
 
   useEffect(() => {
-    if (open && tab === "cookie" && textareaRef.current) {
+    if (open && (tab === "cookie" || tab === "csv") && textareaRef.current) {
       setTimeout(() => textareaRef.current?.focus(), 100);
     }
   }, [open, tab]);
 
   if (!visible) return null;
 
-  async function handleImportCookie() {
-    const lines = input.split("\n").map((l) => l.trim()).filter(Boolean);
-    if (lines.length === 0) return;
+  async function handleImportText(dryRun: boolean) {
+    if (!input.trim()) return;
     setImporting(true);
     setResults([]);
+    setProgress(dryRun ? t("Validating cookies...") : t("Importing..."));
 
-    const existingIds = new Set(store.accounts.map((a) => a.UserID));
-    const out: ImportResult[] = [];
-
-    for (let i = 0; i < lines.length; i++) {
-      setProgress(t("Importing {{current}}/{{total}}...", { current: i + 1, total: lines.length }));
-      const cookie = lines[i];
-      try {
-        const info = await invoke<{ user_id: number; name: string }>("validate_cookie", { cookie });
-        if (existingIds.has(info.user_id)) {
-          out.push({ text: t("{{name}} - already exists", { name: info.name }), ok: false });
-        } else {
-          await invoke("add_account", {
-            securityToken: cookie,
-            username: info.name,
-            userId: info.user_id,
-          });
-          existingIds.add(info.user_id);
-          out.push({ text: t("Added {{name}}", { name: info.name }), ok: true });
+    try {
+      const report = await invoke<AccountImportReport>("import_accounts_text", {
+        text: input,
+        format: tab === "csv" ? "csv" : "list",
+        dryRun,
+      });
+      const out: ImportResult[] = report.rows.map((row) => {
+        const name = row.username || t("Line {{line}}", { line: row.line });
+        switch (row.status) {
+          case "ready":
+            return { text: t("{{name}} - ready to import", { name }), ok: true };
+          case "added":
+            return { text: t("Added {{name}}", { name }), ok: true };
+          case "exists":
+            return { text: t("{{name}} - already exists", { name }), ok: false };
+          default:
+            return { text: t("Line {{line}}: {{error}}", { line: row.line, error: row.message }), ok: false };
         }
-      } catch (e) {
-        out.push({ text: t("Failed: {{error}}", { error: String(e) }), ok: false });
+      });
+      if (out.length === 0) out.push({ text: t("No accounts imported"), ok: false });
+      setResults(out);
+      if (!dryRun) {
+        await store.loadAccounts();
+        store.addToast(t("Import complete: +{{added}}, {{failed}} failed", { added: report.added, failed: report.failed }));
       }
-      setResults([...out]);
+    } catch (e) {
+      setResults([{ text: t("Failed: {{error}}", { error: String(e) }), ok: false }]);
+    } finally {
+      setProgress("");
+      setImporting(false);
     }
+  }
 
-    await store.loadAccounts();
-    setProgress("");
-    setImporting(false);
+  async function handleCsvFileChange(e: React.ChangeEvent<HTMLInputElement>) {
+    const file = e.target.files?.[0];
+    e.target.value = "";
+    if (!file) return;
+    setSelectedFileName(file.name);
+    setResults([]);
+    setInput(await file.text());
   }
 
   async function importOldAccountData(file: File) {
@@ -175,23 +209,87 @@ export function ImportDialog({
     }
   }
 
+  async function importBundle(file: File) {
+    setImporting(true);
+    setResults([]);
+    setSelectedFileName(file.name);
+
+    try {
+      setProgress(t("Reading file..."));
+      const raw = await file.arrayBuffer();
+      const fileData = Array.from(new Uint8Array(raw));
+      let password = await prompt(t("Enter the bundle password:"));
+      let summary: BundleImportSummary | null = null;
+
+      while (summary === null) {
+        if (password === null) {
+          setResults([{ text: t("Import cancelled"), ok: false }]);
+          return;
+        }
+        try {
+          setProgress(t("Decrypting and importing..."));
+          summary = await invoke<BundleImportSummary>("import_accounts", {
+            fileData,
+            password,
+            strategy,
+          });
+        } catch (e) {
+          if (String(e).includes("Bundle password is incorrect")) {
+            password = await prompt(t("Password is incorrect. Enter the bundle password:"));
+            continue;
+          }
+          throw e;
+        }
+      }
+
+      await store.loadAccounts();
+
+      const out: ImportResult[] = [];
+      if (summary.added > 0) {
+        out.push({ text: t("Added {{count}} account(s)", { count: summary.added }), ok: true });
+      }
+      if (summary.replaced > 0) {
+        out.push({ text: t("Replaced {{count}} existing account(s)", { count: summary.replaced }), ok: true });
+      }
+      if (summary.nexus > 0) {
+        out.push({ text: t("Applied Nexus settings for {{count}} account(s)", { count: summary.nexus }), ok: true });
+      }
+      if (summary.skipped > 0) {
+        out.push({ text: t("Skipped {{count}} existing/invalid record(s)", { count: summary.skipped }), ok: false });
+      }
+      if (summary.total === 0 || out.length === 0) {
+        out.push({ text: t("No accounts imported"), ok: false });
+      }
+
+      setResults(out);
+      store.addToast(t("Import complete: +{{added}}, replaced {{replaced}}", { added: summary.added, replaced: summary.replaced }));
+    } catch (e) {
+      setResults([{ text: t("Failed: {{error}}", { error: String(e) }), ok: false }]);
+    } finally {
+      setProgress("");
+      setImporting(false);
+    }
+  }
+
   async function handleLegacyFileChange(e: React.ChangeEvent<HTMLInputElement>) {
     const file = e.target.files?.[0];
     e.target.value = "";
     if (!file) return;
-    await importOldAccountData(file);
+    if (tab === "bundle") await importBundle(file);
+    else await importOldAccountData(file);
   }
 
   async function handleLegacyFileDrop(e: React.DragEvent<HTMLButtonElement>) {
     e.preventDefault();
     const file = e.dataTransfer.files?.[0];
     if (!file) return;
-    await importOldAccountData(file);
+    if (tab === "bundle") await importBundle(file);
+    else await importOldAccountData(file);
   }
 
   function handleDrop(e: React.DragEvent) {
     e.preventDefault();
-    if (tab !== "cookie") return;
+    if (tab !== "cookie" && tab !== "csv") return;
     const text = e.dataTransfer.getData("text/plain");
     if (text) setInput((prev) => (prev ? `${prev}\n${text}` : text));
   }
@@ -204,9 +302,13 @@ export function ImportDialog({
     setSelectedFileName("");
   }
 
+  const textTab = tab === "cookie" || tab === "csv";
+
   const tabs: { id: TabId; label: string }[] = [
     { id: "cookie", label: t("Import by Cookie") },
+    { id: "csv", label: t("Import CSV") },
     { id: "legacy", label: t("Import Old Account Data") },
+    { id: "bundle", label: t("Import Bundle") },
   ];
 
   return (
@@ -215,7 +317,7 @@ export function ImportDialog({
       onClick={handleClose}
     >
       <div
-        className={`theme-modal-scope theme-panel theme-border bg-zinc-900 border border-zinc-800/80 rounded-2xl shadow-2xl w-[560px] ${tab === "cookie" ? "max-h-[420px]" : "max-h-[320px]"} flex flex-col overflow-hidden ${closing ? "animate-scale-out" : "animate-scale-in"}`}
+        className={`theme-modal-scope theme-panel theme-border bg-zinc-900 border border-zinc-800/80 rounded-2xl shadow-2xl w-[560px] ${textTab ? "max-h-[420px]" : "max-h-[320px]"} flex flex-col overflow-hidden ${closing ? "animate-scale-out" : "animate-scale-in"}`}
         onClick={(e) => e.stopPropagation()}
       >
         <div className="flex items-center justify-between px-5 pt-4 pb-0">
@@ -233,10 +335,33 @@ export function ImportDialog({
           />
         </div>
 
-        <div className={`px-5 pb-4 ${tab === "cookie" ? "flex-1 flex flex-col min-h-0" : "pt-2"}`}>
-          {tab === "cookie" ? (
+        <div className={`px-5 pb-4 ${textTab ? "flex-1 flex flex-col min-h-0" : "pt-2"}`}>
+          {textTab ? (
             <>
-              <p className="text-[11px] text-zinc-500 mb-2">{t("Paste one .ROBLOSECURITY cookie per line")}</p>
+              {tab === "cookie" ? (
+                <p className="text-[11px] text-zinc-500 mb-2">{t("Paste one .ROBLOSECURITY cookie or user:pass:cookie per line")}</p>
+              ) : (
+                <div className="flex items-center justify-between mb-2">
+                  <p className="text-[11px] text-zinc-500">
+                    {t("Columns: username, password, cookie, group, alias, description; others become fields")}
+                  </p>
+                  <input
+                    ref={fileInputRef}
+                    type="file"
+                    accept=".csv,text/csv"
+                    className="hidden"
+                    onChange={handleCsvFileChange}
+                  />
+                  <button
+                    type="button"
+                    onClick={() => fileInputRef.current?.click()}
+                    disabled={importing}
+                    className="shrink-0 ml-3 text-[11px] text-sky-400 hover:text-sky-300 disabled:opacity-50"
+                  >
+                    {selectedFileName || t("Open CSV...")}
+                  </button>
+                </div>
+              )}
               <textarea
                 ref={textareaRef}
                 value={input}
@@ -244,18 +369,33 @@ export function ImportDialog({
                 onDrop={handleDrop}
                 onDragOver={(e) => e.preventDefault()}
                 disabled={importing}
-                placeholder={t("_|WARNING:-DO-NOT-SHARE...")}
+                placeholder={tab === "csv" ? "username,password,cookie,group" : t("_|WARNING:-DO-NOT-SHARE...")}
                 className="flex-1 min-h-[100px] max-h-[140px] w-full p-3 bg-zinc-800/50 border border-zinc-700/50 rounded-lg text-xs text-zinc-300 font-mono placeholder-zinc-600 resize-none focus:outline-none focus:border-zinc-600 transition-colors disabled:opacity-50"
                 spellCheck={false}
               />
             </>
           ) : (
             <>
-              <p className="text-[11px] text-zinc-500 mb-3">{t("Select an old AccountData.json file to merge into your current accounts.")}</p>
+              {tab === "bundle" ? (
+                <div className="flex items-center justify-between gap-3 mb-3">
+                  <p className="text-[11px] text-zinc-500">{t("Accounts that already exist:")}</p>
+                  <Select
+                    value={strategy}
+                    options={[
+                      { value: "skip", label: t("Keep current") },
+                      { value: "replace", label: t("Replace") },
+                      { value: "newest", label: t("Keep most recently used") },
+                    ]}
+                    onChange={setStrategy}
+                  />
+                </div>
+              ) : (
+                <p className="text-[11px] text-zinc-500 mb-3">{t("Select an old AccountData.json file to merge into your current accounts.")}</p>
+              )}
               <input
                 ref={fileInputRef}
                 type="file"
-                accept=".json,application/json"
+                accept={tab === "bundle" ? ".rambundle" : ".json,application/json"}
                 className="hidden"
                 onChange={handleLegacyFileChange}
               />
@@ -272,7 +412,9 @@ export function ImportDialog({
                     <FileIcon size={14} strokeWidth={1.75} />
                   </div>
                   <div>
-                    <div className="text-xs text-zinc-200 font-medium">{t("Choose AccountData.json")}</div>
+                    <div className="text-xs text-zinc-200 font-medium">
+                      {tab === "bundle" ? t("Choose account bundle") : t("Choose AccountData.json")}
+                    </div>
                     <div className="text-[11px] text-zinc-500">{t("Click to browse or drop file here")}</div>
                   </div>
                 </div>
@@ -295,16 +437,25 @@ export function ImportDialog({
             </div>
           )}
 
-          <div className={`flex items-center mt-3 ${tab === "cookie" ? "justify-between" : "justify-start"}`}>
+          <div className={`flex items-center mt-3 ${textTab ? "justify-between" : "justify-start"}`}>
             <span className="text-[11px] text-zinc-500">{progress}</span>
-            {tab === "cookie" ? (
-              <button
-                onClick={handleImportCookie}
-                disabled={importing || !input.trim()}
-                className="px-4 py-1.5 bg-sky-600 hover:bg-sky-500 disabled:bg-zinc-700 disabled:text-zinc-500 text-white text-xs font-medium rounded-lg transition-colors"
-              >
-                {importing ? t("Importing...") : t("Import")}
-              </button>
+            {textTab ? (
+              <div className="flex items-center gap-2">
+                <button
+                  onClick={() => handleImportText(true)}
+                  disabled={importing || !input.trim()}
+                  className="px-4 py-1.5 bg-zinc-800 hover:bg-zinc-700 disabled:text-zinc-500 text-zinc-200 text-xs font-medium rounded-lg transition-colors"
+                >
+                  {t("Preview")}
+                </button>
+                <button
+                  onClick={() => handleImportText(false)}
+                  disabled={importing || !input.trim()}
+                  className="px-4 py-1.5 bg-sky-600 hover:bg-sky-500 disabled:bg-zinc-700 disabled:text-zinc-500 text-white text-xs font-medium rounded-lg transition-colors"
+                >
+                  {importing ? t("Importing...") : t("Import")}
+                </button>
+              </div>
             ) : null}
           </div>
         </div>
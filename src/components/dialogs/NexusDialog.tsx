@@ -4,26 +4,47 @@ import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 import { useStore } from "../../store";
 import { useModalClose } from "../../hooks/useModalClose";
+import { usePrompt } from "../../hooks/usePrompt";
 import { SlidingTabBar } from "../ui/SlidingTabBar";
 import { Select } from "../ui/Select";
 import { NumericInput } from "../ui/NumericInput";
 import { tr, useTr } from "../../i18n/text";
-import { X, ChevronRight } from "lucide-react";
+import { X, ChevronRight, ChevronUp, ChevronDown } from "lucide-react";
+
+interface AutoExecuteEntry {
+  ScriptId: string;
+  Params: Record<string, string>;
+}
+
+interface LuaScript {
+  id: string;
+  name: string;
+  description: string;
+  source: string;
+  version: number;
+  updatedAtMs: number;
+}
 
 interface NexusAccount {
   username: string;
   auto_execute: string;
+  auto_execute_scripts?: AutoExecuteEntry[];
   place_id: number;
   job_id: string;
-  relaunch_delay: number;
+  relaunch_delay: number | null;
   auto_relaunch: boolean;
   is_checked: boolean;
   status: string;
   in_game_job_id: string;
+  has_secret?: boolean;
+  latency_ms?: number | null;
+  broadcast_groups?: string[];
 }
 
 interface NexusElement {
   name: string;
+  script: string;
+  owners: string[];
   element_type: string;
   content: string;
   size: [number, number] | null;
@@ -34,25 +55,43 @@ interface NexusElement {
   is_newline: boolean;
 }
 
+interface NexusLogEntry {
+  timestampMs: number;
+  username?: string;
+  level: "debug" | "info" | "warn" | "error";
+  message: string;
+  data?: unknown;
+}
+
+const LOG_LEVELS = ["debug", "info", "warn", "error"] as const;
+
 interface NexusStatus {
   running: boolean;
   port: number | null;
   connected_count: number;
 }
 
-type TabId = "control" | "settings" | "help";
+type TabId = "control" | "library" | "settings" | "help";
 
 export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => void }) {
   const store = useStore();
+  const prompt = usePrompt();
   const { visible, closing, handleClose } = useModalClose(open, onClose);
   const [tab, setTab] = useState<TabId>("control");
   const [status, setStatus] = useState<NexusStatus>({ running: false, port: null, connected_count: 0 });
   const [accounts, setAccounts] = useState<NexusAccount[]>([]);
   const [elements, setElements] = useState<NexusElement[]>([]);
-  const [log, setLog] = useState<string[]>([]);
+  const [log, setLog] = useState<NexusLogEntry[]>([]);
+  const [logUser, setLogUser] = useState("");
+  const [logLevel, setLogLevel] = useState<NexusLogEntry["level"]>("debug");
   const [selectedUsername, setSelectedUsername] = useState<string | null>(null);
   const [addInput, setAddInput] = useState("");
   const [commandInput, setCommandInput] = useState("");
+  const [sendTarget, setSendTarget] = useState("checked");
+  const [broadcastGroups, setBroadcastGroups] = useState<Record<string, string[]>>({});
+  const [libraryScripts, setLibraryScripts] = useState<LuaScript[]>([]);
+  const [sharedData, setSharedData] = useState<Record<string, Record<string, unknown>>>({});
+  const [dataOpen, setDataOpen] = useState(false);
   const [scriptText, setScriptText] = useState("");
   const [autoExecText, setAutoExecText] = useState("");
   const [placeInput, setPlaceInput] = useState("");
@@ -67,6 +106,7 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
   const [sAllowExternal, setSAllowExternal] = useState(false);
   const [sInternetCheck, setSInternetCheck] = useState(false);
   const [sUsePresence, setSUsePresence] = useState(false);
+  const [sRequireAuth, setSRequireAuth] = useState(false);
   const [sRelaunchDelay, setSRelaunchDelay] = useState(30);
   const [sLauncherDelay, setSLauncherDelay] = useState(9);
   const [sPort, setSPort] = useState(5242);
@@ -76,20 +116,32 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
   const [sAutoCloseInterval, setSAutoCloseInterval] = useState(30);
   const [sAutoCloseType, setSAutoCloseType] = useState(0);
   const [sMaxInstances, setSMaxInstances] = useState(10);
+  const [sHeartbeatTimeout, setSHeartbeatTimeout] = useState(30);
+  const [sKillStale, setSKillStale] = useState(false);
+  const [sPersistElements, setSPersistElements] = useState(false);
+  const [sPersistData, setSPersistData] = useState(false);
+  const [sLogCapacity, setSLogCapacity] = useState(1000);
+  const [sLogMaxFileSize, setSLogMaxFileSize] = useState(0);
   const [settingsLoaded, setSettingsLoaded] = useState(false);
 
   const refresh = useCallback(async () => {
     try {
-      const [s, a, e, l] = await Promise.all([
+      const [s, a, e, l, g, lib, d] = await Promise.all([
         invoke<NexusStatus>("get_nexus_status"),
         invoke<NexusAccount[]>("get_nexus_accounts"),
         invoke<NexusElement[]>("get_nexus_elements"),
-        invoke<string[]>("get_nexus_log"),
+        invoke<NexusLogEntry[]>("get_nexus_log", { filter: { limit: 500 } }),
+        invoke<Record<string, string[]>>("get_nexus_broadcast_groups"),
+        invoke<LuaScript[]>("get_lua_scripts"),
+        invoke<Record<string, Record<string, unknown>>>("get_nexus_data"),
       ]);
       setStatus(s);
       setAccounts(a);
       setElements(e);
       setLog(l);
+      setBroadcastGroups(g);
+      setLibraryScripts(lib);
+      setSharedData(d);
     } catch {}
   }, []);
 
@@ -103,6 +155,7 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
       setSAllowExternal(s.AccountControl.AllowExternalConnections === "true");
       setSInternetCheck(s.AccountControl.InternetCheck === "true");
       setSUsePresence(s.AccountControl.UsePresence === "true");
+      setSRequireAuth(s.AccountControl.RequireAuth === "true");
       setSRelaunchDelay(parseInt(s.AccountControl.RelaunchDelay) || 30);
       setSLauncherDelay(parseInt(s.AccountControl.LauncherDelay) || 9);
       setSPort(parseInt(s.AccountControl.NexusPort) || 5242);
@@ -112,6 +165,12 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
       setSAutoCloseInterval(parseInt(s.AccountControl.AutoCloseInterval) || 30);
       setSAutoCloseType(parseInt(s.AccountControl.AutoCloseType) || 0);
       setSMaxInstances(parseInt(s.AccountControl.MaxInstances) || 10);
+      setSHeartbeatTimeout(parseInt(s.AccountControl.HeartbeatTimeout) || 0);
+      setSKillStale(s.AccountControl.KillStaleClients === "true");
+      setSPersistElements(s.AccountControl.PersistElements === "true");
+      setSPersistData(s.AccountControl.PersistData === "true");
+      setSLogCapacity(parseInt(s.AccountControl.LogCapacity) || 1000);
+      setSLogMaxFileSize(parseInt(s.AccountControl.LogMaxFileSize) || 0);
     }
     setSettingsLoaded(true);
 
@@ -132,14 +191,16 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
     if (!open) return;
     const unlisteners: (() => void)[] = [];
 
-    listen<{ message: string }>("nexus-log", (e) => {
-      setLog((prev) => [...prev, e.payload.message]);
+    listen<NexusLogEntry>("nexus-log", (e) => {
+      setLog((prev) => [...prev.slice(-499), e.payload]);
     }).then((u) => unlisteners.push(u));
 
     listen("nexus-account-connected", () => refresh()).then((u) => unlisteners.push(u));
     listen("nexus-account-disconnected", () => refresh()).then((u) => unlisteners.push(u));
     listen("nexus-element-created", () => refresh()).then((u) => unlisteners.push(u));
     listen("nexus-element-newline", () => refresh()).then((u) => unlisteners.push(u));
+    listen("nexus-elements-changed", () => refresh()).then((u) => unlisteners.push(u));
+    listen("nexus-data-changed", () => refresh()).then((u) => unlisteners.push(u));
 
     return () => unlisteners.forEach((u) => u());
   }, [open, refresh]);
@@ -220,10 +281,22 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
     refresh();
   }
 
+  function buildTarget(): Record<string, unknown> {
+    if (sendTarget === "server") return { type: "server", jobId: selectedAcc?.in_game_job_id || "" };
+    if (sendTarget === "place") return { type: "place", placeId: selectedAcc?.place_id || 0 };
+    if (sendTarget.startsWith("bg:")) return { type: "broadcastGroup", name: sendTarget.slice(3) };
+    if (sendTarget.startsWith("group:")) return { type: "group", group: sendTarget.slice(6) };
+    return { type: sendTarget };
+  }
+
+  async function sendTargeted(message: string) {
+    await invoke<number>("nexus_send_targeted", { target: buildTarget(), message });
+  }
+
   async function handleSendCommand() {
     if (!commandInput.trim()) return;
     try {
-      await invoke("nexus_send_command", { message: commandInput });
+      await sendTargeted(commandInput);
       setCommandInput("");
     } catch (e) {
       store.addToast(tr("Error: {{error}}", { error: String(e) }));
@@ -233,12 +306,48 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
   async function handleExecuteScript() {
     if (!scriptText.trim()) return;
     try {
-      await invoke("nexus_send_command", { message: `execute ${scriptText}` });
+      await sendTargeted(`execute ${scriptText}`);
+    } catch (e) {
+      store.addToast(tr("Error: {{error}}", { error: String(e) }));
+    }
+  }
+
+  async function handleAddToBroadcastGroup(username: string) {
+    const name = (await prompt(tr("Broadcast group name:")))?.trim();
+    if (!name) return;
+    const members = broadcastGroups[name] || [];
+    try {
+      await invoke("set_nexus_broadcast_group", { name, usernames: [...members.filter((m) => m !== username), username] });
+      refresh();
+    } catch (e) {
+      store.addToast(tr("Error: {{error}}", { error: String(e) }));
+    }
+  }
+
+  async function handleLeaveBroadcastGroups(username: string) {
+    try {
+      for (const [name, members] of Object.entries(broadcastGroups)) {
+        if (!members.includes(username)) continue;
+        await invoke("set_nexus_broadcast_group", { name, usernames: members.filter((m) => m !== username) });
+      }
+      refresh();
     } catch (e) {
       store.addToast(tr("Error: {{error}}", { error: String(e) }));
     }
   }
 
+  const targetOptions = [
+    { value: "checked", label: tr("Checked") },
+    { value: "all", label: tr("All") },
+    { value: "server", label: tr("Same Server") },
+    { value: "place", label: tr("Same Place") },
+    ...Object.keys(broadcastGroups).map((name) => ({ value: `bg:${name}`, label: `# ${name}` })),
+    ...[...new Set(store.accounts.map((a) => a.Group || "Default"))].map((group) => ({
+      value: `group:${group}`,
+      label: group,
+    })),
+  ];
+
   async function handleAutoExecBlur() {
     if (!selectedUsername) return;
     const acc = accounts.find((a) => a.username === selectedUsername);
@@ -250,6 +359,50 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
     } catch {}
   }
 
+  async function handleAutoExecScriptsChange(entries: AutoExecuteEntry[]) {
+    if (!selectedUsername) return;
+    const acc = accounts.find((a) => a.username === selectedUsername);
+    if (!acc) return;
+    setAccounts((prev) => prev.map((a) => (a.username === acc.username ? { ...a, auto_execute_scripts: entries } : a)));
+    try {
+      await invoke("update_nexus_account", {
+        account: { ...acc, auto_execute: autoExecText, auto_execute_scripts: entries },
+      });
+    } catch (e) {
+      store.addToast(tr("Error: {{error}}", { error: String(e) }));
+    }
+  }
+
+  async function handleSaveLuaScript(script: { id: string; name: string; description: string; source: string }) {
+    try {
+      await invoke<LuaScript>("save_lua_script", script);
+      store.addToast(tr("Saved {{name}}", { name: script.name }));
+      refresh();
+      return true;
+    } catch (e) {
+      store.addToast(tr("Error: {{error}}", { error: String(e) }));
+      return false;
+    }
+  }
+
+  async function handleDeleteLuaScript(scriptId: string) {
+    try {
+      await invoke("delete_lua_script", { scriptId });
+      refresh();
+    } catch (e) {
+      store.addToast(tr("Error: {{error}}", { error: String(e) }));
+    }
+  }
+
+  async function handlePushLuaScript(scriptId: string) {
+    try {
+      const sent = await invoke<number>("push_nexus_script", { scriptId });
+      store.addToast(tr("Pushed to {{count}} clients", { count: sent }));
+    } catch (e) {
+      store.addToast(tr("Error: {{error}}", { error: String(e) }));
+    }
+  }
+
   async function handleFieldBlur(field: "place_id" | "job_id") {
     if (!selectedUsername) return;
     const acc = accounts.find((a) => a.username === selectedUsername);
@@ -275,18 +428,75 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
     } catch {}
   }
 
-  async function handleElementClick(name: string) {
+  async function handleElementClick(script: string, name: string) {
     try {
-      await invoke("nexus_send_command", { message: `ButtonClicked:${name}` });
+      await invoke("click_nexus_element", { name, script });
     } catch {}
   }
 
-  async function handleElementChange(name: string, value: string) {
+  async function handleElementChange(script: string, name: string, value: string) {
+    setElements((prev) => prev.map((el) => (el.script === script && el.name === name ? { ...el, value } : el)));
     try {
-      await invoke("set_nexus_element_value", { name, value });
+      await invoke("set_nexus_element_value", { name, value, script });
     } catch {}
   }
 
+  async function handleClearElements(script: string) {
+    try {
+      await invoke("clear_nexus_elements", { script });
+      refresh();
+    } catch {}
+  }
+
+  async function handleExportLog() {
+    try {
+      const path = await invoke<string>("export_nexus_log", {
+        filter: { username: logUser || null, minLevel: logLevel },
+        format: "text",
+      });
+      store.addToast(tr("Log exported to {{path}}", { path }));
+    } catch (e) {
+      store.addToast(tr("Error: {{error}}", { error: String(e) }));
+    }
+  }
+
+  async function showNexusSecret(secret: string, note: string) {
+    const copied = await navigator.clipboard.writeText(secret).then(
+      () => true,
+      () => false
+    );
+    window.prompt(
+      `${note} ${copied ? tr("It has been copied to the clipboard.") : tr("Copy it now, it is not shown again.")}`,
+      secret
+    );
+  }
+
+  async function handleRotateSecret(username: string | null) {
+    try {
+      const secret = await invoke<string>("rotate_nexus_secret", { username });
+      refresh();
+      await showNexusSecret(
+        secret,
+        username ? tr("New secret for {{username}}.", { username }) : tr("New shared key.")
+      );
+    } catch (e) {
+      store.addToast(tr("Error: {{error}}", { error: String(e) }));
+    }
+  }
+
+  async function handleRequireAuth(enabled: boolean) {
+    if (!settingsLoaded) return;
+    try {
+      const generated = await invoke<string | null>("set_nexus_require_auth", { enabled });
+      setSRequireAuth(enabled);
+      if (generated) {
+        await showNexusSecret(generated, tr("Authentication needs a shared key, so one was generated."));
+      }
+    } catch (e) {
+      store.addToast(tr("Error: {{error}}", { error: String(e) }));
+    }
+  }
+
   function saveSetting(key: string, value: string) {
     if (!settingsLoaded) return;
     invoke("update_setting", { section: "AccountControl", key, value }).catch(() => {});
@@ -297,6 +507,7 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
 
   const tabs: { id: TabId; label: string }[] = [
     { id: "control", label: tr("Control Panel") },
+    { id: "library", label: tr("Library") },
     { id: "settings", label: tr("Settings") },
     { id: "help", label: tr("Help") },
   ];
@@ -355,6 +566,9 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
               allChecked={allChecked}
               addInput={addInput}
               commandInput={commandInput}
+              sendTarget={sendTarget}
+              targetOptions={targetOptions}
+              setSendTarget={setSendTarget}
               scriptText={scriptText}
               autoExecText={autoExecText}
               placeInput={placeInput}
@@ -362,7 +576,16 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
               scriptOpen={scriptOpen}
               autoExecOpen={autoExecOpen}
               outputOpen={outputOpen}
-              log={log}
+              log={log.filter(
+                (entry) =>
+                  (!logUser || entry.username === logUser) &&
+                  LOG_LEVELS.indexOf(entry.level) >= LOG_LEVELS.indexOf(logLevel)
+              )}
+              logUser={logUser}
+              logLevel={logLevel}
+              setLogUser={setLogUser}
+              setLogLevel={setLogLevel}
+              onExportLog={handleExportLog}
               elements={elements}
               contextMenu={contextMenu}
               logRef={logRef}
@@ -384,10 +607,23 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
               onSendCommand={handleSendCommand}
               onExecuteScript={handleExecuteScript}
               onAutoExecBlur={handleAutoExecBlur}
+              libraryScripts={libraryScripts}
+              onAutoExecScriptsChange={handleAutoExecScriptsChange}
               onFieldBlur={handleFieldBlur}
               onAutoRelaunchToggle={handleAutoRelaunchToggle}
               onElementClick={handleElementClick}
               onElementChange={handleElementChange}
+              onElementsClear={handleClearElements}
+              sharedData={sharedData}
+              dataOpen={dataOpen}
+              setDataOpen={setDataOpen}
+              onDataDelete={async (script, key) => {
+                await invoke("delete_nexus_data", { script, key }).catch(() => {});
+                refresh();
+              }}
+              onAddToBroadcastGroup={handleAddToBroadcastGroup}
+              onLeaveBroadcastGroups={handleLeaveBroadcastGroups}
+              onRotateSecret={(username) => handleRotateSecret(username)}
               onClearLog={async () => {
                 await invoke("clear_nexus_log").catch(() => {});
                 setLog([]);
@@ -395,12 +631,22 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
             />
           )}
 
+          {tab === "library" && (
+            <LibraryPanel
+              scripts={libraryScripts}
+              onSave={handleSaveLuaScript}
+              onDelete={handleDeleteLuaScript}
+              onPush={handlePushLuaScript}
+            />
+          )}
+
           {tab === "settings" && (
             <SettingsPanel
               startOnLaunch={sStartOnLaunch}
               allowExternal={sAllowExternal}
               internetCheck={sInternetCheck}
               usePresence={sUsePresence}
+              requireAuth={sRequireAuth}
               relaunchDelay={sRelaunchDelay}
               launcherDelay={sLauncherDelay}
               port={sPort}
@@ -410,7 +656,17 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
               autoCloseInterval={sAutoCloseInterval}
               autoCloseType={sAutoCloseType}
               maxInstances={sMaxInstances}
+              heartbeatTimeout={sHeartbeatTimeout}
+              killStale={sKillStale}
+              persistElements={sPersistElements}
+              persistData={sPersistData}
+              logCapacity={sLogCapacity}
+              logMaxFileSize={sLogMaxFileSize}
               onToggle={(key, val) => {
+                if (key === "RequireAuth") {
+                  void handleRequireAuth(val);
+                  return;
+                }
                 const setters: Record<string, (v: boolean) => void> = {
                   StartOnLaunch: setSStartOnLaunch,
                   AllowExternalConnections: setSAllowExternal,
@@ -418,6 +674,9 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
                   UsePresence: setSUsePresence,
                   AutoMinimizeEnabled: setSAutoMinimize,
                   AutoCloseEnabled: setSAutoClose,
+                  KillStaleClients: setSKillStale,
+                  PersistElements: setSPersistElements,
+                  PersistData: setSPersistData,
                 };
                 setters[key]?.(val);
                 saveSetting(key, val ? "true" : "false");
@@ -430,6 +689,9 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
                   AutoMinimizeInterval: setSAutoMinInterval,
                   AutoCloseInterval: setSAutoCloseInterval,
                   MaxInstances: setSMaxInstances,
+                  HeartbeatTimeout: setSHeartbeatTimeout,
+                  LogCapacity: setSLogCapacity,
+                  LogMaxFileSize: setSLogMaxFileSize,
                 };
                 setters[key]?.(val);
                 saveSetting(key, String(val));
@@ -438,6 +700,7 @@ export function NexusDialog({ open, onClose }: { open: boolean; onClose: () => v
                 setSAutoCloseType(val);
                 saveSetting("AutoCloseType", String(val));
               }}
+              onRotateKey={() => handleRotateSecret(null)}
             />
           )}
 
@@ -455,6 +718,9 @@ function ControlPanel({
   allChecked,
   addInput,
   commandInput,
+  sendTarget,
+  targetOptions,
+  setSendTarget,
   scriptText,
   autoExecText,
   placeInput,
@@ -463,6 +729,11 @@ function ControlPanel({
   autoExecOpen,
   outputOpen,
   log,
+  logUser,
+  logLevel,
+  setLogUser,
+  setLogLevel,
+  onExportLog,
   elements,
   contextMenu,
   logRef,
@@ -484,11 +755,21 @@ function ControlPanel({
   onSendCommand,
   onExecuteScript,
   onAutoExecBlur,
+  libraryScripts,
+  onAutoExecScriptsChange,
   onFieldBlur,
   onAutoRelaunchToggle,
   onElementClick,
   onElementChange,
+  onElementsClear,
+  sharedData,
+  dataOpen,
+  setDataOpen,
+  onDataDelete,
+  onAddToBroadcastGroup,
+  onLeaveBroadcastGroups,
   onClearLog,
+  onRotateSecret,
 }: {
   accounts: NexusAccount[];
   selectedUsername: string | null;
@@ -496,6 +777,9 @@ function ControlPanel({
   allChecked: boolean;
   addInput: string;
   commandInput: string;
+  sendTarget: string;
+  targetOptions: { value: string; label: string }[];
+  setSendTarget: (v: string) => void;
   scriptText: string;
   autoExecText: string;
   placeInput: string;
@@ -503,7 +787,12 @@ function ControlPanel({
   scriptOpen: boolean;
   autoExecOpen: boolean;
   outputOpen: boolean;
-  log: string[];
+  log: NexusLogEntry[];
+  logUser: string;
+  logLevel: NexusLogEntry["level"];
+  setLogUser: (v: string) => void;
+  setLogLevel: (v: NexusLogEntry["level"]) => void;
+  onExportLog: () => void;
   elements: NexusElement[];
   contextMenu: { x: number; y: number; username: string } | null;
   logRef: React.RefObject<HTMLDivElement | null>;
@@ -525,13 +814,37 @@ function ControlPanel({
   onSendCommand: () => void;
   onExecuteScript: () => void;
   onAutoExecBlur: () => void;
+  libraryScripts: LuaScript[];
+  onAutoExecScriptsChange: (entries: AutoExecuteEntry[]) => void;
   onFieldBlur: (field: "place_id" | "job_id") => void;
   onAutoRelaunchToggle: () => void;
-  onElementClick: (name: string) => void;
-  onElementChange: (name: string, value: string) => void;
+  onElementClick: (script: string, name: string) => void;
+  onElementChange: (script: string, name: string, value: string) => void;
+  onElementsClear: (script: string) => void;
+  sharedData: Record<string, Record<string, unknown>>;
+  dataOpen: boolean;
+  setDataOpen: (v: boolean) => void;
+  onDataDelete: (script: string, key: string) => void;
+  onAddToBroadcastGroup: (username: string) => void;
+  onLeaveBroadcastGroups: (username: string) => void;
   onClearLog: () => void;
+  onRotateSecret: (username: string) => void;
 }) {
   const t = useTr();
+  const elementGroups = elements.reduce<[string, NexusElement[]][]>((groups, el) => {
+    const group = groups.find(([script]) => script === el.script);
+    if (group) group[1].push(el);
+    else groups.push([el.script, [el]]);
+    return groups;
+  }, []);
+  const autoExecScripts = selectedAcc?.auto_execute_scripts || [];
+  function moveAutoExecScript(index: number, delta: number) {
+    const next = [...autoExecScripts];
+    const target = index + delta;
+    if (target < 0 || target >= next.length) return;
+    [next[index], next[target]] = [next[target], next[index]];
+    onAutoExecScriptsChange(next);
+  }
   return (
     <div className="flex h-full">
       <div className="w-[240px] border-r border-zinc-800/60 flex flex-col shrink-0">
@@ -576,6 +889,9 @@ function ControlPanel({
                 acc.status === "Online" ? "bg-emerald-400" : "bg-zinc-600"
               }`} />
               <span className="text-[12px] text-zinc-300 truncate flex-1">{acc.username}</span>
+              {acc.status === "Online" && acc.latency_ms != null && (
+                <span className="text-[9px] text-zinc-600 font-mono shrink-0">{acc.latency_ms}ms</span>
+              )}
               {acc.in_game_job_id && acc.status === "Online" && (
                 <span className="text-[9px] text-zinc-600 font-mono truncate max-w-[60px]">
                   {acc.in_game_job_id.slice(0, 8)}
@@ -653,6 +969,7 @@ function ControlPanel({
             placeholder={t("Command")}
             className="flex-1 px-2.5 py-1.5 bg-zinc-800/50 border border-zinc-700/50 rounded-lg text-[12px] text-zinc-300 placeholder:text-zinc-600 focus:outline-none focus:border-zinc-600"
           />
+          <Select value={sendTarget} options={targetOptions} onChange={setSendTarget} className="w-32" />
           <button
             onClick={onSendCommand}
             className="px-3 py-1.5 bg-sky-600 hover:bg-sky-500 text-white rounded-lg text-[11px] font-medium transition-colors"
@@ -693,83 +1010,186 @@ function ControlPanel({
               className="w-full h-20 px-2.5 py-2 bg-zinc-800/50 border border-zinc-700/50 rounded-lg text-[11px] text-zinc-300 font-mono resize-none focus:outline-none focus:border-zinc-600"
               placeholder={t("Script to execute on connect...")}
             />
+            <div className="mt-1.5 space-y-1">
+              {autoExecScripts.map((entry, i) => {
+                const script = libraryScripts.find((s) => s.id === entry.ScriptId);
+                return (
+                  <div key={`${i}-${entry.ScriptId}`} className="flex items-center gap-1.5">
+                    <span className={`text-[11px] w-28 truncate ${script ? "text-zinc-300" : "text-red-400"}`}>
+                      {script ? script.name : entry.ScriptId}
+                    </span>
+                    <input
+                      defaultValue={Object.entries(entry.Params || {}).map(([k, v]) => `${k}=${v}`).join(", ")}
+                      onBlur={(e) => {
+                        const params: Record<string, string> = {};
+                        for (const pair of e.target.value.split(",")) {
+                          const [key, ...rest] = pair.split("=");
+                          if (key.trim()) params[key.trim()] = rest.join("=").trim();
+                        }
+                        onAutoExecScriptsChange(autoExecScripts.map((x, j) => (j === i ? { ...x, Params: params } : x)));
+                      }}
+                      placeholder={t("key=value, ...")}
+                      className="flex-1 px-2 py-0.5 bg-zinc-800/50 border border-zinc-700/50 rounded-md text-[11px] text-zinc-300 font-mono placeholder:text-zinc-600 focus:outline-none focus:border-zinc-600"
+                    />
+                    <button onClick={() => moveAutoExecScript(i, -1)} className="text-zinc-600 hover:text-zinc-300 transition-colors">
+                      <ChevronUp size={12} strokeWidth={2} />
+                    </button>
+                    <button onClick={() => moveAutoExecScript(i, 1)} className="text-zinc-600 hover:text-zinc-300 transition-colors">
+                      <ChevronDown size={12} strokeWidth={2} />
+                    </button>
+                    <button
+                      onClick={() => onAutoExecScriptsChange(autoExecScripts.filter((_, j) => j !== i))}
+                      className="text-zinc-600 hover:text-red-400 transition-colors"
+                    >
+                      <X size={12} strokeWidth={2} />
+                    </button>
+                  </div>
+                );
+              })}
+              {libraryScripts.length > 0 && (
+                <Select
+                  value=""
+                  options={[
+                    { value: "", label: t("Add library script...") },
+                    ...libraryScripts.map((s) => ({ value: s.id, label: s.name })),
+                  ]}
+                  onChange={(id) => id && onAutoExecScriptsChange([...autoExecScripts, { ScriptId: id, Params: {} }])}
+                  className="w-48"
+                />
+              )}
+            </div>
           </CollapsibleSection>
         )}
 
         <CollapsibleSection title={t("Output")} open={outputOpen} onToggle={() => setOutputOpen(!outputOpen)} actions={
-          <button onClick={onClearLog} className="text-[9px] text-zinc-600 hover:text-zinc-400 transition-colors">
-            {t("Clear")}
-          </button>
+          <div className="flex items-center gap-2">
+            <Select
+              value={logUser}
+              options={[{ value: "", label: t("All Accounts") }, ...accounts.map((a) => ({ value: a.username, label: a.username }))]}
+              onChange={setLogUser}
+              className="w-28"
+            />
+            <Select
+              value={logLevel}
+              options={LOG_LEVELS.map((level) => ({ value: level, label: t(level.charAt(0).toUpperCase() + level.slice(1)) }))}
+              onChange={(v) => setLogLevel(v as NexusLogEntry["level"])}
+              className="w-20"
+            />
+            <button onClick={onExportLog} className="text-[9px] text-zinc-600 hover:text-zinc-400 transition-colors">
+              {t("Export")}
+            </button>
+            <button onClick={onClearLog} className="text-[9px] text-zinc-600 hover:text-zinc-400 transition-colors">
+              {t("Clear")}
+            </button>
+          </div>
         }>
           <div ref={logRef} className="h-28 overflow-y-auto bg-zinc-950/50 border border-zinc-800/40 rounded-lg p-2 space-y-0.5">
             {log.length === 0 && (
               <span className="text-[10px] text-zinc-600">{t("No output")}</span>
             )}
-            {log.map((msg, i) => (
-              <div key={i} className="text-[10px] text-zinc-400 font-mono leading-relaxed break-all">
-                {msg}
+            {log.map((entry, i) => (
+              <div
+                key={i}
+                className={`text-[10px] font-mono leading-relaxed break-all ${
+                  entry.level === "error" ? "text-red-400" : entry.level === "warn" ? "text-amber-400" : entry.level === "debug" ? "text-zinc-600" : "text-zinc-400"
+                }`}
+              >
+                <span className="text-zinc-600">{new Date(entry.timestampMs).toLocaleTimeString()} </span>
+                {entry.username && <span className="text-sky-400/80">{entry.username} </span>}
+                {entry.message}
               </div>
             ))}
           </div>
         </CollapsibleSection>
 
-        {elements.length > 0 && (
-          <div className="flex flex-wrap items-start gap-1.5">
-            {elements.map((el) => {
-              if (el.is_newline) return <div key={el.name} className="w-full h-0" />;
-
-              if (el.element_type === "Button") {
-                return (
-                  <button
-                    key={el.name}
-                    onClick={() => onElementClick(el.name)}
-                    className="px-2.5 py-1 bg-zinc-800 border border-zinc-700/50 rounded-md text-[11px] text-zinc-300 hover:bg-zinc-700 hover:text-zinc-100 transition-colors"
-                  >
-                    {el.content}
-                  </button>
-                );
-              }
+        {Object.keys(sharedData).length > 0 && (
+          <CollapsibleSection title={t("Shared Data")} open={dataOpen} onToggle={() => setDataOpen(!dataOpen)}>
+            <div className="max-h-32 overflow-y-auto bg-zinc-950/50 border border-zinc-800/40 rounded-lg p-2 space-y-0.5">
+              {Object.entries(sharedData).flatMap(([script, values]) =>
+                Object.entries(values).map(([key, value]) => (
+                  <div key={`${script}\0${key}`} className="flex items-center gap-2 text-[10px] font-mono">
+                    <span className="text-zinc-500 shrink-0">{script ? `${script}/` : ""}{key}</span>
+                    <span className="text-zinc-300 truncate flex-1">{JSON.stringify(value)}</span>
+                    <button onClick={() => onDataDelete(script, key)} className="text-zinc-600 hover:text-red-400 transition-colors">
+                      <X size={10} strokeWidth={2} />
+                    </button>
+                  </div>
+                ))
+              )}
+            </div>
+          </CollapsibleSection>
+        )}
 
-              if (el.element_type === "TextBox") {
-                return (
-                  <input
-                    key={el.name}
-                    value={el.value}
-                    onChange={(e) => onElementChange(el.name, e.target.value)}
-                    className="px-2 py-1 bg-zinc-800/50 border border-zinc-700/50 rounded-md text-[11px] text-zinc-300 focus:outline-none focus:border-zinc-600"
-                    style={{ width: el.size ? el.size[0] : 75 }}
-                  />
-                );
-              }
+        {elementGroups.map(([script, group]) => (
+          <div key={script || "__default"} className="space-y-1">
+            {(script || elementGroups.length > 1) && (
+              <div className="flex items-center justify-between">
+                <span className="text-[10px] text-zinc-500 uppercase tracking-wider">{script || t("Default")}</span>
+                <button
+                  onClick={() => onElementsClear(script)}
+                  className="text-[10px] text-zinc-600 hover:text-zinc-300 transition-colors"
+                >
+                  {t("Clear")}
+                </button>
+              </div>
+            )}
+            <div className="flex flex-wrap items-start gap-1.5">
+              {group.map((el) => {
+                if (el.is_newline) return <div key={el.name} className="w-full h-0" />;
+
+                if (el.element_type === "Button") {
+                  return (
+                    <button
+                      key={el.name}
+                      onClick={() => onElementClick(el.script, el.name)}
+                      className="px-2.5 py-1 bg-zinc-800 border border-zinc-700/50 rounded-md text-[11px] text-zinc-300 hover:bg-zinc-700 hover:text-zinc-100 transition-colors"
+                    >
+                      {el.content}
+                    </button>
+                  );
+                }
 
-              if (el.element_type === "Numeric") {
-                const numericValue = Number.parseFloat(el.value);
-                const stepValue = Number.parseFloat(el.increment || "1");
-                return (
-                  <div key={el.name} style={{ width: el.size ? el.size[0] : 75 }}>
-                    <NumericInput
-                      value={Number.isFinite(numericValue) ? numericValue : 0}
-                      onChange={(next) => onElementChange(el.name, String(next))}
-                      step={Number.isFinite(stepValue) && stepValue > 0 ? stepValue : 1}
-                      integer={el.decimal_places !== null ? el.decimal_places <= 0 : undefined}
-                      className="w-full px-2 py-1 bg-zinc-800/50 border border-zinc-700/50 rounded-md text-[11px] text-zinc-300 font-mono focus:outline-none focus:border-zinc-600 [appearance:textfield] [&::-webkit-outer-spin-button]:appearance-none [&::-webkit-inner-spin-button]:appearance-none"
+                if (el.element_type === "TextBox") {
+                  return (
+                    <input
+                      key={el.name}
+                      value={el.value}
+                      onChange={(e) => onElementChange(el.script, el.name, e.target.value)}
+                      className="px-2 py-1 bg-zinc-800/50 border border-zinc-700/50 rounded-md text-[11px] text-zinc-300 focus:outline-none focus:border-zinc-600"
+                      style={{ width: el.size ? el.size[0] : 75 }}
                     />
-                  </div>
-                );
-              }
+                  );
+                }
 
-              if (el.element_type === "Label") {
-                return (
-                  <span key={el.name} className="text-[11px] text-zinc-400 py-1 px-1">
-                    {el.content}
-                  </span>
-                );
-              }
+                if (el.element_type === "Numeric") {
+                  const numericValue = Number.parseFloat(el.value);
+                  const stepValue = Number.parseFloat(el.increment || "1");
+                  return (
+                    <div key={el.name} style={{ width: el.size ? el.size[0] : 75 }}>
+                      <NumericInput
+                        value={Number.isFinite(numericValue) ? numericValue : 0}
+                        onChange={(next) => onElementChange(el.script, el.name, String(next))}
+                        step={Number.isFinite(stepValue) && stepValue > 0 ? stepValue : 1}
+                        integer={el.decimal_places !== null ? el.decimal_places <= 0 : undefined}
+                        className="w-full px-2 py-1 bg-zinc-800/50 border border-zinc-700/50 rounded-md text-[11px] text-zinc-300 font-mono focus:outline-none focus:border-zinc-600 [appearance:textfield] [&::-webkit-outer-spin-button]:appearance-none [&::-webkit-inner-spin-button]:appearance-none"
+                      />
+                    </div>
+                  );
+                }
+
+                if (el.element_type === "Label") {
+                  return (
+                    <span key={el.name} className="text-[11px] text-zinc-400 py-1 px-1">
+                      {el.content}
+                    </span>
+                  );
+                }
 
-              return null;
-            })}
+                return null;
+              })}
+            </div>
           </div>
-        )}
+        ))}
       </div>
 
       {contextMenu &&
@@ -778,7 +1198,7 @@ function ControlPanel({
             className="theme-modal-scope theme-panel theme-border fixed z-100 bg-zinc-900 border border-zinc-700/60 rounded-lg shadow-xl py-1 min-w-[140px] animate-scale-in"
             style={{
               left: Math.max(8, Math.min(contextMenu.x, window.innerWidth - 148)),
-              top: Math.max(8, Math.min(contextMenu.y, window.innerHeight - 96)),
+              top: Math.max(8, Math.min(contextMenu.y, window.innerHeight - 124)),
             }}
           >
             <button
@@ -793,6 +1213,35 @@ function ControlPanel({
             >
               {t("Copy Job ID")}
             </button>
+            <button
+              onClick={() => {
+                onRotateSecret(contextMenu.username);
+                setContextMenu(null);
+              }}
+              className="w-full px-3 py-1.5 text-left text-[11px] text-zinc-300 hover:bg-zinc-800 transition-colors"
+            >
+              {t("Rotate Secret")}
+            </button>
+            <button
+              onClick={() => {
+                onAddToBroadcastGroup(contextMenu.username);
+                setContextMenu(null);
+              }}
+              className="w-full px-3 py-1.5 text-left text-[11px] text-zinc-300 hover:bg-zinc-800 transition-colors"
+            >
+              {t("Add to Broadcast Group")}
+            </button>
+            {(accounts.find((a) => a.username === contextMenu.username)?.broadcast_groups?.length ?? 0) > 0 && (
+              <button
+                onClick={() => {
+                  onLeaveBroadcastGroups(contextMenu.username);
+                  setContextMenu(null);
+                }}
+                className="w-full px-3 py-1.5 text-left text-[11px] text-zinc-300 hover:bg-zinc-800 transition-colors"
+              >
+                {t("Leave Broadcast Groups")}
+              </button>
+            )}
             <button
               onClick={() => {
                 onRemove([contextMenu.username]);
@@ -861,11 +1310,130 @@ function CollapsibleSection({
   );
 }
 
+function LibraryPanel({
+  scripts,
+  onSave,
+  onDelete,
+  onPush,
+}: {
+  scripts: LuaScript[];
+  onSave: (script: { id: string; name: string; description: string; source: string }) => Promise<boolean>;
+  onDelete: (scriptId: string) => void;
+  onPush: (scriptId: string) => void;
+}) {
+  const t = useTr();
+  const [selectedId, setSelectedId] = useState<string | null>(null);
+  const [id, setId] = useState("");
+  const [name, setName] = useState("");
+  const [description, setDescription] = useState("");
+  const [source, setSource] = useState("");
+  const selected = scripts.find((s) => s.id === selectedId);
+
+  function select(script: LuaScript | null) {
+    setSelectedId(script?.id ?? null);
+    setId(script?.id ?? "");
+    setName(script?.name ?? "");
+    setDescription(script?.description ?? "");
+    setSource(script?.source ?? "");
+  }
+
+  return (
+    <div className="flex h-full">
+      <div className="w-[200px] border-r border-zinc-800/60 flex flex-col shrink-0">
+        <div className="flex items-center gap-2 px-3 py-2 border-b border-zinc-800/40">
+          <span className="text-[11px] text-zinc-400 font-medium flex-1">{t("Scripts")}</span>
+          <button onClick={() => select(null)} className="text-[10px] text-zinc-500 hover:text-zinc-200 transition-colors">
+            {t("New")}
+          </button>
+        </div>
+        <div className="flex-1 overflow-y-auto">
+          {scripts.map((script) => (
+            <div
+              key={script.id}
+              onClick={() => select(script)}
+              className={`flex items-center gap-2 px-3 py-1.5 cursor-pointer transition-colors ${
+                selectedId === script.id ? "bg-sky-500/10" : "hover:bg-zinc-800/40"
+              }`}
+            >
+              <span className="text-[12px] text-zinc-300 truncate flex-1">{script.name}</span>
+              <span className="text-[9px] text-zinc-600 font-mono shrink-0">v{script.version}</span>
+            </div>
+          ))}
+          {scripts.length === 0 && (
+            <div className="px-3 py-6 text-center text-[11px] text-zinc-600">{t("No scripts saved")}</div>
+          )}
+        </div>
+      </div>
+
+      <div className="flex-1 overflow-y-auto p-3 flex flex-col gap-2">
+        <div className="flex items-center gap-2">
+          <input
+            value={id}
+            onChange={(e) => setId(e.target.value)}
+            disabled={!!selected}
+            placeholder={t("ID")}
+            className="w-36 px-2 py-1 bg-zinc-800/50 border border-zinc-700/50 rounded-md text-[12px] text-zinc-300 font-mono placeholder:text-zinc-600 focus:outline-none focus:border-zinc-600 disabled:opacity-60"
+          />
+          <input
+            value={name}
+            onChange={(e) => setName(e.target.value)}
+            placeholder={t("Name")}
+            className="flex-1 px-2 py-1 bg-zinc-800/50 border border-zinc-700/50 rounded-md text-[12px] text-zinc-300 placeholder:text-zinc-600 focus:outline-none focus:border-zinc-600"
+          />
+        </div>
+        <input
+          value={description}
+          onChange={(e) => setDescription(e.target.value)}
+          placeholder={t("Description")}
+          className="px-2 py-1 bg-zinc-800/50 border border-zinc-700/50 rounded-md text-[12px] text-zinc-300 placeholder:text-zinc-600 focus:outline-none focus:border-zinc-600"
+        />
+        <textarea
+          value={source}
+          onChange={(e) => setSource(e.target.value)}
+          placeholder={`${t("Lua source")} - {{username}}, {{userId}}, {{placeId}}, {{jobId}}`}
+          className="flex-1 min-h-[200px] px-2.5 py-2 bg-zinc-800/50 border border-zinc-700/50 rounded-lg text-[11px] text-zinc-300 font-mono resize-none focus:outline-none focus:border-zinc-600"
+        />
+        <div className="flex items-center gap-1.5">
+          <button
+            onClick={async () => {
+              if (await onSave({ id, name, description, source })) setSelectedId(id.trim());
+            }}
+            className="px-3 py-1 bg-sky-600 hover:bg-sky-500 text-white rounded-md text-[10px] font-medium transition-colors"
+          >
+            {t("Save")}
+          </button>
+          {selected && (
+            <>
+              <button
+                onClick={() => onPush(selected.id)}
+                className="px-3 py-1 bg-zinc-800 border border-zinc-700/50 rounded-md text-[10px] text-zinc-400 hover:text-zinc-200 transition-colors"
+              >
+                {t("Push to Clients")}
+              </button>
+              <button
+                onClick={() => {
+                  onDelete(selected.id);
+                  select(null);
+                }}
+                className="px-3 py-1 bg-zinc-800 border border-zinc-700/50 rounded-md text-[10px] text-red-400 hover:bg-zinc-700 transition-colors"
+              >
+                {t("Delete")}
+              </button>
+              <span className="ml-auto text-[10px] text-zinc-600 font-mono">v{selected.version}</span>
+            </>
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
+
 function SettingsPanel({
   startOnLaunch,
   allowExternal,
   internetCheck,
   usePresence,
+  requireAuth,
   relaunchDelay,
   launcherDelay,
   port,
@@ -875,14 +1443,22 @@ function SettingsPanel({
   autoCloseInterval,
   autoCloseType,
   maxInstances,
+  heartbeatTimeout,
+  killStale,
+  persistElements,
+  persistData,
+  logCapacity,
+  logMaxFileSize,
   onToggle,
   onNumber,
   onCloseType,
+  onRotateKey,
 }: {
   startOnLaunch: boolean;
   allowExternal: boolean;
   internetCheck: boolean;
   usePresence: boolean;
+  requireAuth: boolean;
   relaunchDelay: number;
   launcherDelay: number;
   port: number;
@@ -892,9 +1468,16 @@ function SettingsPanel({
   autoCloseInterval: number;
   autoCloseType: number;
   maxInstances: number;
+  heartbeatTimeout: number;
+  killStale: boolean;
+  persistElements: boolean;
+  persistData: boolean;
+  logCapacity: number;
+  logMaxFileSize: number;
   onToggle: (key: string, val: boolean) => void;
   onNumber: (key: string, val: number) => void;
   onCloseType: (val: number) => void;
+  onRotateKey: () => void;
 }) {
   const t = useTr();
   return (
@@ -904,6 +1487,17 @@ function SettingsPanel({
         <SettingToggle label="Allow External Connections" checked={allowExternal} onChange={(v) => onToggle("AllowExternalConnections", v)} />
         <SettingToggle label="Check Internet Before Launch" checked={internetCheck} onChange={(v) => onToggle("InternetCheck", v)} />
         <SettingToggle label="Use Presence API" checked={usePresence} onChange={(v) => onToggle("UsePresence", v)} />
+        <SettingToggle label="Require Authentication" checked={requireAuth} onChange={(v) => onToggle("RequireAuth", v)} />
+        <SettingToggle label="Persist Custom Elements" checked={persistElements} onChange={(v) => onToggle("PersistElements", v)} />
+        <SettingToggle label="Persist Shared Data" checked={persistData} onChange={(v) => onToggle("PersistData", v)} />
+        <div className="flex items-center py-1.5 px-1">
+          <button
+            onClick={onRotateKey}
+            className="px-2.5 py-1 bg-zinc-800 border border-zinc-700/50 rounded-md text-[11px] text-zinc-400 hover:text-zinc-200 hover:bg-zinc-700 transition-colors"
+          >
+            {t("Rotate Shared Key")}
+          </button>
+        </div>
       </div>
 
       <div className="h-px bg-zinc-800/60 my-3" />
@@ -912,6 +1506,10 @@ function SettingsPanel({
         <SettingNumber label="Relaunch Delay" value={relaunchDelay} min={1} max={3600} suffix="sec" onChange={(v) => onNumber("RelaunchDelay", v)} />
         <SettingNumber label="Launcher Delay" value={launcherDelay} min={1} max={3600} suffix="sec" onChange={(v) => onNumber("LauncherDelay", v)} />
         <SettingNumber label="Port" value={port} min={1} max={65535} onChange={(v) => onNumber("NexusPort", v)} />
+        <SettingNumber label="Heartbeat Timeout" value={heartbeatTimeout} min={0} max={3600} suffix="sec" onChange={(v) => onNumber("HeartbeatTimeout", v)} />
+        <SettingToggle label="Kill Stale Clients" checked={killStale} onChange={(v) => onToggle("KillStaleClients", v)} />
+        <SettingNumber label="Log Entries" value={logCapacity} min={50} max={100000} onChange={(v) => onNumber("LogCapacity", v)} />
+        <SettingNumber label="Log File Size" value={logMaxFileSize} min={0} max={102400} suffix="KB" onChange={(v) => onNumber("LogMaxFileSize", v)} />
       </div>
 
       <div className="h-px bg-zinc-800/60 my-3" />
@@ -1009,6 +1607,11 @@ function HelpPanel() {
           <p>3. {t("Execute Nexus.lua in each Roblox client you want to control.")}</p>
           <p>4. {t("Connected clients will appear as Online with a green status dot.")}</p>
           <p>5. {t("Use the command input or script panel to send commands to checked accounts.")}</p>
+          <p>6. {t("With Require Authentication on, set getgenv().NexusSecret to the account secret or shared key before running Nexus.lua.")}</p>
+          <p>7. {t("Set Nexus.Script before creating elements to group them; clicks and value changes are sent back to the clients that created them.")}</p>
+          <p>8. {t("Pick a target next to Send to reach checked accounts, an account group, a broadcast group, or everyone in the selected account's server or place.")}</p>
+          <p>9. {t("Save reusable scripts in the Library tab and add them to an account's Auto Execute list; they run in order on connect with per-account parameters filled in.")}</p>
+          <p>10. {t("Use Nexus:SetData, GetData, ClaimData and WatchData to share state between clients; keys are scoped to Nexus.Script.")}</p>
         </div>
       </div>
 
@@ -0,0 +1,61 @@
+import { useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { useConfirm } from "../../hooks/usePrompt";
+import { useTr } from "../../i18n/text";
+import { useStore } from "../../store";
+
+interface AccountBackupInfo {
+  name: string;
+  createdAt: string;
+  size: number;
+  valid: boolean;
+}
+
+interface AccountFileRecovery {
+  damaged: string | null;
+  backups: AccountBackupInfo[];
+  suggested: string | null;
+}
+
+let offered = false;
+
+export function AccountRecoveryPrompt() {
+  const t = useTr();
+  const store = useStore();
+  const confirm = useConfirm();
+
+  useEffect(() => {
+    if (offered) return;
+    offered = true;
+    (async () => {
+      let recovery: AccountFileRecovery;
+      try {
+        recovery = await invoke<AccountFileRecovery>("get_account_file_recovery");
+      } catch {
+        return;
+      }
+      if (!recovery.damaged) return;
+      const backup = recovery.backups.find((b) => b.name === recovery.suggested);
+      if (!backup) {
+        store.setError(t("Account file is damaged and no valid backup was found: {{reason}}", { reason: recovery.damaged }));
+        return;
+      }
+      const when = new Date(backup.createdAt).toLocaleString();
+      const ok = await confirm(
+        t("Account file is damaged ({{reason}}). Restore the backup from {{when}}?", {
+          reason: recovery.damaged,
+          when,
+        }),
+      );
+      if (!ok) return;
+      try {
+        await invoke<boolean>("restore_account_backup", { name: backup.name });
+        window.location.reload();
+      } catch (e) {
+        store.setError(String(e));
+      }
+    })();
+  }, [confirm, store, t]);
+
+  return null;
+}
@@ -77,10 +77,11 @@ export function Toolbar() {
           value={store.searchQuery}
           onChange={(e) => store.setSearchQuery(e.target.value)}
           onClick={(e) => e.stopPropagation()}
-          placeholder={t("Filter accounts...")}
+          placeholder={t("Filter accounts... (? for a query)")}
+          title={store.queryError ?? undefined}
           autoComplete="off"
           spellCheck={false}
-          className="theme-input w-full pl-9 pr-3 py-1.5 rounded-lg text-sm transition-colors"
+          className={`theme-input w-full pl-9 pr-3 py-1.5 rounded-lg text-sm transition-colors ${store.queryError ? "border-red-500/60" : ""}`}
         />
         {store.searchQuery && (
           <Tooltip content={t("Clear search")} side="bottom">
@@ -6,6 +6,25 @@ import { useTr } from "../../i18n/text";
 import { MenuItemView } from "./MenuItemView";
 import type { MenuItem } from "./MenuItemView";
 
+interface SavePickerWritable {
+  write: (data: string) => Promise<void>;
+  close: () => Promise<void>;
+}
+
+interface SavePickerHandle {
+  createWritable: () => Promise<SavePickerWritable>;
+}
+
+interface SavePickerApi {
+  showSaveFilePicker?: (options?: {
+    suggestedName?: string;
+    types?: Array<{
+      description?: string;
+      accept: Record<string, string[]>;
+    }>;
+  }) => Promise<SavePickerHandle>;
+}
+
 export function ContextMenu() {
   const store = useStore();
   const t = useTr();
@@ -160,6 +179,31 @@ export function ContextMenu() {
     },
   ];
 
+  const openVaults = store.vaults.filter((v) => v.open);
+  const moveToVaultSubmenu: MenuItem[] = [
+    { label: t("Main"), action: () => store.moveToVault(userIds, "") },
+    ...openVaults.map((v) => ({
+      label: v.name,
+      action: () => store.moveToVault(userIds, v.name),
+    })),
+  ];
+
+  const selectedTags = [...new Set(accounts.flatMap((a) => a.Tags || []))].sort();
+  const tagsSubmenu: MenuItem[] = [
+    {
+      label: t("Add Tag..."),
+      action: async () => {
+        const tag = await prompt(t("Tag:"));
+        if (tag?.trim()) store.editTags(userIds, [tag.trim()], []);
+      },
+    },
+    ...(selectedTags.length > 0 ? [{ separator: true, label: "" }] : []),
+    ...selectedTags.map((tag) => ({
+      label: t("Remove {{tag}}", { tag }),
+      action: () => store.editTags(userIds, [], [tag]),
+    })),
+  ];
+
   const items: MenuItem[] = [
     {
       label: t("Set Alias"),
@@ -287,6 +331,57 @@ export function ContextMenu() {
     },
     { separator: true, label: "" },
     { label: t("Move to Group"), submenu: moveToGroupSubmenu },
+    { label: t("Tags"), submenu: tagsSubmenu },
+    ...(openVaults.length > 0 ? [{ label: t("Move to Vault"), submenu: moveToVaultSubmenu }] : []),
+    {
+      label: t("Export Bundle..."),
+      action: async () => {
+        const password = await prompt(t("Bundle password (at least 8 characters):"));
+        if (!password) return;
+        const includePasswords = await confirm(t("Include account passwords in the bundle?"));
+        const includeNexus = await confirm(t("Include Nexus control settings?"));
+        try {
+          const path = await invoke<string>("export_accounts", {
+            userIds,
+            password,
+            includePasswords,
+            includeNexus,
+          });
+          store.addToast(t("Exported {{count}} account(s) to {{path}}", { count: userIds.length, path }));
+        } catch (e) {
+          store.addToast(t("Export failed: {{error}}", { error: String(e) }));
+        }
+      },
+    },
+    {
+      label: t("Export CSV"),
+      action: async () => {
+        if (userIds.length === 0) return;
+        const savePickerApi = window as Window & SavePickerApi;
+        if (typeof savePickerApi.showSaveFilePicker !== "function") {
+          store.addToast(t("Export failed: {{error}}", { error: t("No save dialog is available") }));
+          return;
+        }
+        const includeSecrets = await confirm(
+          t("Include cookies and passwords? They are written to the file in plain text."),
+          true
+        );
+        try {
+          const handle = await savePickerApi.showSaveFilePicker({
+            suggestedName: "Accounts.csv",
+            types: [{ description: "CSV", accept: { "text/csv": [".csv"] } }],
+          });
+          const csv = await invoke<string>("export_accounts_csv", { userIds, includeSecrets });
+          const writable = await handle.createWritable();
+          await writable.write(csv);
+          await writable.close();
+          store.addToast(t("Exported {{count}} account(s)", { count: userIds.length }));
+        } catch (e) {
+          if (String(e || "").toLowerCase().includes("abort")) return;
+          store.addToast(t("Export failed: {{error}}", { error: String(e) }));
+        }
+      },
+    },
     {
       label: t("Copy Group"),
       action: () => {
@@ -322,6 +417,7 @@ export function ContextMenu() {
           LastUse: single.LastUse,
           LastAttemptedRefresh: single.LastAttemptedRefresh,
           Fields: single.Fields,
+          Tags: single.Tags || [],
           BrowserTrackerID: single.BrowserTrackerID,
           HasPassword: !!single.Password,
           HasCookie: !!single.SecurityToken,
@@ -52,8 +52,9 @@ export function ServerListDialog({ open, onClose }: ServerListDialogProps) {
     store.setJobId(jobId);
     store.setPlaceId(localPlaceId);
     if (userId) {
-      if (!(await confirmJoinOnline([userId]))) return;
-      store.joinServer(userId);
+      const decision = await confirmJoinOnline([userId]);
+      if (!decision) return;
+      store.joinServer(userId, decision === "confirmed");
     }
     addRecentGame({
       placeId: parseInt(localPlaceId) || 0,
@@ -82,9 +83,10 @@ export function ServerListDialog({ open, onClose }: ServerListDialogProps) {
       store.addToast(t("No account selected"));
       return;
     }
-    if (!(await confirmJoinOnline([userId]))) return;
+    const decision = await confirmJoinOnline([userId]);
+    if (!decision) return;
     try {
-      await invoke("launch_roblox", {
+      const result = await invoke<{ launched: boolean; error?: string }>("launch_roblox", {
         userId,
         placeId,
         jobId: "",
@@ -93,7 +95,12 @@ export function ServerListDialog({ open, onClose }: ServerListDialogProps) {
         joinVip: false,
         linkCode: "",
         shuffleJob: false,
+        presenceConfirmed: decision === "confirmed",
       });
+      if (!result.launched) {
+        store.addToast(t("Failed to join: {{error}}", { error: result.error || "" }));
+        return;
+      }
       store.addToast(t("Launching game..."));
     } catch (e) {
       store.addToast(t("Failed to join: {{error}}", { error: String(e) }));
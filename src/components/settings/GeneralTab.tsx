@@ -273,6 +273,27 @@ export function GeneralTab({ s }: { s: UseSettingsReturn }) {
         label="Warn Before Joining Online Accounts"
         description="Show a confirmation if selected accounts are already online/in-game"
       />
+      {s.get("General", "WarnOnOnlineJoin", "true") === "true" && (
+        <div className="flex items-center gap-3 py-2 px-1">
+          <div className="min-w-0">
+            <div className="text-[13px] text-zinc-300">{t("Online Account Policy")}</div>
+            <div className="mt-0.5 text-[11px] text-zinc-500">
+              {t("What launches, botting and the web server do when an account is already online")}
+            </div>
+          </div>
+          <div className="ml-auto min-w-[220px]">
+            <Select
+              value={s.get("General", "OnlineJoinPolicy", "warn")}
+              options={[
+                { value: "warn", label: t("Warn and ask to confirm") },
+                { value: "skip", label: t("Skip the account") },
+                { value: "kick", label: t("Sign out the other session") },
+              ]}
+              onChange={(value) => s.set("General", "OnlineJoinPolicy", value)}
+            />
+          </div>
+        </div>
+      )}
       <Toggle
         checked={s.getBool("General", "AutoCookieRefresh")}
         onChange={(v) => s.setBool("General", "AutoCookieRefresh", v)}
@@ -1,10 +1,519 @@
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
 import type { UseSettingsReturn } from "../../hooks/useSettings";
 import { Toggle } from "../ui/Toggle";
 import { NumberField } from "../ui/NumberField";
 import { TextField } from "../ui/TextField";
 import { Divider } from "../ui/Divider";
 import { SectionLabel } from "../ui/SectionLabel";
+import { Select } from "../ui/Select";
 import { useTr } from "../../i18n/text";
+import { useStore } from "../../store";
+import type { BalanceDrop, BalanceTotal } from "../../types";
+
+interface AccountContainerInfo {
+  version: number;
+  kdf: string;
+  opsLimit: number;
+  memLimit: number;
+  cipher: string;
+  scope: "file" | "fields";
+}
+
+interface RememberUnlockStatus {
+  enabled: boolean;
+  backend: string;
+  stored: boolean;
+}
+
+function RememberUnlockToggle() {
+  const t = useTr();
+  const store = useStore();
+  const [status, setStatus] = useState<RememberUnlockStatus | null>(null);
+
+  useEffect(() => {
+    invoke<RememberUnlockStatus>("get_remember_unlock")
+      .then(setStatus)
+      .catch(() => setStatus(null));
+  }, []);
+
+  if (!status) return null;
+
+  async function handleChange(enabled: boolean) {
+    try {
+      setStatus(await invoke<RememberUnlockStatus>("set_remember_unlock", { enabled }));
+    } catch (e) {
+      store.addToast(String(e));
+    }
+  }
+
+  return (
+    <Toggle
+      checked={status.enabled && status.stored}
+      onChange={handleChange}
+      label={t("Remember Unlock Key")}
+      description={t("Stores the unlock key in the system keychain so the account file opens automatically on startup.")}
+    />
+  );
+}
+
+const vaultInputClass =
+  "flex-1 min-w-0 px-2.5 py-1.5 rounded-lg bg-zinc-900 border border-zinc-800 text-[12px] text-zinc-200 placeholder:text-zinc-600 focus:outline-none focus:border-zinc-600";
+const vaultButtonClass =
+  "px-3 py-1.5 rounded-lg bg-zinc-800 hover:bg-zinc-700 border border-zinc-700/70 text-[12px] text-zinc-200 font-medium transition-colors disabled:opacity-50";
+
+function VaultManager() {
+  const t = useTr();
+  const store = useStore();
+  const [passwords, setPasswords] = useState<Record<string, string>>({});
+  const [newName, setNewName] = useState("");
+  const [newPassword, setNewPassword] = useState("");
+  const [busy, setBusy] = useState(false);
+
+  useEffect(() => {
+    store.refreshVaults();
+  }, []);
+
+  async function run(action: () => Promise<unknown>, done: string) {
+    setBusy(true);
+    try {
+      await action();
+      await store.loadAccounts();
+      await store.refreshVaults();
+      store.addToast(done);
+      return true;
+    } catch (e) {
+      store.addToast(String(e));
+      return false;
+    } finally {
+      setBusy(false);
+    }
+  }
+
+  function openVault(name: string) {
+    const password = passwords[name] ?? "";
+    void run(() => invoke("open_vault", { name, password }), t("Vault {{name}} opened", { name })).then((ok) => {
+      if (ok) setPasswords((prev) => ({ ...prev, [name]: "" }));
+    });
+  }
+
+  function createVault() {
+    const name = newName.trim();
+    if (!name || !newPassword) return;
+    void run(() => invoke("create_vault", { name, password: newPassword }), t("Vault {{name}} created", { name })).then((ok) => {
+      if (!ok) return;
+      setNewName("");
+      setNewPassword("");
+    });
+  }
+
+  return (
+    <div className="py-2 px-1 space-y-2">
+      <div className="min-w-0">
+        <div className="text-[13px] text-zinc-200">{t("Vaults")}</div>
+        <div className="text-[11px] text-zinc-500 mt-0.5">
+          {t("Separate account files with their own passwords. Open vaults are listed alongside your main accounts.")}
+        </div>
+      </div>
+      {store.vaults.map((vault) => (
+        <div key={vault.name} className="flex items-center gap-2">
+          <div className="min-w-[120px] text-[12px] text-zinc-300 truncate">
+            {vault.name}
+            <span className="ml-1.5 text-zinc-500">
+              {vault.open ? t("{{count}} accounts", { count: vault.accountCount ?? 0 }) : t("Closed")}
+            </span>
+          </div>
+          {vault.open ? (
+            <button
+              type="button"
+              disabled={busy}
+              onClick={() => run(() => invoke("close_vault", { name: vault.name }), t("Vault {{name}} closed", { name: vault.name }))}
+              className={`ml-auto ${vaultButtonClass}`}
+            >
+              {t("Close")}
+            </button>
+          ) : (
+            <>
+              <input
+                type="password"
+                value={passwords[vault.name] ?? ""}
+                onChange={(e) => setPasswords((prev) => ({ ...prev, [vault.name]: e.target.value }))}
+                onKeyDown={(e) => e.key === "Enter" && openVault(vault.name)}
+                placeholder={t("Password")}
+                className={vaultInputClass}
+              />
+              <button type="button" disabled={busy} onClick={() => openVault(vault.name)} className={vaultButtonClass}>
+                {t("Open")}
+              </button>
+            </>
+          )}
+        </div>
+      ))}
+      <div className="flex items-center gap-2">
+        <input
+          value={newName}
+          onChange={(e) => setNewName(e.target.value)}
+          placeholder={t("New vault name")}
+          className={vaultInputClass}
+        />
+        <input
+          type="password"
+          value={newPassword}
+          onChange={(e) => setNewPassword(e.target.value)}
+          placeholder={t("Password")}
+          className={vaultInputClass}
+        />
+        <button
+          type="button"
+          disabled={busy || !newName.trim() || !newPassword}
+          onClick={createVault}
+          className={vaultButtonClass}
+        >
+          {t("Create")}
+        </button>
+      </div>
+    </div>
+  );
+}
+
+function SmartGroupManager() {
+  const t = useTr();
+  const store = useStore();
+  const [name, setName] = useState("");
+  const [query, setQuery] = useState("");
+  const [busy, setBusy] = useState(false);
+
+  useEffect(() => {
+    store.refreshSmartGroups();
+  }, []);
+
+  async function run(action: () => Promise<unknown>) {
+    setBusy(true);
+    try {
+      await action();
+      await store.refreshSmartGroups();
+      return true;
+    } catch (e) {
+      store.addToast(String(e));
+      return false;
+    } finally {
+      setBusy(false);
+    }
+  }
+
+  function saveGroup() {
+    if (!name.trim() || !query.trim()) return;
+    void run(() => invoke("save_smart_group", { name: name.trim(), query })).then((ok) => {
+      if (!ok) return;
+      setName("");
+      setQuery("");
+    });
+  }
+
+  return (
+    <div className="py-2 px-1 space-y-2">
+      <div className="min-w-0">
+        <div className="text-[13px] text-zinc-200">{t("Smart Groups")}</div>
+        <div className="text-[11px] text-zinc-500 mt-0.5">
+          {t("Saved account queries, e.g. tag:farm and robux>100 and last_use<7d and valid. Filter with ?name or use the name wherever a query is accepted.")}
+        </div>
+      </div>
+      {store.smartGroups.map((group) => (
+        <div key={group.name} className="flex items-center gap-2">
+          <button
+            type="button"
+            onClick={() => store.setSearchQuery(`?${group.name}`)}
+            className="min-w-[120px] text-left text-[12px] text-zinc-300 truncate hover:text-zinc-100"
+          >
+            {group.name}
+            <span className={`ml-1.5 ${group.error ? "text-red-400" : "text-zinc-500"}`}>
+              {group.error ?? t("{{count}} accounts", { count: group.userIds.length })}
+            </span>
+          </button>
+          <span className="flex-1 text-[11px] text-zinc-500 font-mono truncate">{group.query}</span>
+          <button
+            type="button"
+            disabled={busy}
+            onClick={() => run(() => invoke("delete_smart_group", { name: group.name }))}
+            className={vaultButtonClass}
+          >
+            {t("Delete")}
+          </button>
+        </div>
+      ))}
+      <div className="flex items-center gap-2">
+        <input
+          value={name}
+          onChange={(e) => setName(e.target.value)}
+          placeholder={t("Name")}
+          className={vaultInputClass}
+        />
+        <input
+          value={query}
+          onChange={(e) => setQuery(e.target.value)}
+          onKeyDown={(e) => e.key === "Enter" && saveGroup()}
+          placeholder="tag:farm and valid"
+          spellCheck={false}
+          className={`${vaultInputClass} font-mono`}
+        />
+        <button
+          type="button"
+          disabled={busy || !name.trim() || !query.trim()}
+          onClick={saveGroup}
+          className={vaultButtonClass}
+        >
+          {t("Save")}
+        </button>
+      </div>
+    </div>
+  );
+}
+
+function BalanceTracker({ s }: { s: UseSettingsReturn }) {
+  const t = useTr();
+  const store = useStore();
+  const [totals, setTotals] = useState<BalanceTotal[]>([]);
+  const [drops, setDrops] = useState<BalanceDrop[]>([]);
+  const [busy, setBusy] = useState(false);
+
+  async function refresh() {
+    try {
+      setTotals(await invoke<BalanceTotal[]>("get_balance_totals", { days: 30 }));
+      setDrops(await invoke<BalanceDrop[]>("get_balance_drops", { days: 7 }));
+    } catch (e) {
+      store.addToast(String(e));
+    }
+  }
+
+  useEffect(() => {
+    void refresh();
+  }, []);
+
+  function setTracking(key: string, value: boolean) {
+    s.setBool("General", key, value);
+    // Settings are saved after a short debounce; wake the tracker once they are.
+    setTimeout(() => invoke("refresh_balance_tracker").catch(() => {}), 500);
+  }
+
+  async function snapshotNow() {
+    setBusy(true);
+    try {
+      const report = await invoke<{ recorded: number; failed: unknown[] }>("snapshot_balances");
+      store.addToast(
+        t("Recorded {{recorded}} balances, {{failed}} failed", {
+          recorded: report.recorded,
+          failed: report.failed.length,
+        })
+      );
+      await refresh();
+    } catch (e) {
+      store.addToast(String(e));
+    } finally {
+      setBusy(false);
+    }
+  }
+
+  const latest = totals[totals.length - 1];
+  const previous = totals[totals.length - 2];
+  const change = latest && previous ? latest.robux - previous.robux : null;
+  const nameOf = (userId: number) =>
+    store.accounts.find((a) => a.UserID === userId)?.Username ?? String(userId);
+
+  return (
+    <>
+      <Toggle
+        checked={s.getBool("General", "TrackBalances")}
+        onChange={(v) => setTracking("TrackBalances", v)}
+        label="Track Balances"
+        description="Periodically record every account's Robux to BalanceHistory.csv and warn about large drops"
+      />
+      <NumberField
+        value={s.getNumber("General", "BalanceTrackInterval", 360)}
+        onChange={(v) => s.setNumber("General", "BalanceTrackInterval", v)}
+        label="Snapshot Every"
+        min={15}
+        max={10080}
+        suffix="min"
+      />
+      <Toggle
+        checked={s.getBool("General", "BalanceTrackPremium")}
+        onChange={(v) => s.setBool("General", "BalanceTrackPremium", v)}
+        label="Track Premium"
+        description="Also record whether each account has Premium"
+      />
+      <Toggle
+        checked={s.getBool("General", "BalanceTrackLimiteds")}
+        onChange={(v) => s.setBool("General", "BalanceTrackLimiteds", v)}
+        label="Track Limiteds"
+        description="Also count limited items; slower for large inventories"
+      />
+      <NumberField
+        value={s.getNumber("General", "BalanceDropMinRobux", 500)}
+        onChange={(v) => s.setNumber("General", "BalanceDropMinRobux", v)}
+        label="Flag Drops Of"
+        min={1}
+        max={10000000}
+        suffix="R$"
+      />
+      <NumberField
+        value={s.getNumber("General", "BalanceDropMinPercent", 0)}
+        onChange={(v) => s.setNumber("General", "BalanceDropMinPercent", v)}
+        label="And At Least"
+        min={0}
+        max={100}
+        suffix="%"
+      />
+      <NumberField
+        value={s.getNumber("General", "BalanceHistoryDays", 90)}
+        onChange={(v) => s.setNumber("General", "BalanceHistoryDays", v)}
+        label="Keep History"
+        min={0}
+        max={3650}
+        suffix="days"
+      />
+      <div className="py-2 px-1 space-y-2">
+        <div className="flex items-center justify-between gap-3">
+          <div className="min-w-0 text-[12px] text-zinc-300">
+            {latest
+              ? t("{{robux}} R$ across {{accounts}} accounts", {
+                  robux: latest.robux.toLocaleString(),
+                  accounts: latest.accounts,
+                })
+              : t("No snapshots yet")}
+            {change !== null && change !== 0 && (
+              <span className={`ml-1.5 ${change < 0 ? "text-red-400" : "text-emerald-400"}`}>
+                {change > 0 ? "+" : ""}
+                {change.toLocaleString()}
+              </span>
+            )}
+          </div>
+          <button type="button" disabled={busy} onClick={snapshotNow} className={vaultButtonClass}>
+            {busy ? t("Recording...") : t("Snapshot Now")}
+          </button>
+        </div>
+        {drops.map((drop) => (
+          <div key={`${drop.userId}-${drop.toAt}`} className="flex items-center gap-2 text-[11px]">
+            <span className="min-w-[120px] text-zinc-300 truncate">{nameOf(drop.userId)}</span>
+            <span className="flex-1 text-red-400">
+              {drop.robuxLost > 0 && `-${drop.robuxLost.toLocaleString()} R$ `}
+              {drop.limitedsLost > 0 && t("-{{count}} limiteds", { count: drop.limitedsLost })}
+              {drop.premiumLost && ` ${t("lost Premium")}`}
+            </span>
+            <span className="text-zinc-500">{new Date(drop.toAt).toLocaleString()}</span>
+          </div>
+        ))}
+      </div>
+    </>
+  );
+}
+
+function AccountFileRekey({ s }: { s: UseSettingsReturn }) {
+  const t = useTr();
+  const store = useStore();
+  const [info, setInfo] = useState<AccountContainerInfo | null>(null);
+  const [password, setPassword] = useState("");
+  const [kdf, setKdf] = useState(() => s.get("General", "EncryptionKdf", "moderate"));
+  const [busy, setBusy] = useState(false);
+
+  useEffect(() => {
+    invoke<AccountContainerInfo | null>("get_account_encryption_info")
+      .then(setInfo)
+      .catch(() => setInfo(null));
+  }, []);
+
+  if (!info) return null;
+
+  async function handleScopeChange(scope: string) {
+    setBusy(true);
+    try {
+      const next = await invoke<AccountContainerInfo | null>("set_account_encryption_scope", { scope });
+      if (next) setInfo(next);
+      store.addToast(t("Account file re-encrypted"));
+    } catch (e) {
+      store.addToast(String(e));
+    } finally {
+      setBusy(false);
+    }
+  }
+
+  async function handleRekey() {
+    if (!password.trim()) return;
+    setBusy(true);
+    try {
+      const next = await invoke<AccountContainerInfo>("rekey_account_file", { password, kdf });
+      setInfo(next);
+      setPassword("");
+      s.set("General", "EncryptionKdf", kdf);
+      store.addToast(t("Account file re-encrypted"));
+    } catch (e) {
+      store.addToast(String(e));
+    } finally {
+      setBusy(false);
+    }
+  }
+
+  const memMiB = Math.round(info.memLimit / (1024 * 1024));
+
+  return (
+    <div className="py-2 px-1 space-y-2">
+      <RememberUnlockToggle />
+      <div className="min-w-0">
+        <div className="text-[13px] text-zinc-200">{t("Key Derivation")}</div>
+        <div className="text-[11px] text-zinc-500 mt-0.5">
+          {`v${info.version} · ${info.kdf} · ${info.opsLimit} passes · ${memMiB} MiB · ${info.cipher}`}
+        </div>
+      </div>
+      <div className="flex items-center justify-between gap-3">
+        <div className="min-w-0">
+          <div className="text-[13px] text-zinc-200">{t("Encrypted Data")}</div>
+          <div className="text-[11px] text-zinc-500 mt-0.5">
+            {t("Sealing only cookies and passwords keeps usernames and groups readable while locked.")}
+          </div>
+        </div>
+        <div className="min-w-[160px]">
+          <Select
+            value={info.scope}
+            options={[
+              { value: "file", label: t("Whole file") },
+              { value: "fields", label: t("Cookies and passwords") },
+            ]}
+            onChange={(v) => {
+              if (!busy && v !== info.scope) void handleScopeChange(v);
+            }}
+          />
+        </div>
+      </div>
+      <div className="flex items-center gap-2">
+        <div className="min-w-[160px]">
+          <Select
+            value={kdf}
+            options={[
+              { value: "interactive", label: t("Fast (64 MiB)") },
+              { value: "moderate", label: t("Balanced (256 MiB)") },
+              { value: "sensitive", label: t("Strong (1 GiB)") },
+            ]}
+            onChange={setKdf}
+          />
+        </div>
+        <input
+          type="password"
+          value={password}
+          onChange={(e) => setPassword(e.target.value)}
+          placeholder={t("Current password")}
+          className="flex-1 min-w-0 px-2.5 py-1.5 rounded-lg bg-zinc-900 border border-zinc-800 text-[12px] text-zinc-200 placeholder:text-zinc-600 focus:outline-none focus:border-zinc-600"
+        />
+        <button
+          type="button"
+          disabled={busy || !password.trim()}
+          onClick={handleRekey}
+          className="px-3 py-1.5 rounded-lg bg-zinc-800 hover:bg-zinc-700 border border-zinc-700/70 text-[12px] text-zinc-200 font-medium transition-colors disabled:opacity-50"
+        >
+          {busy ? t("Re-encrypting...") : t("Re-key")}
+        </button>
+      </div>
+    </div>
+  );
+}
 
 export function MiscellaneousTab({
   s,
@@ -300,6 +809,10 @@ export function MiscellaneousTab({
         suffix="min"
       />
 
+      <Divider />
+      <SectionLabel>Balance Tracker</SectionLabel>
+      <BalanceTracker s={s} />
+
       <Divider />
       <SectionLabel>Security</SectionLabel>
       <div className="flex items-center justify-between gap-3 py-2 px-1 rounded-lg border border-zinc-800/70 bg-zinc-900/35">
@@ -317,6 +830,49 @@ export function MiscellaneousTab({
           {t("Open")}
         </button>
       </div>
+      <NumberField
+        value={s.getNumber("General", "AccountBackups", 5)}
+        onChange={(v) => s.setNumber("General", "AccountBackups", v)}
+        label="Account Backups"
+        min={0}
+        max={100}
+      />
+      <NumberField
+        value={s.getNumber("General", "AutoLockMinutes", 0)}
+        onChange={(v) => s.setNumber("General", "AutoLockMinutes", v)}
+        label="Auto-Lock After"
+        min={0}
+        max={1440}
+        suffix="min"
+      />
+      <Toggle
+        checked={s.getBool("General", "LockOnSessionLock")}
+        onChange={(v) => s.setBool("General", "LockOnSessionLock", v)}
+        label="Lock With Computer"
+        description="Locks the account file when the system lock screen appears"
+      />
+      <div className="flex items-center justify-between gap-3 py-2 px-1">
+        <div className="min-w-0">
+          <div className="text-[13px] text-zinc-200">{t("While Botting")}</div>
+          <div className="text-[11px] text-zinc-500 mt-0.5">
+            {t("What auto-lock does when a botting session is running")}
+          </div>
+        </div>
+        <div className="min-w-[180px]">
+          <Select
+            value={s.get("General", "AutoLockBottingPolicy", "defer")}
+            options={[
+              { value: "defer", label: t("Wait until it ends") },
+              { value: "stop", label: t("Stop botting and lock") },
+              { value: "lock", label: t("Lock anyway") },
+            ]}
+            onChange={(v) => s.set("General", "AutoLockBottingPolicy", v)}
+          />
+        </div>
+      </div>
+      <AccountFileRekey s={s} />
+      <VaultManager />
+      <SmartGroupManager />
     </div>
   );
 }
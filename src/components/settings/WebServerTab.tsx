@@ -91,6 +91,12 @@ export function WebServerTab({ s }: { s: UseSettingsReturn }) {
         onChange={(v) => s.setBool("WebServer", "AllowAccountEditing", v)}
         label="Allow Account Editing"
       />
+      <Toggle
+        checked={s.getBool("WebServer", "AllowAccountManagement")}
+        onChange={(v) => s.setBool("WebServer", "AllowAccountManagement", v)}
+        label="Allow Account Management"
+        description="Remove accounts, change groups, set passwords and bulk import cookies. Always requires the password."
+      />
       <Toggle
         checked={s.getBool("WebServer", "AllowExternalConnections")}
         onChange={(v) => s.setBool("WebServer", "AllowExternalConnections", v)}
@@ -141,6 +147,52 @@ export function WebServerTab({ s }: { s: UseSettingsReturn }) {
         min={1}
         max={65535}
       />
+
+      <Divider />
+      <SectionLabel>Rate Limiting</SectionLabel>
+
+      <Toggle
+        checked={s.getBool("WebServer", "RateLimitEnabled")}
+        onChange={(v) => s.setBool("WebServer", "RateLimitEnabled", v)}
+        label="Enable Rate Limiting"
+        description="Reject clients that send too many requests with 429 Too Many Requests"
+      />
+      <NumberField
+        value={s.getNumber("WebServer", "RateLimitWindow", 60)}
+        onChange={(v) => s.setNumber("WebServer", "RateLimitWindow", v)}
+        label="Window"
+        min={1}
+        max={3600}
+        suffix="s"
+      />
+      <NumberField
+        value={s.getNumber("WebServer", "RateLimitPerRoute", 120)}
+        onChange={(v) => s.setNumber("WebServer", "RateLimitPerRoute", v)}
+        label="Requests per route"
+        min={0}
+        max={100000}
+      />
+      <NumberField
+        value={s.getNumber("WebServer", "RateLimitPerToken", 300)}
+        onChange={(v) => s.setNumber("WebServer", "RateLimitPerToken", v)}
+        label="Requests per client"
+        min={0}
+        max={100000}
+      />
+      <NumberField
+        value={s.getNumber("WebServer", "OutboundBudget", 120)}
+        onChange={(v) => s.setNumber("WebServer", "OutboundBudget", v)}
+        label="Roblox request budget"
+        min={0}
+        max={100000}
+      />
+      <TextField
+        value={s.get("WebServer", "RouteRateLimits", "")}
+        onChange={(v) => s.set("WebServer", "RouteRateLimits", v)}
+        label="Route limits"
+        placeholder="LaunchAccount:10,GetCSRFToken:30"
+        pattern={/[^0-9a-zA-Z:, ]/g}
+      />
     </div>
   );
 }
@@ -16,13 +16,19 @@ function presenceLabel(t: number): string {
   return "Offline";
 }
 
+// "clear" means nobody looked online (or the warning is off), "confirmed" means
+// the user accepted the warning and the launch should tell the backend so.
+export type JoinOnlineDecision = false | "clear" | "confirmed";
+
 export function useJoinOnlineWarning() {
   const store = useStore();
   const confirmWithOptOut = useConfirmWithOptOut();
 
-  return async function confirmJoin(userIds: number[]): Promise<boolean> {
-    if (userIds.length === 0) return true;
-    if (store.settings?.General?.WarnOnOnlineJoin === "false") return true;
+  return async function confirmJoin(userIds: number[]): Promise<JoinOnlineDecision> {
+    if (userIds.length === 0) return "clear";
+    if (store.settings?.General?.WarnOnOnlineJoin === "false") return "clear";
+    const policy = store.settings?.General?.OnlineJoinPolicy || "warn";
+    if (policy !== "warn") return "clear";
 
     const uniqueIds = [...new Set(userIds)];
     const presenceById = new Map<number, number>();
@@ -40,7 +46,7 @@ export function useJoinOnlineWarning() {
         }
       }
     } catch {
-      return true;
+      return "clear";
     }
 
     const accountById = new Map(store.accounts.map((a) => [a.UserID, a]));
@@ -56,7 +62,7 @@ export function useJoinOnlineWarning() {
       })
       .filter((v): v is { name: string; type: number } => v !== null);
 
-    if (risky.length === 0) return true;
+    if (risky.length === 0) return "clear";
 
     const preview = risky
       .slice(0, 4)
@@ -85,6 +91,6 @@ export function useJoinOnlineWarning() {
       store.addToast("Online-join warning disabled");
     }
 
-    return result.confirmed;
+    return result.confirmed ? "confirmed" : false;
   };
 }
@@ -10,7 +10,7 @@ import {
 } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
-import type { Account, ThemeData, ThumbnailData, ParsedGroup } from "./types";
+import type { Account, BalanceDrop, ThemeData, ThumbnailData, ParsedGroup, SmartGroupInfo, VaultInfo } from "./types";
 import { parseGroupName } from "./types";
 import { applyThemeCssVariables, normalizeTheme, DEFAULT_THEME } from "./theme";
 import i18n, { normalizeLanguage } from "./i18n";
@@ -23,11 +23,30 @@ import {
   getUpdaterSkipVersionKey,
 } from "./updaterChannels";
 
-interface PresenceEntry {
-  userId?: number;
-  userPresenceType?: number;
-  user_id?: number;
-  user_presence_type?: number;
+interface PresenceSnapshot {
+  userId: number;
+  presence: { userPresenceType: number };
+}
+
+interface PresenceChange {
+  userId: number;
+  presenceType: number;
+}
+
+interface PresenceCheckResult {
+  userId: number;
+  policy: "off" | "warn" | "skip" | "kick";
+  decision: "clear" | "unchecked" | "confirmed" | "warned" | "skipped" | "kicked";
+  presenceType?: number | null;
+  lastLocation?: string | null;
+  detail?: string | null;
+}
+
+interface LaunchResult {
+  userId: number;
+  launched: boolean;
+  presence?: PresenceCheckResult;
+  error?: string;
 }
 
 interface RunningInstanceEntry {
@@ -145,8 +164,8 @@ export interface StoreValue {
   presenceByUserId: Map<number, number>;
   launchedByProgram: Set<number>;
 
-  joinServer: (userId: number) => Promise<void>;
-  launchMultiple: (userIds: number[]) => Promise<void>;
+  joinServer: (userId: number, presenceConfirmed?: boolean) => Promise<void>;
+  launchMultiple: (userIds: number[], presenceConfirmed?: boolean) => Promise<void>;
   restartRobloxClients: (userIds: number[]) => Promise<void>;
   focusRobloxClient: (userId: number) => Promise<boolean>;
   killAllRobloxProcesses: () => Promise<void>;
@@ -161,6 +180,13 @@ export interface StoreValue {
   refreshBottingStatus: () => Promise<void>;
   refreshCookie: (userId: number) => Promise<boolean>;
   moveToGroup: (userIds: number[], group: string) => Promise<void>;
+  vaults: VaultInfo[];
+  refreshVaults: () => Promise<void>;
+  moveToVault: (userIds: number[], vault: string) => Promise<void>;
+  smartGroups: SmartGroupInfo[];
+  refreshSmartGroups: () => Promise<void>;
+  editTags: (userIds: number[], add: string[], remove: string[]) => Promise<void>;
+  queryError: string | null;
   sortGroupAlphabetically: (groupKey: string) => void;
   reorderAccounts: (draggedUserId: number, targetUserId: number) => Promise<void>;
   joiningAccounts: Set<number>;
@@ -296,6 +322,10 @@ export function StoreProvider({ children }: { children: ReactNode }) {
   const [launchedByProgram, setLaunchedByProgram] = useState<Set<number>>(new Set());
   const [error, setError] = useState<string | null>(null);
   const [needsPassword, setNeedsPassword] = useState(false);
+  const [vaults, setVaults] = useState<VaultInfo[]>([]);
+  const [smartGroups, setSmartGroups] = useState<SmartGroupInfo[]>([]);
+  const [queryMatches, setQueryMatches] = useState<Set<number> | null>(null);
+  const [queryError, setQueryError] = useState<string | null>(null);
   const [unlocking, setUnlocking] = useState(false);
   const [encryptionSetupOpen, setEncryptionSetupOpen] = useState(false);
   const [encryptionSetupMode, setEncryptionSetupMode] = useState<"firstRun" | "settings">("firstRun");
@@ -343,17 +373,45 @@ export function StoreProvider({ children }: { children: ReactNode }) {
   const showAvatarsWhenHidden = settings?.General?.ShowAvatarsWhenHidden === "true";
   const hideRobuxWhenHidden = settings?.General?.HideRobuxWhenHidden === "true";
 
+  // A search starting with "?" is an account query or smart group name,
+  // evaluated by the backend.
+  const accountQuery = searchQuery.startsWith("?") ? searchQuery.slice(1).trim() : null;
+
+  useEffect(() => {
+    if (!accountQuery) {
+      setQueryMatches(null);
+      setQueryError(null);
+      return;
+    }
+    const timer = window.setTimeout(() => {
+      invoke<number[]>("query_accounts", { query: accountQuery })
+        .then((ids) => {
+          setQueryMatches(new Set(ids));
+          setQueryError(null);
+        })
+        .catch((e) => {
+          setQueryMatches(new Set());
+          setQueryError(String(e));
+        });
+    }, 250);
+    return () => window.clearTimeout(timer);
+  }, [accountQuery, accounts]);
+
   const filteredAccounts = useMemo(() => {
     if (!searchQuery) return accounts;
+    if (accountQuery !== null) {
+      return queryMatches ? accounts.filter((a) => queryMatches.has(a.UserID)) : accounts;
+    }
     const q = searchQuery.toLowerCase();
     return accounts.filter(
       (a) =>
         (a.Username || "").toLowerCase().includes(q) ||
         (a.Alias || "").toLowerCase().includes(q) ||
         (a.Description || "").toLowerCase().includes(q) ||
-        (a.Group || "").toLowerCase().includes(q)
+        (a.Group || "").toLowerCase().includes(q) ||
+        (a.Tags || []).some((tag) => tag.toLowerCase().includes(q))
     );
-  }, [accounts, searchQuery]);
+  }, [accounts, searchQuery, accountQuery, queryMatches]);
 
   const groups = useMemo(() => {
     if (!showGroups) {
@@ -637,6 +695,46 @@ export function StoreProvider({ children }: { children: ReactNode }) {
     return await invoke<boolean>("refresh_cookie", { userId });
   }
 
+  async function refreshVaults() {
+    try {
+      setVaults(await invoke<VaultInfo[]>("list_vaults"));
+    } catch {
+      setVaults([]);
+    }
+  }
+
+  async function moveToVault(userIds: number[], vault: string) {
+    try {
+      const moved = await invoke<number>("move_accounts_to_vault", {
+        userIds,
+        vault: vault || null,
+      });
+      await loadAccounts();
+      await refreshVaults();
+      addToast(tr("Moved {{count}} accounts to {{vault}}", { count: moved, vault: vault || tr("Main") }));
+    } catch (e) {
+      addToast(String(e));
+    }
+  }
+
+  async function refreshSmartGroups() {
+    try {
+      setSmartGroups(await invoke<SmartGroupInfo[]>("list_smart_groups"));
+    } catch {
+      setSmartGroups([]);
+    }
+  }
+
+  async function editTags(userIds: number[], add: string[], remove: string[]) {
+    try {
+      await invoke<number>("edit_account_tags", { userIds, add, remove });
+      await loadAccounts();
+      await refreshSmartGroups();
+    } catch (e) {
+      addToast(String(e));
+    }
+  }
+
   async function moveToGroup(userIds: number[], group: string) {
     const updated = accounts.map((a) =>
       userIds.includes(a.UserID) ? { ...a, Group: group } : a
@@ -724,7 +822,7 @@ export function StoreProvider({ children }: { children: ReactNode }) {
     }
   }
 
-  async function joinServer(userId: number) {
+  async function joinServer(userId: number, presenceConfirmed = false) {
     clearLaunchTimeout();
     setJoiningAccounts(new Set([userId]));
     setLaunchProgress({
@@ -761,7 +859,7 @@ export function StoreProvider({ children }: { children: ReactNode }) {
         }
       }
 
-      await invoke("launch_roblox", {
+      const result = await invoke<LaunchResult>("launch_roblox", {
         userId,
         placeId: pid,
         jobId: resolvedJobId,
@@ -770,7 +868,14 @@ export function StoreProvider({ children }: { children: ReactNode }) {
         joinVip,
         linkCode,
         shuffleJob: shuffleJobId,
+        presenceConfirmed,
       });
+      if (!result.launched) {
+        throw result.error || tr("Launch skipped");
+      }
+      if (result.presence?.decision === "kicked") {
+        addToast(tr("Signed out other sessions of {{name}}", { name: accountName }));
+      }
       addToast(tr("Launching game..."));
     } catch (e) {
       setJoiningAccounts((prev) => {
@@ -795,7 +900,7 @@ export function StoreProvider({ children }: { children: ReactNode }) {
     }, 7000);
   }
 
-  async function launchMultiple(userIds: number[]) {
+  async function launchMultiple(userIds: number[], presenceConfirmed = false) {
     if (userIds.length === 0) return;
 
     clearLaunchTimeout();
@@ -810,13 +915,19 @@ export function StoreProvider({ children }: { children: ReactNode }) {
 
     try {
       const pid = parseInt(placeId) || 5315046213;
-      await invoke("launch_multiple", {
+      const results = await invoke<LaunchResult[]>("launch_multiple", {
         userIds,
         placeId: pid,
         jobId,
         launchData,
+        presenceConfirmed,
       });
-      addToast(tr("Launching {{count}} accounts...", { count: userIds.length }));
+      const launched = results.filter((r) => r.launched).length;
+      if (launched < results.length) {
+        addToast(tr("Launched {{launched}} of {{total}} accounts", { launched, total: results.length }));
+      } else {
+        addToast(tr("Launching {{count}} accounts...", { count: userIds.length }));
+      }
     } catch (e) {
       setJoiningAccounts(new Set());
       setLaunchProgress(null);
@@ -872,12 +983,12 @@ export function StoreProvider({ children }: { children: ReactNode }) {
     }
 
     if (launchedIds.length === 1) {
-      await joinServer(launchedIds[0]);
+      await joinServer(launchedIds[0], true);
       return;
     }
 
     try {
-      await launchMultiple(launchedIds);
+      await launchMultiple(launchedIds, true);
     } catch {
     }
   }
@@ -1332,6 +1443,21 @@ export function StoreProvider({ children }: { children: ReactNode }) {
         setJoiningAccounts(userId !== null ? new Set([userId]) : new Set());
         setActionStatusMessage(tr("Launching account {{current}}/{{total}}...", { current, total }), "info", 2000);
       }),
+      listen<PresenceCheckResult>("launch-presence-warning", (e) => {
+        const where = e.payload.lastLocation ? ` (${e.payload.lastLocation})` : "";
+        setActionStatusMessage(
+          `${tr("Account {{userId}} is already online", { userId: e.payload.userId })}${where}`,
+          "warn",
+          5000
+        );
+      }),
+      listen<BalanceDrop[]>("balance-drops", (e) => {
+        setActionStatusMessage(
+          tr("Large balance drop on {{count}} account(s), see Balance Tracker", { count: e.payload.length }),
+          "warn",
+          8000
+        );
+      }),
       listen("launch-complete", () => {
         setJoiningAccounts(new Set());
         setLaunchProgress((prev) => {
@@ -1357,6 +1483,37 @@ export function StoreProvider({ children }: { children: ReactNode }) {
     };
   }, [needsPassword, initialized, setActionStatusMessage]);
 
+  useEffect(() => {
+    if (needsPassword || !initialized) return;
+
+    let lastReport = 0;
+    const onActivity = () => {
+      const now = Date.now();
+      if (now - lastReport < 30000) return;
+      lastReport = now;
+      invoke("report_activity").catch(() => {});
+    };
+    const activityEvents = ["pointerdown", "keydown", "wheel", "mousemove"] as const;
+    activityEvents.forEach((name) => window.addEventListener(name, onActivity, { passive: true }));
+
+    const unlisten = listen<{ reason: string }>("accounts-locked", () => {
+      setAccounts([]);
+      setVaults([]);
+      setNeedsPassword(true);
+    });
+    const unlistenVaults = listen("vaults-closed", () => {
+      loadAccounts();
+      refreshVaults();
+    });
+    refreshVaults();
+
+    return () => {
+      activityEvents.forEach((name) => window.removeEventListener(name, onActivity));
+      unlisten.then((fn) => fn());
+      unlistenVaults.then((fn) => fn());
+    };
+  }, [needsPassword, initialized]);
+
   useEffect(() => {
     if (needsPassword || !initialized) return;
 
@@ -1462,44 +1619,30 @@ export function StoreProvider({ children }: { children: ReactNode }) {
     }
 
     let cancelled = false;
-    const userIds = accounts.map((a) => a.UserID);
-    const intervalMinutes = Math.max(
-      1,
-      parseInt(settings?.General?.PresenceUpdateRate || "5", 10) || 5
-    );
-    const intervalMs = Math.max(30_000, intervalMinutes * 60 * 1000);
+    let unlisten: (() => void) | null = null;
 
-    const refreshPresence = async () => {
-      if (userIds.length === 0) {
-        if (!cancelled) setPresenceByUserId(new Map());
-        return;
-      }
+    invoke<PresenceSnapshot[]>("get_cached_presence")
+      .then((snapshots) => {
+        if (cancelled) return;
+        setPresenceByUserId(new Map(snapshots.map((s) => [s.userId, s.presence.userPresenceType ?? 0])));
+      })
+      .catch(() => {});
 
-      const next = new Map<number, number>();
-      try {
-        for (let i = 0; i < userIds.length; i += 100) {
-          const chunk = userIds.slice(i, i + 100);
-          const result = await invoke<PresenceEntry[]>("get_presence", { userIds: chunk });
-          for (const presence of result) {
-            const userId = presence.userId ?? presence.user_id;
-            const presenceType = presence.userPresenceType ?? presence.user_presence_type ?? 0;
-            if (typeof userId === "number") {
-              next.set(userId, presenceType);
-            }
-          }
-        }
-        if (!cancelled) {
-          setPresenceByUserId(next);
-        }
-      } catch {
-      }
-    };
+    listen<PresenceChange[]>("presence-changed", (event) => {
+      setPresenceByUserId((prev) => {
+        const next = new Map(prev);
+        for (const change of event.payload) next.set(change.userId, change.presenceType);
+        return next;
+      });
+    }).then((u) => {
+      if (cancelled) u();
+      else unlisten = u;
+    });
 
-    refreshPresence();
-    const timer = window.setInterval(refreshPresence, intervalMs);
+    invoke("refresh_presence").catch(() => {});
     return () => {
       cancelled = true;
-      window.clearInterval(timer);
+      unlisten?.();
     };
   }, [
     accounts,
@@ -1742,6 +1885,13 @@ export function StoreProvider({ children }: { children: ReactNode }) {
     refreshBottingStatus,
     refreshCookie,
     moveToGroup,
+    vaults,
+    refreshVaults,
+    moveToVault,
+    smartGroups,
+    refreshSmartGroups,
+    editTags,
+    queryError,
     sortGroupAlphabetically,
     reorderAccounts,
     joiningAccounts,
@@ -11,6 +11,41 @@ export interface Account {
   Fields: Record<string, string>;
   LastAttemptedRefresh: string;
   BrowserTrackerID: string;
+  Tags?: string[];
+  Vault?: string;
+}
+
+export interface SmartGroupInfo {
+  name: string;
+  query: string;
+  userIds: number[];
+  error: string | null;
+}
+
+export interface BalanceTotal {
+  at: string;
+  accounts: number;
+  robux: number;
+  premium: number;
+  limiteds: number;
+}
+
+export interface BalanceDrop {
+  userId: number;
+  fromAt: string;
+  toAt: string;
+  fromRobux: number;
+  toRobux: number;
+  robuxLost: number;
+  limitedsLost: number;
+  premiumLost: boolean;
+}
+
+export interface VaultInfo {
+  name: string;
+  open: boolean;
+  encrypted: boolean;
+  accountCount: number | null;
 }
 
 export interface ThemeData {